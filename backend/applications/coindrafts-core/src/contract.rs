@@ -9,10 +9,11 @@ Handles game creation, player registration, and portfolio submissions.
 
 mod state;
 
-use coindrafts_core::{Achievement, AchievementType, CoinDraftsAbi, CoinDraftsOperation, Game, GameResult, PlayerProfile, PlayerStats, PlayerTier, Portfolio, PortfolioStatus, CryptoHolding, GameMode, GameStatus, TraditionalLeaguesMessage, PriceSnapshot};
+use coindrafts_core::{Achievement, AchievementType, CoinDraftsAbi, CoinDraftsCoreMessage, CoinDraftsOperation, Game, GameResult, PlayerProfile, PlayerStats, PlayerTier, Portfolio, PortfolioStatus, CryptoHolding, MAX_HOLDINGS, MAX_SHORT_PERCENT, GameMode, GameStatus, CoinDraftsEvent, CoinDraftsMessage, DeliveryStatus, GameEvent, MessageEnvelope, MessageRecord, TraditionalLeaguesMessage, CORE_NOTIFICATION_STREAM, PriceSnapshot, ArithmeticError, Fixed, FixedResult, Rounding};
 use self::state::CoinDraftsState;
+use traditional_leagues::{TraditionalLeaguesAbi, TraditionalLeaguesOperation, TraditionalLeaguesResponse};
 use linera_sdk::{
-    linera_base_types::WithContractAbi,
+    linera_base_types::{ApplicationId, ChainId, GenericApplicationId, StreamName, StreamUpdate, WithContractAbi},
     views::View,
     Contract, ContractRuntime,
 };
@@ -30,10 +31,10 @@ impl WithContractAbi for CoinDraftsContract {
 }
 
 impl Contract for CoinDraftsContract {
-    type Message = TraditionalLeaguesMessage;
+    type Message = MessageEnvelope<CoinDraftsCoreMessage>;
     type InstantiationArgument = ();
     type Parameters = ();
-    type EventValue = ();
+    type EventValue = CoinDraftsEvent;
 
     async fn load(runtime: ContractRuntime<Self>) -> Self {
         let state = CoinDraftsState::load(runtime.root_view_storage_context())
@@ -44,6 +45,7 @@ impl Contract for CoinDraftsContract {
 
     async fn instantiate(&mut self, _argument: ()) {
         self.state.game_counter.set(1);
+        self.state.admin.set(self.runtime.authenticated_signer());
     }
 
     async fn execute_operation(&mut self, operation: CoinDraftsOperation) -> () {
@@ -52,9 +54,9 @@ impl Contract for CoinDraftsContract {
                 let game_id = format!("game_{}", *self.state.game_counter.get());
                 let timestamp = self.runtime.system_time().micros();
 
-                let game = Game {
+                let mut game = Game {
                     game_id: game_id.clone(),
-                    name,
+                    name: name.clone(),
                     mode,
                     status: GameStatus::WaitingForPlayers,
                     created_at: timestamp,
@@ -64,166 +66,115 @@ impl Contract for CoinDraftsContract {
                     duration_hours,
                     start_prices: None,
                     winners: Vec::new(),
+                    tournament_id: None,
                 };
 
-                self.state.games.insert(&game_id, game).expect("Failed to create game");
-                self.state.game_counter.set(*self.state.game_counter.get() + 1);
-
-                // If it's a Traditional League game, send message to create tournament using official Linera pattern
+                // Traditional League games are backed by a tournament that leagues creates and links back
                 if mode == GameMode::TraditionalLeague {
-                    let tournament_message = TraditionalLeaguesMessage::TournamentCreated {
-                        game_id: game_id.clone(),
-                        tournament_id: format!("tournament_{}", game_id),
-                        tournament_info: format!("Tournament for Game {}", game_id),
-                    };
-                    
-                    // Get the chain ID where Traditional Leagues is deployed (using creator chain for now)
-                    let traditional_leagues_chain_id = self.runtime.application_creator_chain_id();
-                    
-                    // Send message using the official Linera pattern
-                    self.runtime
-                        .prepare_message(tournament_message)
-                        .with_authentication()
-                        .send_to(traditional_leagues_chain_id);
-                    
-                    // Tournament creation message sent
+                    let duration_minutes = duration_hours.saturating_mul(60);
+                    match self.leagues_application() {
+                        // Same chain: the tournament is created atomically with the game
+                        Some(leagues) => {
+                            let operation = TraditionalLeaguesOperation::CreateTournamentForGame {
+                                game_id: game_id.clone(),
+                                name,
+                                entry_fee_usdc,
+                                max_participants: max_players,
+                                duration_minutes,
+                            };
+                            match self.runtime.call_application(true, leagues, &operation) {
+                                TraditionalLeaguesResponse::TournamentCreated { tournament_id } if tournament_id != "error" => {
+                                    self.state.tournament_games.insert(&tournament_id, game_id.clone())
+                                        .expect("Failed to link tournament to game");
+                                    game.tournament_id = Some(tournament_id);
+                                    game.status = GameStatus::Active;
+                                }
+                                response => {
+                                    log::warn!("Rejecting game {}: Traditional Leagues did not create its tournament: {:?}",
+                                              game_id, response);
+                                    return;
+                                }
+                            }
+                        }
+                        None => self.request_leagues(CoinDraftsMessage::CreateTournament {
+                            game_id: game_id.clone(),
+                            tournament_name: name,
+                            entry_fee_usdc,
+                            max_participants: max_players,
+                            duration_minutes,
+                        }),
+                    }
                 }
+
+                let created = GameEvent::GameCreated {
+                    game_id: game_id.clone(),
+                    name: game.name.clone(),
+                    entry_fee_usdc,
+                    max_players,
+                    created_at: timestamp,
+                };
+                self.save_game(game).await.expect("Failed to create game");
+                self.state.game_counter.set(*self.state.game_counter.get() + 1);
+                self.publish(created);
             },
 
             CoinDraftsOperation::RegisterPlayer { game_id, player_name } => {
-                let account_str = self.runtime.authenticated_signer()
-                    .map(|a| a.to_string())
-                    .unwrap_or_else(|| "unknown".to_string());
-                let timestamp = self.runtime.system_time().micros();
-
-                let player = PlayerProfile {
-                    name: player_name,
-                    account: account_str.clone(),
-                    registered_at: timestamp,
-                    stats: PlayerStats {
-                        games_played: 0,
-                        games_won: 0,
-                        top_10_finishes: 0,
-                        avg_performance: 0,
-                        best_performance: 0,
-                        current_streak: 0,
-                        longest_streak: 0,
-                        accuracy_score: 0,
-                    },
-                    tier: PlayerTier::Rookie,
-                    total_earnings_usdc: 0,
+                if !self.on_game_chain() {
+                    self.send_to_game_chain(CoinDraftsCoreMessage::JoinGame { game_id, player_name });
+                    return;
+                }
+                let Some(signer) = self.runtime.authenticated_signer() else {
+                    log::warn!("Player registrations must be signed");
+                    return;
                 };
 
-                self.state.players.insert(&account_str, player).expect("Failed to register player");
-
-                // Update game player count
-                if let Ok(Some(mut game)) = self.state.games.get(&game_id).await {
-                    game.player_count += 1;
-                    self.state.games.insert(&game_id, game).expect("Failed to update game");
-                }
+                let player = self.new_player_profile(player_name, signer.to_string());
+                let _ = self.join_game(game_id, player).await;
             },
 
             CoinDraftsOperation::RegisterPlayerWithAccount { game_id, player_name, player_account } => {
-                let timestamp = self.runtime.system_time().micros();
-
-                let player = PlayerProfile {
-                    name: player_name,
-                    account: player_account.clone(),
-                    registered_at: timestamp,
-                    stats: PlayerStats {
-                        games_played: 0,
-                        games_won: 0,
-                        top_10_finishes: 0,
-                        avg_performance: 0,
-                        best_performance: 0,
-                        current_streak: 0,
-                        longest_streak: 0,
-                        accuracy_score: 0,
-                    },
-                    tier: PlayerTier::Rookie,
-                    total_earnings_usdc: 0,
-                };
-
-                self.state.players.insert(&player_account, player).expect("Failed to register player");
-
-                // Update game player count
-                if let Ok(Some(mut game)) = self.state.games.get(&game_id).await {
-                    game.player_count += 1;
-                    self.state.games.insert(&game_id, game).expect("Failed to update game");
+                if !self.signed_by_admin() {
+                    log::warn!("Only the application creator may register players by account");
+                    return;
                 }
-            },
-
-            CoinDraftsOperation::SubmitPortfolio { game_id, cryptocurrencies } => {
-                let account_str = self.runtime.authenticated_signer()
-                    .map(|a| a.to_string())
-                    .unwrap_or_else(|| "unknown".to_string());
-                let timestamp = self.runtime.system_time().micros();
 
-                // Convert cryptocurrencies (IDs like "bitcoin") to CryptoHolding with equal allocation
-                let equal_allocation = 100u8 / cryptocurrencies.len() as u8;
-                let holdings: Vec<CryptoHolding> = cryptocurrencies.into_iter().map(|crypto_id| {
-                    CryptoHolding {
-                        symbol: crypto_id,  // Store crypto ID (e.g., "bitcoin") to match price snapshots
-                        allocation_percent: equal_allocation,
-                    }
-                }).collect();
+                let player = self.new_player_profile(player_name, player_account);
+                let _ = self.join_game(game_id, player).await;
+            },
 
-                let portfolio = Portfolio {
-                    game_id: game_id.clone(),
-                    player_account: account_str,
-                    holdings,
-                    submitted_at: timestamp,
-                    status: PortfolioStatus::Valid,
+            CoinDraftsOperation::SubmitPortfolio { game_id, cryptocurrencies, short_picks } => {
+                if !self.on_game_chain() {
+                    self.send_to_game_chain(CoinDraftsCoreMessage::SubmitPortfolio { game_id, cryptocurrencies, short_picks });
+                    return;
+                }
+                let Some(signer) = self.runtime.authenticated_signer() else {
+                    log::warn!("Portfolio submissions must be signed");
+                    return;
                 };
 
-                // Get existing portfolios for this game or create new vec
-                let mut portfolios = self.state.portfolios.get(&game_id)
-                    .await
-                    .unwrap_or_default()
-                    .unwrap_or_default();
-                
-                // Remove any existing portfolio from this player (prevent duplicates)
-                portfolios.retain(|p| p.player_account != portfolio.player_account);
-                
-                portfolios.push(portfolio);
-                
-                self.state.portfolios.insert(&game_id, portfolios)
-                    .expect("Failed to submit portfolio");
+                let _ = self.submit_portfolio(game_id, signer.to_string(), cryptocurrencies, short_picks).await;
             },
 
-            CoinDraftsOperation::SubmitPortfolioForAccount { game_id, player_account, cryptocurrencies } => {
-                let timestamp = self.runtime.system_time().micros();
+            CoinDraftsOperation::UpdatePortfolio { game_id, cryptocurrencies, short_picks } => {
+                if !self.on_game_chain() {
+                    self.send_to_game_chain(CoinDraftsCoreMessage::UpdatePortfolio { game_id, cryptocurrencies, short_picks });
+                    return;
+                }
+                let Some(signer) = self.runtime.authenticated_signer() else {
+                    log::warn!("Portfolio updates must be signed");
+                    return;
+                };
 
-                // Convert cryptocurrencies (IDs like "bitcoin") to CryptoHolding with equal allocation
-                let equal_allocation = 100u8 / cryptocurrencies.len() as u8;
-                let holdings: Vec<CryptoHolding> = cryptocurrencies.into_iter().map(|crypto_id| {
-                    CryptoHolding {
-                        symbol: crypto_id,  // Store crypto ID (e.g., "bitcoin") to match price snapshots
-                        allocation_percent: equal_allocation,
-                    }
-                }).collect();
+                let _ = self.update_portfolio(game_id, signer.to_string(), cryptocurrencies, short_picks).await;
+            },
 
-                let portfolio = Portfolio {
-                    game_id: game_id.clone(),
-                    player_account,
-                    holdings,
-                    submitted_at: timestamp,
-                    status: PortfolioStatus::Valid,
-                };
+            CoinDraftsOperation::SubmitPortfolioForAccount { game_id, player_account, cryptocurrencies, short_picks } => {
+                if !self.signed_by_admin() {
+                    log::warn!("Only the application creator may submit portfolios by account");
+                    return;
+                }
 
-                // Get existing portfolios for this game or create new vec
-                let mut portfolios = self.state.portfolios.get(&game_id)
-                    .await
-                    .unwrap_or_default()
-                    .unwrap_or_default();
-                
-                // Remove any existing portfolio from this player (prevent duplicates)
-                portfolios.retain(|p| p.player_account != portfolio.player_account);
-                
-                portfolios.push(portfolio);
-                
-                self.state.portfolios.insert(&game_id, portfolios)
-                    .expect("Failed to submit portfolio");
+                let _ = self.submit_portfolio(game_id, player_account, cryptocurrencies, short_picks).await;
             },
 
             CoinDraftsOperation::StartGame { game_id, price_snapshot } => {
@@ -231,8 +182,10 @@ impl Contract for CoinDraftsContract {
                 if let Ok(Some(mut game)) = self.state.games.get(&game_id).await {
                     game.status = GameStatus::Active;
                     game.start_prices = Some(price_snapshot.clone());
-                    self.state.games.insert(&game_id, game).expect("Failed to update game");
+                    self.save_game(game).await.expect("Failed to update game");
                     log::info!("Game {} started with {} price snapshots stored", game_id, price_snapshot.len());
+                    let started_at = self.runtime.system_time().micros();
+                    self.publish(GameEvent::GameStarted { game_id, started_at });
                 }
             },
 
@@ -251,31 +204,37 @@ impl Contract for CoinDraftsContract {
                         return;
                     }
                     
-                    let portfolios = self.state.portfolios.get(&game_id)
+                    // Portfolios the game's tournament rejected are not scored
+                    let portfolios: Vec<Portfolio> = self.state.game_portfolios(&game_id)
                         .await
                         .unwrap_or_default()
-                        .unwrap_or_default();
+                        .into_iter()
+                        .filter(|portfolio| portfolio.status == PortfolioStatus::Valid)
+                        .collect();
                     
                     if portfolios.is_empty() {
-                        log::warn!("Cannot end game {} - no portfolios submitted", game_id);
+                        log::warn!("Cannot end game {} - no valid portfolios submitted", game_id);
                         return;
                     }
 
                     game.status = GameStatus::Completed;
 
                     // Calculate returns for each portfolio
-                    let mut leaderboard: Vec<(String, i64)> = Vec::new();
+                    let mut leaderboard: Vec<(String, Fixed)> = Vec::new();
                     
                     if let Some(start_prices) = &game.start_prices {
                         for portfolio in &portfolios {
-                            let crypto_ids: Vec<String> = portfolio.holdings.iter()
-                                .map(|h| h.symbol.clone())
-                                .collect();
-                            let total_return = Self::calculate_portfolio_return(
-                                &crypto_ids,
+                            let total_return = match Self::calculate_portfolio_return(
+                                &portfolio.holdings,
                                 start_prices,
                                 &price_snapshot
-                            );
+                            ) {
+                                Ok(total_return) => total_return,
+                                Err(e) => {
+                                    log::error!("Cannot end game {} - return calculation failed: {}", game_id, e);
+                                    return;
+                                }
+                            };
                             leaderboard.push((portfolio.player_account.clone(), total_return));
                         }
                         
@@ -284,17 +243,18 @@ impl Contract for CoinDraftsContract {
                     } else {
                         // No start prices, just list players
                         for portfolio in &portfolios {
-                            leaderboard.push((portfolio.player_account.clone(), 0));
+                            leaderboard.push((portfolio.player_account.clone(), Fixed::ZERO));
                         }
                     }
                     
-                    // Calculate prize pool using stored entry fee
-                    let total_pool = game.entry_fee_usdc * portfolios.len() as u64;
-                    let prizes = vec![
-                        total_pool * 50 / 100,
-                        total_pool * 30 / 100,
-                        total_pool * 20 / 100,
-                    ];
+                    // Every paid entry funds the pool, including players whose portfolio was rejected
+                    let prizes = match Self::calculate_prizes(game.entry_fee_usdc, game.player_count as u64) {
+                        Ok(prizes) => prizes,
+                        Err(e) => {
+                            log::error!("Cannot end game {} - prize calculation failed: {}", game_id, e);
+                            return;
+                        }
+                    };
                     
                     // Update all player stats and distribute prizes
                     let timestamp = self.runtime.system_time().micros();
@@ -307,7 +267,7 @@ impl Contract for CoinDraftsContract {
                         let game_result = GameResult {
                             game_id: game_id.clone(),
                             rank: rank_num,
-                            portfolio_return: *portfolio_return,
+                            portfolio_return: portfolio_return.raw(),
                             prize_won: prize,
                             played_at: timestamp,
                         };
@@ -322,9 +282,7 @@ impl Contract for CoinDraftsContract {
                             }
                             
                             // Distribute prizes to top 3
-                            if rank < prizes.len() {
-                                player.total_earnings_usdc += prizes[rank];
-                            }
+                            player.total_earnings_usdc = player.total_earnings_usdc.saturating_add(prize);
                             
                             // Check and unlock achievements
                             let _ = self.check_and_unlock_achievements(
@@ -335,7 +293,7 @@ impl Contract for CoinDraftsContract {
                                 &player
                             ).await;
                             
-                            let _ = self.state.players.insert(player_account, player);
+                            let _ = self.save_player(player_account, player);
                         }
                     }
                     
@@ -345,10 +303,46 @@ impl Contract for CoinDraftsContract {
                     
                     // Mark game as completed
                     game.status = GameStatus::Completed;
+                    let winners = game.winners.clone();
                     
-                    self.state.games.insert(&game_id, game).expect("Failed to update game");
+                    self.save_game(game).await.expect("Failed to update game");
+                    self.publish(GameEvent::GameSettled { game_id, winners, settled_at: timestamp });
                 }
             },
+
+            CoinDraftsOperation::RetryMessage { message_id } => {
+                self.retry_request(message_id).await;
+            }
+
+            CoinDraftsOperation::ConnectLeagues { application_id, chain_id } => {
+                self.connect_leagues(application_id, chain_id);
+            }
+        }
+    }
+
+    async fn process_streams(&mut self, updates: Vec<StreamUpdate>) {
+        let (Some(leagues), Some(leagues_chain)) =
+            (*self.state.leagues_application_id.get(), *self.state.leagues_chain_id.get())
+        else {
+            return;
+        };
+        for update in updates {
+            if update.chain_id != leagues_chain
+                || update.stream_id.application_id != GenericApplicationId::User(leagues)
+                || update.stream_id.stream_name != StreamName::from(CORE_NOTIFICATION_STREAM)
+            {
+                continue;
+            }
+            for index in update.previous_index..update.next_index {
+                // Only leagues can read its own stream, so it hands each notification over
+                let operation = TraditionalLeaguesOperation::ReadNotification { chain_id: leagues_chain, index };
+                match self.runtime.call_application(true, leagues.with_abi::<TraditionalLeaguesAbi>(), &operation) {
+                    TraditionalLeaguesResponse::Notification { envelope: Some(envelope) } => {
+                        self.receive_leagues_message(envelope).await;
+                    }
+                    response => log::warn!("Traditional Leagues did not return notification {}: {:?}", index, response),
+                }
+            }
         }
     }
 
@@ -357,19 +351,571 @@ impl Contract for CoinDraftsContract {
         self.state.save().await.expect("Failed to save state");
     }
 
-    async fn execute_message(&mut self, message: TraditionalLeaguesMessage) {
-        // Handle incoming messages FROM Traditional Leagues application
-        // Following proper Linera message flow patterns
+    async fn execute_message(&mut self, envelope: MessageEnvelope<CoinDraftsCoreMessage>) {
+        let message_id = envelope.message_id.clone();
+        if self.runtime.message_is_bouncing() == Some(true) {
+            // The game chain rejected one of this player chain's requests
+            let kind = envelope.payload.kind();
+            log::warn!("Request {} ({}) was rejected by the game chain", message_id, kind);
+            self.settle_request(message_id, Some(format!("{} was rejected by the game chain", kind))).await;
+            return;
+        }
+
+        let message = match envelope.into_payload() {
+            Ok(message) => message,
+            Err(version) => panic!("Message {} uses unsupported protocol version {}", message_id, version),
+        };
+        // A failed request aborts the message so that, being tracked, it bounces back to the player
+        if let Err(reason) = self.handle_player_message(message_id, message).await {
+            panic!("{}", reason);
+        }
+    }
+}
+
+// Helper functions for CoinDraftsContract
+impl CoinDraftsContract {
+    /// Traditional Leagues application, if connected and running on this chain. Without it,
+    /// requests are handed to the leagues instance on this chain, which forwards them.
+    fn leagues_application(&mut self) -> Option<ApplicationId<TraditionalLeaguesAbi>> {
+        let leagues = (*self.state.leagues_application_id.get())?;
+        (*self.state.leagues_chain_id.get() == Some(self.runtime.chain_id()))
+            .then(|| leagues.with_abi::<TraditionalLeaguesAbi>())
+    }
+
+    /// Follow the notification stream of the Traditional Leagues application on `chain_id`,
+    /// replacing any earlier connection
+    fn connect_leagues(&mut self, application_id: ApplicationId, chain_id: ChainId) {
+        if !self.signed_by_admin() {
+            log::warn!("Only the application creator may connect Traditional Leagues");
+            return;
+        }
+        if let (Some(previous), Some(previous_chain)) =
+            (*self.state.leagues_application_id.get(), *self.state.leagues_chain_id.get())
+        {
+            self.runtime.unsubscribe_from_events(previous_chain, previous, StreamName::from(CORE_NOTIFICATION_STREAM));
+        }
+
+        // Leagues notifications (answers, completions, cancellations, seasons) arrive through process_streams
+        self.runtime.subscribe_to_events(chain_id, application_id, StreamName::from(CORE_NOTIFICATION_STREAM));
+        self.state.leagues_application_id.set(Some(application_id));
+        self.state.leagues_chain_id.set(Some(chain_id));
+        log::info!("Connected to Traditional Leagues {} on chain {}", application_id, chain_id);
+    }
+
+    /// Tournament backing a game, once leagues has created it
+    async fn game_tournament(&self, game_id: &str) -> Option<String> {
+        self.state.games.get(game_id).await.ok().flatten()?.tournament_id
+    }
+
+    /// Whether this is the chain hosting the games: the chain that created the application
+    fn on_game_chain(&mut self) -> bool {
+        self.runtime.chain_id() == self.runtime.application_creator_chain_id()
+    }
+
+    /// Whether the operation is signed by the account that created the application
+    fn signed_by_admin(&mut self) -> bool {
+        let signer = self.runtime.authenticated_signer();
+        signer.is_some() && signer == *self.state.admin.get()
+    }
+
+    /// Send a player's request from their own chain to the game chain, signed by the player and
+    /// tracked so a rejected request bounces back. Logged in the outbox until the receipt arrives.
+    fn send_to_game_chain(&mut self, request: CoinDraftsCoreMessage) {
+        let chain_id = self.runtime.chain_id();
+        let message_id = self.state.generate_message_id(chain_id);
+        let now = self.runtime.system_time().micros();
+        let record = MessageRecord::pending(message_id.clone(), request.kind(), now);
+        self.state.outbox.insert(&message_id, record).expect("Failed to log request");
+
+        let game_chain = self.runtime.application_creator_chain_id();
+        self.runtime
+            .prepare_message(MessageEnvelope::new(message_id, request))
+            .with_authentication()
+            .with_tracking()
+            .send_to(game_chain);
+    }
+
+    /// Apply a message from another CoinDrafts Core chain. On the game chain, player requests act
+    /// for the authenticated signer and are answered with a receipt.
+    async fn handle_player_message(&mut self, message_id: String, message: CoinDraftsCoreMessage) -> Result<(), String> {
+        if let CoinDraftsCoreMessage::RequestApplied { message_id } = message {
+            self.settle_request(message_id, None).await;
+            return Ok(());
+        }
+        let player_account = self.runtime.authenticated_signer()
+            .ok_or_else(|| format!("Player request {} is not signed", message_id))?
+            .to_string();
+
+        match message {
+            CoinDraftsCoreMessage::JoinGame { game_id, player_name } => {
+                let player = self.new_player_profile(player_name, player_account);
+                self.join_game(game_id, player).await?;
+            }
+            CoinDraftsCoreMessage::SubmitPortfolio { game_id, cryptocurrencies, short_picks } => {
+                self.submit_portfolio(game_id, player_account, cryptocurrencies, short_picks).await?;
+            }
+            CoinDraftsCoreMessage::UpdatePortfolio { game_id, cryptocurrencies, short_picks } => {
+                self.update_portfolio(game_id, player_account, cryptocurrencies, short_picks).await?;
+            }
+            CoinDraftsCoreMessage::RequestApplied { .. } => unreachable!("Receipts are handled above"),
+        }
+
+        let origin = self.runtime.message_origin_chain_id().expect("Messages have an origin chain");
+        let chain_id = self.runtime.chain_id();
+        let receipt_id = self.state.generate_message_id(chain_id);
+        self.runtime
+            .prepare_message(MessageEnvelope::new(receipt_id, CoinDraftsCoreMessage::RequestApplied { message_id }))
+            .send_to(origin);
+        Ok(())
+    }
+
+    /// Fresh profile for a player joining a game
+    fn new_player_profile(&mut self, name: String, account: String) -> PlayerProfile {
+        PlayerProfile {
+            name,
+            account,
+            registered_at: self.runtime.system_time().micros(),
+            stats: PlayerStats {
+                games_played: 0,
+                games_won: 0,
+                top_10_finishes: 0,
+                avg_performance: 0,
+                best_performance: 0,
+                current_streak: 0,
+                longest_streak: 0,
+                accuracy_score: 0,
+            },
+            tier: PlayerTier::Rookie,
+            total_earnings_usdc: 0,
+        }
+    }
+
+    /// Record a player's profile and entry fee for a game, entering Traditional League
+    /// players into the game's tournament. A rejected tournament registration is refunded
+    /// and recorded rather than reported as an error.
+    async fn join_game(&mut self, game_id: String, player: PlayerProfile) -> Result<(), String> {
+        let account = player.account.clone();
+        self.save_player(&account, player.clone()).expect("Failed to register player");
+
+        let Ok(Some(mut game)) = self.state.games.get(&game_id).await else {
+            log::warn!("Player {} cannot join unknown game {}", account, game_id);
+            return Err(format!("Game {} not found", game_id));
+        };
+        let key = (game_id.clone(), account.clone());
+        if let Ok(Some(_)) = self.state.entry_payments.get(&key).await {
+            log::warn!("Player {} already joined game {}", account, game_id);
+            return Err(format!("Player {} already joined game {}", account, game_id));
+        }
+
+        // Collect the entry fee and update game player count
+        self.state.entry_payments.insert(&key, game.entry_fee_usdc).expect("Failed to record entry fee");
+        game.player_count += 1;
+        self.save_game(game).await.expect("Failed to update game");
+
+        match self.register_with_leagues(&game_id, &player).await {
+            Ok(()) => {
+                let _ = self.state.registration_rejections.remove(&key);
+                self.publish(GameEvent::PlayerJoined {
+                    game_id,
+                    player_account: account,
+                    joined_at: player.registered_at,
+                });
+            }
+            Err(reason) => self.reject_registration(&game_id, &account, reason).await,
+        }
+        Ok(())
+    }
+
+    /// Store a player's equal-weight portfolio, replacing any earlier one, once the game's
+    /// tournament has accepted it
+    async fn submit_portfolio(
+        &mut self,
+        game_id: String,
+        player_account: String,
+        cryptocurrencies: Vec<String>,
+        short_picks: Option<Vec<String>>,
+    ) -> Result<(), String> {
+        let timestamp = self.runtime.system_time().micros();
+
+        if cryptocurrencies.is_empty() || cryptocurrencies.len() > MAX_HOLDINGS {
+            log::warn!("Rejecting portfolio for game {}: {} picks, expected 1 to {}",
+                      game_id, cryptocurrencies.len(), MAX_HOLDINGS);
+            return Err(format!("A portfolio needs 1 to {} picks, got {}", MAX_HOLDINGS, cryptocurrencies.len()));
+        }
+
+        // Convert cryptocurrencies (IDs like "bitcoin") to CryptoHolding with equal allocation
+        let holdings = Self::equal_holdings(cryptocurrencies, short_picks.unwrap_or_default());
+
+        let portfolio = Portfolio {
+            game_id: game_id.clone(),
+            player_account,
+            holdings,
+            submitted_at: timestamp,
+            status: PortfolioStatus::Valid,
+        };
+
+        if portfolio.short_percent() > MAX_SHORT_PERCENT {
+            log::warn!("Rejecting portfolio for game {}: {}% short exceeds the {}% cap",
+                      game_id, portfolio.short_percent(), MAX_SHORT_PERCENT);
+            return Err(format!("{}% short exceeds the {}% cap", portfolio.short_percent(), MAX_SHORT_PERCENT));
+        }
+
+        let key = (portfolio.game_id.clone(), portfolio.player_account.clone());
+        if let Err(reason) = self.sync_with_leagues(&portfolio).await {
+            log::warn!("Rejecting portfolio of {} for game {}: {}", key.1, key.0, reason);
+            self.state.portfolio_rejections.insert(&key, reason.clone()).expect("Failed to record rejection");
+            return Err(reason);
+        }
+        let _ = self.state.portfolio_rejections.remove(&key);
+
+        // Replaces any existing portfolio from this player (prevent duplicates)
+        self.state.upsert_portfolio(portfolio)
+            .await
+            .expect("Failed to submit portfolio");
+        self.publish(GameEvent::PortfolioSubmitted {
+            game_id,
+            player_account: key.1,
+            submitted_at: timestamp,
+        });
+        Ok(())
+    }
+
+    /// Replace a player's submitted portfolio while the game has not started
+    async fn update_portfolio(
+        &mut self,
+        game_id: String,
+        player_account: String,
+        cryptocurrencies: Vec<String>,
+        short_picks: Option<Vec<String>>,
+    ) -> Result<(), String> {
+        let key = (game_id.clone(), player_account.clone());
+        if !self.state.portfolios.contains_key(&key).await.unwrap_or(false) {
+            log::warn!("Player {} has no portfolio to update in game {}", player_account, game_id);
+            return Err(format!("No portfolio of {} in game {}", player_account, game_id));
+        }
+        let game = self.state.games.get(&game_id).await.ok().flatten()
+            .ok_or_else(|| format!("Game {} not found", game_id))?;
+        if game.start_prices.is_some() || matches!(game.status, GameStatus::Completed | GameStatus::Cancelled) {
+            log::warn!("Game {} has started, portfolio of {} is locked", game_id, player_account);
+            return Err(format!("Game {} has started", game_id));
+        }
+
+        self.submit_portfolio(game_id, player_account, cryptocurrencies, short_picks).await
+    }
+
+    /// Register a player in the tournament behind a Traditional League game. Leagues holds
+    /// the registration until core verifies it: straight away when called on the same chain,
+    /// otherwise once leagues asks on its notification stream.
+    async fn register_with_leagues(&mut self, game_id: &str, player: &PlayerProfile) -> Result<(), String> {
+        let Some(tournament_id) = self.game_tournament(game_id).await else {
+            return Ok(());
+        };
+        let Some(leagues) = self.leagues_application() else {
+            self.request_leagues(CoinDraftsMessage::RegisterPlayerForTournament {
+                game_id: game_id.to_string(),
+                tournament_id,
+                player_profile: player.clone(),
+            });
+            return Ok(());
+        };
+
+        let operation = TraditionalLeaguesOperation::RegisterForTournament {
+            tournament_id: tournament_id.clone(),
+            player_account: player.account.clone(),
+        };
+        match self.runtime.call_application(true, leagues, &operation) {
+            TraditionalLeaguesResponse::RegistrationPending { success: true } => {}
+            response => return Err(format!("Traditional Leagues refused the registration: {:?}", response)),
+        }
+
+        let verification = self.verify_player(game_id, &player.account).await;
+        self.answer_verification(game_id, tournament_id, &player.account, verification)
+    }
+
+    /// Check that a player may enter a game's tournament: they need a profile and a paid entry
+    async fn verify_player(&self, game_id: &str, player_account: &str) -> Result<(), String> {
+        let game = self.state.games.get(game_id).await.ok().flatten()
+            .ok_or_else(|| format!("Game {} not found", game_id))?;
+        if !self.state.players.contains_key(player_account).await.unwrap_or(false) {
+            return Err(format!("No player profile for {}", player_account));
+        }
+
+        let key = (game_id.to_string(), player_account.to_string());
+        match self.state.entry_payments.get(&key).await.ok().flatten() {
+            Some(paid) if paid >= game.entry_fee_usdc => Ok(()),
+            Some(paid) => Err(format!("Entry fee for game {} underpaid: {} of {} USDC", game_id, paid, game.entry_fee_usdc)),
+            None => Err(format!("Entry fee for game {} not paid", game_id)),
+        }
+    }
+
+    /// Send a verification result for a held registration to leagues. Returns the final
+    /// outcome when leagues answers on the same chain, otherwise the verification itself.
+    fn answer_verification(
+        &mut self,
+        game_id: &str,
+        tournament_id: String,
+        player_account: &str,
+        verification: Result<(), String>,
+    ) -> Result<(), String> {
+        let Some(leagues) = self.leagues_application() else {
+            self.request_leagues(CoinDraftsMessage::VerifyPlayer {
+                game_id: game_id.to_string(),
+                player_account: player_account.to_string(),
+                tournament_id,
+                verified: verification.is_ok(),
+                reason: verification.clone().err(),
+            });
+            return verification;
+        };
+
+        let operation = TraditionalLeaguesOperation::VerifyPlayer {
+            tournament_id,
+            player_account: player_account.to_string(),
+            verified: verification.is_ok(),
+            reason: verification.err(),
+        };
+        match self.runtime.call_application(true, leagues, &operation) {
+            TraditionalLeaguesResponse::PlayerRegistered { success: true } => Ok(()),
+            TraditionalLeaguesResponse::RegistrationRejected { reason } => Err(reason),
+            response => Err(format!("Unexpected Traditional Leagues response: {:?}", response)),
+        }
+    }
+
+    /// Record why a player's registration was rejected and refund their entry
+    async fn reject_registration(&mut self, game_id: &str, player_account: &str, reason: String) {
+        log::warn!("Registration of {} for game {} rejected: {}", player_account, game_id, reason);
+        let key = (game_id.to_string(), player_account.to_string());
+        if let Ok(Some(_)) = self.state.entry_payments.get(&key).await {
+            self.state.entry_payments.remove(&key).expect("Failed to refund entry fee");
+            if let Ok(Some(mut game)) = self.state.games.get(game_id).await {
+                game.player_count = game.player_count.saturating_sub(1);
+                self.save_game(game).await.expect("Failed to update game");
+            }
+        }
+        self.state.registration_rejections.insert(&key, reason).expect("Failed to record rejection");
+    }
+
+    /// Sync a portfolio into the tournament behind a Traditional League game. On the same
+    /// chain a rejection comes back directly; otherwise leagues reports it on its stream.
+    async fn sync_with_leagues(&mut self, portfolio: &Portfolio) -> Result<(), String> {
+        let Some(tournament_id) = self.game_tournament(&portfolio.game_id).await else {
+            return Ok(());
+        };
+        let Some(leagues) = self.leagues_application() else {
+            self.request_leagues(CoinDraftsMessage::SyncPortfolio {
+                game_id: portfolio.game_id.clone(),
+                tournament_id,
+                portfolio: portfolio.clone(),
+            });
+            return Ok(());
+        };
+
+        let operation = TraditionalLeaguesOperation::SyncPortfolio {
+            game_id: portfolio.game_id.clone(),
+            tournament_id,
+            portfolio: portfolio.clone(),
+        };
+        match self.runtime.call_application(true, leagues, &operation) {
+            TraditionalLeaguesResponse::PortfolioSubmitted { success: true } => Ok(()),
+            TraditionalLeaguesResponse::PortfolioRejected { error } => Err(error.to_string()),
+            response => Err(format!("Unexpected Traditional Leagues response: {:?}", response)),
+        }
+    }
+
+    /// Hand a request for Traditional Leagues to its instance on this chain, which forwards it
+    /// to the leagues chain, logging it in the outbox until leagues acknowledges it
+    fn request_leagues(&mut self, request: CoinDraftsMessage) {
+        let chain_id = self.runtime.chain_id();
+        let message_id = self.state.generate_message_id(chain_id);
+        let envelope = MessageEnvelope::new(message_id.clone(), request);
+        let is_receipt = matches!(envelope.payload, CoinDraftsMessage::Acknowledge { .. });
+        let kind = envelope.payload.kind();
+
+        if !is_receipt {
+            self.state.outbox_messages.insert(&message_id, envelope.clone()).expect("Failed to keep request");
+        }
+        let delivery = self.deliver_to_leagues(envelope);
+        if !is_receipt {
+            let now = self.runtime.system_time().micros();
+            let mut record = MessageRecord::pending(message_id.clone(), kind, now);
+            if let Err(error) = delivery {
+                record.settle(Err(error), now);
+            }
+            self.state.outbox.insert(&message_id, record).expect("Failed to log request");
+        }
+    }
+
+    /// Pass a request to the Traditional Leagues instance on this chain
+    fn deliver_to_leagues(&mut self, envelope: MessageEnvelope<CoinDraftsMessage>) -> Result<(), String> {
+        let Some(leagues) = *self.state.leagues_application_id.get() else {
+            log::warn!("Request {} was not sent: Traditional Leagues is not connected", envelope.message_id);
+            return Err("Traditional Leagues is not connected".to_string());
+        };
+        let message_id = envelope.message_id.clone();
+        let operation = TraditionalLeaguesOperation::ReceiveCoreRequest { envelope };
+        match self.runtime.call_application(true, leagues.with_abi::<TraditionalLeaguesAbi>(), &operation) {
+            TraditionalLeaguesResponse::CoreRequestReceived { success: true } => Ok(()),
+            response => {
+                log::warn!("Traditional Leagues refused request {}: {:?}", message_id, response);
+                Err(format!("Traditional Leagues refused the request: {:?}", response))
+            }
+        }
+    }
+
+    /// Publish a lifecycle event for other applications and indexers
+    fn publish(&mut self, event: GameEvent) {
+        let stream_name = StreamName::from(event.stream_name());
+        self.runtime.emit(stream_name, &CoinDraftsEvent::Lifecycle(event));
+    }
+
+    /// Store a game, logging its status under the game's next revision when it changed
+    async fn save_game(&mut self, game: Game) -> Result<(), String> {
+        let previous = self.state.games.get(&game.game_id).await
+            .map_err(|e| format!("Failed to get game: {}", e))?;
+        if previous.map(|previous| previous.status) != Some(game.status) {
+            let revision = self.state.game_status_revisions.get(&game.game_id).await
+                .map_err(|e| format!("Failed to get game status revision: {}", e))?
+                .unwrap_or(0) + 1;
+            self.state.game_status_changes.insert(&(game.game_id.clone(), revision), game.status)
+                .map_err(|e| format!("Failed to record game status: {}", e))?;
+            self.state.game_status_revisions.insert(&game.game_id, revision)
+                .map_err(|e| format!("Failed to record game status: {}", e))?;
+        }
+        self.state.games.insert(&game.game_id.clone(), game)
+            .map_err(|e| format!("Failed to store game: {}", e))
+    }
+
+    /// Store a player's profile and advance the leaderboard revision
+    fn save_player(&mut self, account: &str, player: PlayerProfile) -> Result<(), String> {
+        self.state.leaderboard_revision.set(*self.state.leaderboard_revision.get() + 1);
+        self.state.players.insert(account, player)
+            .map_err(|e| format!("Failed to store player: {}", e))
+    }
+
+    /// Apply a notification FROM Traditional Leagues at most once and acknowledge it. A
+    /// notification whose receipt was lost is acknowledged again without being reapplied,
+    /// so a replayed `TournamentCompleted` never pays winners twice.
+    async fn receive_leagues_message(&mut self, envelope: MessageEnvelope<TraditionalLeaguesMessage>) {
+        let message_id = envelope.message_id.clone();
+        let message = match envelope.into_payload() {
+            Ok(message) => message,
+            Err(version) => {
+                log::warn!("Rejecting notification {} with unsupported protocol version {}", message_id, version);
+                self.acknowledge(message_id, Some(format!("Unsupported protocol version {}", version)));
+                return;
+            }
+        };
+        if matches!(message, TraditionalLeaguesMessage::Acknowledge { .. }) {
+            // Receipts are neither logged nor acknowledged themselves
+            let _ = self.handle_leagues_message(message).await;
+            return;
+        }
+
+        let now = self.runtime.system_time().micros();
+        let mut record = match self.state.inbox.get(&message_id).await {
+            Ok(Some(record)) if record.status == DeliveryStatus::Delivered => {
+                log::info!("Notification {} ({}) was already applied", message_id, record.kind);
+                self.acknowledge(message_id, None);
+                return;
+            }
+            Ok(Some(mut record)) => {
+                record.retry(now);
+                record
+            }
+            _ => MessageRecord::pending(message_id.clone(), message.kind(), now),
+        };
+
+        let outcome = self.handle_leagues_message(message).await;
+        record.settle(outcome.clone(), now);
+        self.state.inbox.insert(&message_id, record).expect("Failed to log notification");
+        self.acknowledge(message_id, outcome.err());
+    }
+
+    /// Send Traditional Leagues the receipt for one of its notifications, directly when it runs
+    /// on this chain
+    fn acknowledge(&mut self, message_id: String, error: Option<String>) {
+        let Some(leagues) = self.leagues_application() else {
+            self.request_leagues(CoinDraftsMessage::Acknowledge { message_id, error });
+            return;
+        };
+        let operation = TraditionalLeaguesOperation::Acknowledge { message_id: message_id.clone(), error };
+        if let Some(failure) = self.runtime.call_application(true, leagues, &operation).failure() {
+            log::warn!("Traditional Leagues did not take the receipt for {}: {}", message_id, failure);
+        }
+    }
+
+    /// Apply the receipt (or bounce) for a request sent to Traditional Leagues or the game chain
+    async fn settle_request(&mut self, message_id: String, error: Option<String>) {
+        let Ok(Some(mut record)) = self.state.outbox.get(&message_id).await else {
+            log::warn!("Receipt for unknown request {}", message_id);
+            return;
+        };
+        if let Some(error) = &error {
+            log::warn!("Request {} ({}) failed: {}", message_id, record.kind, error);
+        }
+
+        record.settle(error.map_or(Ok(()), Err), self.runtime.system_time().micros());
+        if record.status == DeliveryStatus::Delivered {
+            // Only failed requests are ever resent
+            self.state.outbox_messages.remove(&message_id).expect("Failed to drop delivered request");
+        }
+        self.state.outbox.insert(&message_id, record).expect("Failed to update request");
+    }
+
+    /// Resend a failed request under its original message ID, so leagues applies it at most once
+    async fn retry_request(&mut self, message_id: String) {
+        if !self.signed_by_admin() {
+            log::warn!("Only the application creator may retry requests");
+            return;
+        }
+
+        let mut record = match self.state.outbox.get(&message_id).await {
+            Ok(Some(record)) if record.status == DeliveryStatus::Failed => record,
+            _ => {
+                log::warn!("Request {} has not failed", message_id);
+                return;
+            }
+        };
+        let Ok(Some(envelope)) = self.state.outbox_messages.get(&message_id).await else {
+            log::error!("Request {} is not stored for retries", message_id);
+            return;
+        };
+
+        let now = self.runtime.system_time().micros();
+        record.retry(now);
+        if let Err(error) = self.deliver_to_leagues(envelope) {
+            record.settle(Err(error), now);
+        }
+        self.state.outbox.insert(&message_id, record).expect("Failed to update request");
+    }
+
+    /// Handle a notification FROM the Traditional Leagues application, read from the leagues
+    /// notification event stream
+    async fn handle_leagues_message(&mut self, message: TraditionalLeaguesMessage) -> Result<(), String> {
         match message {
             TraditionalLeaguesMessage::TournamentCreated { game_id, tournament_id, tournament_info } => {
-                // Handle tournament creation confirmation from Traditional Leagues
-                log::info!("Tournament {} created for game {}: {}", tournament_id, game_id, tournament_info);
-                
-                // Update game status to reflect tournament is ready
-                if let Ok(Some(mut game)) = self.state.games.get(&game_id).await {
-                    game.status = GameStatus::Active;
-                    let _ = self.state.games.insert(&game_id, game);
+                // Leagues answered our creation request with its own tournament ID
+                log::info!("Tournament {} created for game {}: {:?}", tournament_id, game_id, tournament_info);
+
+                let Ok(Some(mut game)) = self.state.games.get(&game_id).await else {
+                    log::warn!("Tournament {} created for unknown game {}", tournament_id, game_id);
+                    return Err(format!("Unknown game {}", game_id));
+                };
+                if game.tournament_id.as_ref() == Some(&tournament_id) {
+                    // Already linked by the same-chain call that created the tournament
+                    return Ok(());
+                }
+                if let Some(linked) = &game.tournament_id {
+                    log::warn!("Game {} is already linked to tournament {}, ignoring {}", game_id, linked, tournament_id);
+                    return Err(format!("Game {} is already linked to tournament {}", game_id, linked));
                 }
+
+                // Update game status to reflect tournament is ready
+                game.tournament_id = Some(tournament_id.clone());
+                game.status = GameStatus::Active;
+                self.save_game(game).await.expect("Failed to link game to tournament");
+                self.state.tournament_games.insert(&tournament_id, game_id).expect("Failed to link tournament to game");
+                Ok(())
             }
             
             TraditionalLeaguesMessage::TournamentCompleted { game_id, tournament_id, winners, total_prize_pool } => {
@@ -377,57 +923,137 @@ impl Contract for CoinDraftsContract {
                 log::info!("Tournament {} completed for game {} with {} winners, prize pool: {}", 
                           tournament_id, game_id, winners.len(), total_prize_pool);
                 
-                // Update game status to completed
-                if let Ok(Some(mut game)) = self.state.games.get(&game_id).await {
-                    game.status = GameStatus::Completed;
-                    let _ = self.state.games.insert(&game_id, game);
+                // Update game status to completed, paying out only once per game
+                let Ok(Some(mut game)) = self.state.games.get(&game_id).await else {
+                    return Err(format!("Unknown game {}", game_id));
+                };
+                if game.status == GameStatus::Completed {
+                    log::info!("Game {} was already settled", game_id);
+                    return Ok(());
                 }
+                game.status = GameStatus::Completed;
+                let _ = self.save_game(game).await;
                 
                 // Update player stats for winners (simple distribution)
                 let prize_per_winner = if !winners.is_empty() { total_prize_pool / winners.len() as u64 } else { 0 };
+                let settled_winners = winners.clone();
                 for winner in winners {
                     if let Ok(Some(mut player)) = self.state.players.get(&winner).await {
                         player.stats.games_won += 1;
                         player.stats.games_played += 1;
-                        player.total_earnings_usdc += prize_per_winner;
-                        let _ = self.state.players.insert(&winner, player);
+                        player.total_earnings_usdc = player.total_earnings_usdc.saturating_add(prize_per_winner);
+                        let _ = self.save_player(&winner, player);
                     }
                 }
+                let settled_at = self.runtime.system_time().micros();
+                self.publish(GameEvent::GameSettled { game_id, winners: settled_winners, settled_at });
+                Ok(())
             }
             
-            TraditionalLeaguesMessage::PlayerVerified { game_id, player_account, verified } => {
-                // Handle player verification response from Traditional Leagues
-                log::info!("Player {} verification for game {}: {}", player_account, game_id, verified);
-                
-                // Update player status based on verification
+            TraditionalLeaguesMessage::VerifyPlayer { game_id, player_account, tournament_id } => {
+                // Leagues holds the registration until we confirm the profile and entry payment
+                log::info!("Verification requested for player {} in tournament {} (game {})",
+                          player_account, tournament_id, game_id);
+
+                let verification = self.verify_player(&game_id, &player_account).await;
+                if let Err(reason) = self.answer_verification(&game_id, tournament_id, &player_account, verification) {
+                    self.reject_registration(&game_id, &player_account, reason).await;
+                }
+                Ok(())
+            }
+
+            TraditionalLeaguesMessage::PlayerVerified { game_id, tournament_id, player_account, verified, reason } => {
+                // Final outcome of a held registration
+                log::info!("Player {} verification for tournament {} (game {}): {}", player_account, tournament_id, game_id, verified);
+
                 if verified {
-                    if let Ok(Some(player)) = self.state.players.get(&player_account).await {
-                        // Could add verification status to player profile
-                        let _ = self.state.players.insert(&player_account, player);
-                    }
+                    let _ = self.state.registration_rejections.remove(&(game_id, player_account));
+                } else {
+                    let reason = reason.unwrap_or_else(|| "Rejected by Traditional Leagues".to_string());
+                    self.reject_registration(&game_id, &player_account, reason).await;
                 }
+                Ok(())
             }
-            
+
+            TraditionalLeaguesMessage::PortfolioSyncFailed { game_id, tournament_id, player_account, reason } => {
+                // The portfolio never made it into the tournament, so it cannot count
+                log::warn!("Portfolio of {} for tournament {} (game {}) failed to sync: {}",
+                          player_account, tournament_id, game_id, reason);
+
+                let key = (game_id, player_account);
+                if let Ok(Some(mut portfolio)) = self.state.portfolios.get(&key).await {
+                    portfolio.status = PortfolioStatus::Invalid;
+                    self.state.upsert_portfolio(portfolio).await.expect("Failed to update portfolio");
+                }
+                self.state.portfolio_rejections.insert(&key, reason).expect("Failed to record rejection");
+                Ok(())
+            }
+
             TraditionalLeaguesMessage::TournamentStatusUpdate { tournament_id, status, current_round } => {
                 // Handle tournament status updates from Traditional Leagues
-                log::info!("Tournament {} status update: {} (round {})", tournament_id, status, current_round);
+                log::info!("Tournament {} status update: {:?} (round {})", tournament_id, status, current_round);
                 
                 // Could update local game state based on tournament progress
                 // For now, just acknowledge the status update
+                Ok(())
+            }
+
+            TraditionalLeaguesMessage::PlayerUnregistered { game_id, tournament_id, player_account, refund_usdc } => {
+                // Keep the linked game in step with the tournament's participant list
+                log::info!("Player {} withdrew from tournament {} (game {}), refunded {}",
+                          player_account, tournament_id, game_id, refund_usdc);
+
+                let key = (game_id.clone(), player_account.clone());
+                if !self.state.entry_payments.contains_key(&key).await.unwrap_or(false) {
+                    log::warn!("Player {} has no entry in game {} to refund", player_account, game_id);
+                    return Err(format!("Player {} has not joined game {}", player_account, game_id));
+                }
+
+                // Free the entry so the player can join again, and keep the refund on record
+                let refunded = self.state.refunds.get(&key).await.ok().flatten().unwrap_or(0);
+                self.state.entry_payments.remove(&key).expect("Failed to refund entry fee");
+                self.state.refunds.insert(&key, refunded.saturating_add(refund_usdc)).expect("Failed to record refund");
+                if let Ok(Some(mut game)) = self.state.games.get(&game_id).await {
+                    game.player_count = game.player_count.saturating_sub(1);
+                    self.save_game(game).await.expect("Failed to update game");
+                }
+                self.state.remove_portfolio(&game_id, &player_account).await?;
+                Ok(())
+            }
+
+            TraditionalLeaguesMessage::TournamentCancelled { game_id, tournament_id, reason, refunded_players, refund_usdc } => {
+                // The linked game can no longer be played
+                log::info!("Tournament {} for game {} cancelled ({}), {} entrants refunded {} each",
+                          tournament_id, game_id, reason, refunded_players.len(), refund_usdc);
+
+                if let Ok(Some(mut game)) = self.state.games.get(&game_id).await {
+                    game.status = GameStatus::Cancelled;
+                    let _ = self.save_game(game).await;
+                }
+                Ok(())
+            }
+
+            TraditionalLeaguesMessage::SeasonEnded { season_id, standings, bonus_pool_usdc } => {
+                // Season boundary; tier resets could hook in here
+                log::info!("Season {} ended with {} ranked players and a {} USDC bonus pool",
+                          season_id, standings.len(), bonus_pool_usdc);
+                Ok(())
+            }
+
+            TraditionalLeaguesMessage::Acknowledge { message_id, error } => {
+                self.settle_request(message_id, error).await;
+                Ok(())
             }
         }
     }
-}
 
-// Helper functions for CoinDraftsContract
-impl CoinDraftsContract {
     /// Check and unlock achievements for a player after a game
     async fn check_and_unlock_achievements(
         &mut self,
         player_account: &str,
         game_id: &str,
         rank: u32,
-        portfolio_return: i64,
+        portfolio_return: Fixed,
         player_profile: &PlayerProfile,
     ) -> Result<Vec<Achievement>, ()> {
         let timestamp = self.runtime.system_time().micros();
@@ -470,7 +1096,7 @@ impl CoinDraftsContract {
         }
 
         // Achievement: Perfect Portfolio (>50% return = 5000 basis points)
-        if rank == 1 && portfolio_return > 5000 {
+        if rank == 1 && portfolio_return > Fixed::from_percent(50) {
             let achievement = Achievement {
                 id: "perfect_portfolio".to_string(),
                 achievement_type: AchievementType::PerfectPortfolio,
@@ -498,39 +1124,76 @@ impl CoinDraftsContract {
         if !newly_unlocked.is_empty() {
             log::info!("Player {} unlocked {} achievements", player_account, newly_unlocked.len());
         }
+        for achievement in &newly_unlocked {
+            self.publish(GameEvent::AchievementUnlocked {
+                player_account: player_account.to_string(),
+                achievement_id: achievement.id.clone(),
+                game_id: achievement.game_id.clone(),
+                unlocked_at: achievement.unlocked_at,
+            });
+        }
 
         Ok(newly_unlocked)
     }
 
-    /// Calculate player tier based on games and wins
+    /// Calculate player tier based on games and wins. The win rate is rounded down, so a
+    /// threshold is only reached by actually meeting it.
     fn calculate_tier(games_played: u32, wins: u32) -> PlayerTier {
         let win_rate = if games_played > 0 {
-            (wins as f64 / games_played as f64) * 100.0
+            Fixed::from_ratio(wins as i128, games_played as i128, Rounding::Down).unwrap_or(Fixed::ZERO)
         } else {
-            0.0
+            Fixed::ZERO
         };
 
         match (games_played, win_rate) {
             (0..=4, _) => PlayerTier::Rookie,
             (5..=14, _) => PlayerTier::Bronze,
-            (15..=49, wr) if wr >= 30.0 => PlayerTier::Silver,
-            (50..=99, wr) if wr >= 40.0 => PlayerTier::Gold,
-            (100..=249, wr) if wr >= 45.0 => PlayerTier::Platinum,
-            (250..=499, wr) if wr >= 50.0 => PlayerTier::Diamond,
-            (500.., wr) if wr >= 55.0 => PlayerTier::Master,
+            (15..=49, wr) if wr >= Fixed::from_percent(30) => PlayerTier::Silver,
+            (50..=99, wr) if wr >= Fixed::from_percent(40) => PlayerTier::Gold,
+            (100..=249, wr) if wr >= Fixed::from_percent(45) => PlayerTier::Platinum,
+            (250..=499, wr) if wr >= Fixed::from_percent(50) => PlayerTier::Diamond,
+            (500.., wr) if wr >= Fixed::from_percent(55) => PlayerTier::Master,
             _ => PlayerTier::Bronze,
         }
     }
 
-    /// Calculate portfolio return from start to end prices
+    /// Split the prize pool between the top 3 finishers (50% / 30% / 20%)
+    fn calculate_prizes(entry_fee_usdc: u64, entrants: u64) -> FixedResult<Vec<u64>> {
+        let total_pool = entry_fee_usdc
+            .checked_mul(entrants)
+            .ok_or(ArithmeticError::Overflow)?;
+
+        [50, 30, 20]
+            .into_iter()
+            .map(|percent| Fixed::from_percent(percent).apply_to_amount(total_pool, Rounding::Down))
+            .collect()
+    }
+
+    /// Equal-allocation holdings for the given cryptocurrency IDs, short where listed in `short_picks`.
+    /// The percent left over by the division goes to the first holdings, one each, so the total is 100.
+    fn equal_holdings(cryptocurrencies: Vec<String>, short_picks: Vec<String>) -> Vec<CryptoHolding> {
+        let count = cryptocurrencies.len().max(1);
+        let (share, remainder) = (100 / count, 100 % count);
+        cryptocurrencies.into_iter().enumerate().map(|(index, crypto_id)| {
+            let allocation = if index < remainder { share + 1 } else { share };
+            CryptoHolding {
+                short: short_picks.contains(&crypto_id),
+                symbol: crypto_id,  // Store crypto ID (e.g., "bitcoin") to match price snapshots
+                allocation_percent: allocation as u8,
+            }
+        }).collect()
+    }
+
+    /// Calculate portfolio return from start to end prices. Short holdings score the inverted return.
     fn calculate_portfolio_return(
-        cryptocurrencies: &[String],
+        holdings: &[CryptoHolding],
         start_prices: &[PriceSnapshot],
         end_prices: &[PriceSnapshot]
-    ) -> i64 {
-        let mut total_return: i64 = 0;
+    ) -> FixedResult<Fixed> {
+        let mut total_return = Fixed::ZERO;
 
-        for crypto_id in cryptocurrencies {
+        for holding in holdings {
+            let crypto_id = &holding.symbol;
             let start_price = start_prices.iter()
                 .find(|p| p.crypto_id == *crypto_id)
                 .map(|p| p.price_usd)
@@ -542,12 +1205,371 @@ impl CoinDraftsContract {
                 .unwrap_or(0);
 
             if start_price > 0 {
-                // Calculate percentage return (* 10000 for basis points precision)
-                let return_pct = ((end_price as i128 - start_price as i128) * 10000) / start_price as i128;
-                total_return += return_pct as i64;
+                // Percentage return in fixed point (basis points precision)
+                let return_pct = Fixed::percent_change(start_price, end_price, Rounding::HalfEven)?;
+                total_return = total_return.checked_add(holding.position_return(return_pct)?)?;
+            }
+        }
+
+        Ok(total_return)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::RefCell;
+    use std::collections::BTreeMap;
+
+    use coindrafts_core::{TournamentInfo, TournamentStatus, TournamentType};
+    use linera_sdk::{
+        bcs,
+        linera_base_types::{AccountOwner, CryptoHash, StreamId, Timestamp},
+        util::BlockingWait,
+        views::View,
+        Contract, ContractRuntime,
+    };
+
+    use super::*;
+
+    fn chain(name: &str) -> ChainId {
+        ChainId(CryptoHash::test_hash(name))
+    }
+
+    fn leagues_id() -> ApplicationId {
+        ApplicationId::new(CryptoHash::test_hash("leagues"))
+    }
+
+    /// Events on the streams of each application, keyed by (application, chain, index), and the
+    /// requests core handed to Traditional Leagues
+    #[derive(Default)]
+    struct Leagues {
+        events: BTreeMap<(ApplicationId, ChainId, u32), CoinDraftsEvent>,
+        requests: Vec<MessageEnvelope<CoinDraftsMessage>>,
+    }
+
+    thread_local! {
+        static LEAGUES: RefCell<Leagues> = RefCell::default();
+    }
+
+    /// Answer core's calls the way Traditional Leagues does: it reads notifications from its
+    /// own streams only
+    fn call_leagues(authenticated: bool, application_id: ApplicationId, operation: Vec<u8>) -> Vec<u8> {
+        assert!(authenticated, "Core calls leagues authenticated");
+        let operation: TraditionalLeaguesOperation = bcs::from_bytes(&operation).expect("Failed to deserialize call");
+        let response = LEAGUES.with_borrow_mut(|leagues| match operation {
+            TraditionalLeaguesOperation::ReadNotification { chain_id, index } => {
+                let envelope = match leagues.events.get(&(application_id, chain_id, index)) {
+                    Some(CoinDraftsEvent::Notification(envelope)) => Some(envelope.clone()),
+                    _ => None,
+                };
+                TraditionalLeaguesResponse::Notification { envelope }
+            }
+            TraditionalLeaguesOperation::ReceiveCoreRequest { envelope } => {
+                leagues.requests.push(envelope);
+                TraditionalLeaguesResponse::CoreRequestReceived { success: true }
             }
+            operation => panic!("Unexpected call to Traditional Leagues: {:?}", operation),
+        });
+        bcs::to_bytes(&response).expect("Failed to serialize response")
+    }
+
+    /// Core on the game chain, created and administered by `admin`
+    fn create_contract() -> CoinDraftsContract {
+        let admin = AccountOwner::Address32(CryptoHash::test_hash("admin"));
+        let runtime = ContractRuntime::new()
+            .with_application_parameters(())
+            .with_chain_id(chain("game"))
+            .with_application_creator_chain_id(chain("game"))
+            .with_authenticated_signer(admin)
+            .with_system_time(Timestamp::from(1_000))
+            .with_call_application_handler(call_leagues);
+        let state = CoinDraftsState::load(runtime.root_view_storage_context())
+            .blocking_wait()
+            .expect("Failed to load state");
+        let mut contract = CoinDraftsContract { state, runtime };
+        contract.instantiate(()).blocking_wait();
+        contract
+    }
+
+    /// Publish a notification at `index` of `application`'s notification stream on the leagues chain
+    fn emit_notification(application: ApplicationId, index: u32, message_id: &str, message: TraditionalLeaguesMessage) {
+        let event = CoinDraftsEvent::Notification(MessageEnvelope::new(message_id.to_string(), message));
+        LEAGUES.with_borrow_mut(|leagues| leagues.events.insert((application, chain("leagues"), index), event));
+    }
+
+    /// Publish a leagues notification at `index` of its stream on the leagues chain
+    fn add_notification(index: u32, message_id: &str, message: TraditionalLeaguesMessage) {
+        emit_notification(leagues_id(), index, message_id, message);
+    }
+
+    /// Process the leagues notifications from `previous_index` up to `next_index`
+    fn process_notifications(contract: &mut CoinDraftsContract, previous_index: u32, next_index: u32) {
+        contract.process_streams(vec![StreamUpdate {
+            chain_id: chain("leagues"),
+            stream_id: StreamId {
+                application_id: GenericApplicationId::User(leagues_id()),
+                stream_name: StreamName::from(CORE_NOTIFICATION_STREAM),
+            },
+            previous_index,
+            next_index,
+        }]).blocking_wait();
+    }
+
+    /// Connect to leagues on its own chain and create a Traditional League game, "game_1"
+    fn create_league_game(contract: &mut CoinDraftsContract) {
+        contract.execute_operation(CoinDraftsOperation::ConnectLeagues {
+            application_id: leagues_id(),
+            chain_id: chain("leagues"),
+        }).blocking_wait();
+        contract.execute_operation(CoinDraftsOperation::CreateGame {
+            mode: GameMode::TraditionalLeague,
+            name: "Weekly League".to_string(),
+            max_players: 10,
+            entry_fee_usdc: 5,
+            duration_hours: 24,
+        }).blocking_wait();
+    }
+
+    fn tournament_created() -> TraditionalLeaguesMessage {
+        TraditionalLeaguesMessage::TournamentCreated {
+            game_id: "game_1".to_string(),
+            tournament_id: "tournament_1".to_string(),
+            tournament_info: TournamentInfo {
+                tournament_id: "tournament_1".to_string(),
+                name: "Weekly League".to_string(),
+                tournament_type: TournamentType::RoundRobin,
+                status: TournamentStatus::Registration,
+                entry_fee_usdc: 5,
+                max_participants: 10,
+                current_participants: 0,
+                duration_minutes: 24 * 60,
+            },
+        }
+    }
+
+    #[test]
+    fn test_cross_chain_tournament_round_trip() {
+        let mut contract = create_contract();
+        create_league_game(&mut contract);
+        assert!(contract.leagues_application().is_none());
+
+        let game = contract.state.games.get("game_1").blocking_wait().unwrap().unwrap();
+        assert_eq!(game.status, GameStatus::WaitingForPlayers);
+        assert_eq!(game.tournament_id, None);
+        let request_id = format!("{}-1", chain("game"));
+        let request = contract.state.outbox.get(&request_id).blocking_wait().unwrap().unwrap();
+        assert_eq!(request.kind, "CreateTournament");
+        assert_eq!(request.status, DeliveryStatus::Pending);
+        let handed_over = LEAGUES.with_borrow(|leagues| leagues.requests.iter().map(|request| request.message_id.clone()).collect::<Vec<_>>());
+        assert_eq!(handed_over, vec![request_id.clone()]);
+
+        // Leagues answers on its own chain: the tournament it created, then the receipt
+        add_notification(0, "leagues-1", tournament_created());
+        add_notification(1, "leagues-2", TraditionalLeaguesMessage::Acknowledge {
+            message_id: request_id.clone(),
+            error: None,
+        });
+        process_notifications(&mut contract, 0, 2);
+
+        let game = contract.state.games.get("game_1").blocking_wait().unwrap().unwrap();
+        assert_eq!(game.status, GameStatus::Active);
+        assert_eq!(game.tournament_id.as_deref(), Some("tournament_1"));
+        let linked = contract.state.tournament_games.get("tournament_1").blocking_wait().unwrap();
+        assert_eq!(linked.as_deref(), Some("game_1"));
+        let request = contract.state.outbox.get(&request_id).blocking_wait().unwrap().unwrap();
+        assert_eq!(request.status, DeliveryStatus::Delivered);
+        let notification = contract.state.inbox.get("leagues-1").blocking_wait().unwrap().unwrap();
+        assert_eq!(notification.status, DeliveryStatus::Delivered);
+
+        // Both statuses are logged for subscribers, the unchanged saves in between are not
+        assert_eq!(contract.state.game_status_revisions.get("game_1").blocking_wait().unwrap(), Some(2));
+        let statuses = [1, 2].map(|revision| {
+            contract.state.game_status_changes.get(&("game_1".to_string(), revision)).blocking_wait().unwrap()
+        });
+        assert_eq!(statuses, [Some(GameStatus::WaitingForPlayers), Some(GameStatus::Active)]);
+
+        // Core acknowledged the notification through leagues too
+        let receipts = LEAGUES.with_borrow(|leagues| leagues.requests.iter().filter(|request| {
+            matches!(&request.payload, CoinDraftsMessage::Acknowledge { message_id, .. } if message_id == "leagues-1")
+        }).count());
+        assert_eq!(receipts, 1);
+    }
+
+    #[test]
+    fn test_notifications_are_read_from_the_leagues_stream_only() {
+        let mut contract = create_contract();
+        create_league_game(&mut contract);
+
+        // An event at the same index of another application's stream is not a leagues notification
+        let impostor = ApplicationId::new(CryptoHash::test_hash("impostor"));
+        emit_notification(impostor, 0, "impostor-1", tournament_created());
+        process_notifications(&mut contract, 0, 1);
+        let game = contract.state.games.get("game_1").blocking_wait().unwrap().unwrap();
+        assert_eq!(game.tournament_id, None);
+        assert!(contract.state.inbox.get("impostor-1").blocking_wait().unwrap().is_none());
+
+        add_notification(0, "leagues-1", tournament_created());
+        process_notifications(&mut contract, 0, 1);
+        let game = contract.state.games.get("game_1").blocking_wait().unwrap().unwrap();
+        assert_eq!(game.tournament_id.as_deref(), Some("tournament_1"));
+    }
+
+    #[test]
+    fn test_withdrawn_player_is_refunded_and_can_rejoin() {
+        let mut contract = create_contract();
+        create_league_game(&mut contract);
+        add_notification(0, "leagues-1", tournament_created());
+        process_notifications(&mut contract, 0, 1);
+
+        let player = AccountOwner::Address32(CryptoHash::test_hash("player"));
+        let key = ("game_1".to_string(), player.to_string());
+        let join = || CoinDraftsOperation::RegisterPlayer {
+            game_id: "game_1".to_string(),
+            player_name: "Player".to_string(),
+        };
+        contract.runtime.set_authenticated_signer(player);
+        contract.execute_operation(join()).blocking_wait();
+        assert_eq!(contract.state.entry_payments.get(&key).blocking_wait().unwrap(), Some(5));
+
+        add_notification(1, "leagues-2", TraditionalLeaguesMessage::PlayerUnregistered {
+            game_id: "game_1".to_string(),
+            tournament_id: "tournament_1".to_string(),
+            player_account: player.to_string(),
+            refund_usdc: 5,
+        });
+        process_notifications(&mut contract, 1, 2);
+
+        assert_eq!(contract.state.entry_payments.get(&key).blocking_wait().unwrap(), None);
+        assert_eq!(contract.state.refunds.get(&key).blocking_wait().unwrap(), Some(5));
+        let game = contract.state.games.get("game_1").blocking_wait().unwrap().unwrap();
+        assert_eq!(game.player_count, 0);
+
+        contract.execute_operation(join()).blocking_wait();
+        assert_eq!(contract.state.entry_payments.get(&key).blocking_wait().unwrap(), Some(5));
+        let game = contract.state.games.get("game_1").blocking_wait().unwrap().unwrap();
+        assert_eq!(game.player_count, 1);
+    }
+
+    #[test]
+    fn test_portfolio_changes_advance_the_revision() {
+        let mut contract = create_contract();
+        create_league_game(&mut contract);
+        add_notification(0, "leagues-1", tournament_created());
+        process_notifications(&mut contract, 0, 1);
+
+        let player = AccountOwner::Address32(CryptoHash::test_hash("player"));
+        contract.runtime.set_authenticated_signer(player);
+        contract.execute_operation(CoinDraftsOperation::RegisterPlayer {
+            game_id: "game_1".to_string(),
+            player_name: "Player".to_string(),
+        }).blocking_wait();
+        for cryptocurrencies in [["bitcoin", "ethereum"], ["bitcoin", "solana"]] {
+            contract.execute_operation(CoinDraftsOperation::SubmitPortfolio {
+                game_id: "game_1".to_string(),
+                cryptocurrencies: cryptocurrencies.map(str::to_string).to_vec(),
+                short_picks: None,
+            }).blocking_wait();
         }
+        assert_eq!(contract.state.portfolio_revisions.get("game_1").blocking_wait().unwrap(), Some(2));
+
+        add_notification(1, "leagues-2", TraditionalLeaguesMessage::PlayerUnregistered {
+            game_id: "game_1".to_string(),
+            tournament_id: "tournament_1".to_string(),
+            player_account: player.to_string(),
+            refund_usdc: 5,
+        });
+        process_notifications(&mut contract, 1, 2);
+
+        assert_eq!(contract.state.portfolio_revisions.get("game_1").blocking_wait().unwrap(), Some(3));
+        for revision in 1..=3 {
+            let change = contract.state.portfolio_changes.get(&("game_1".to_string(), revision)).blocking_wait().unwrap();
+            assert_eq!(change, Some(player.to_string()));
+        }
+        assert_eq!(contract.state.portfolio_count("game_1").blocking_wait().unwrap(), 0);
+    }
 
-        total_return
+    #[test]
+    fn test_tier_thresholds_are_inclusive() {
+        assert_eq!(CoinDraftsContract::calculate_tier(0, 0), PlayerTier::Rookie);
+        assert_eq!(CoinDraftsContract::calculate_tier(15, 4), PlayerTier::Bronze);
+        assert_eq!(CoinDraftsContract::calculate_tier(20, 6), PlayerTier::Silver);
+        assert_eq!(CoinDraftsContract::calculate_tier(50, 20), PlayerTier::Gold);
+        assert_eq!(CoinDraftsContract::calculate_tier(300, 149), PlayerTier::Bronze);
+        assert_eq!(CoinDraftsContract::calculate_tier(300, 150), PlayerTier::Diamond);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_equal_holdings_total_one_hundred_percent() {
+        let cryptocurrencies = ["bitcoin", "ethereum", "solana"].map(str::to_string).to_vec();
+        let holdings = CoinDraftsContract::equal_holdings(cryptocurrencies, vec![]);
+        let allocations: Vec<u8> = holdings.iter().map(|h| h.allocation_percent).collect();
+        assert_eq!(allocations, vec![34, 33, 33]);
+
+        let mut contract = create_contract();
+        let too_many = (0..=MAX_HOLDINGS).map(|i| format!("coin-{}", i)).collect();
+        let result = contract.submit_portfolio("game_1".to_string(), "player".to_string(), too_many, None).blocking_wait();
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_rejected_portfolios_are_not_scored() {
+        let mut contract = create_contract();
+        let admin = contract.runtime.authenticated_signer();
+        create_league_game(&mut contract);
+        add_notification(0, "leagues-1", tournament_created());
+        process_notifications(&mut contract, 0, 1);
+
+        let cryptocurrencies = vec!["bitcoin".to_string(), "ethereum".to_string()];
+        let players = ["valid", "rejected"].map(|name| AccountOwner::Address32(CryptoHash::test_hash(name)));
+        for player in players {
+            contract.runtime.set_authenticated_signer(player);
+            contract.execute_operation(CoinDraftsOperation::RegisterPlayer {
+                game_id: "game_1".to_string(),
+                player_name: "Player".to_string(),
+            }).blocking_wait();
+            contract.execute_operation(CoinDraftsOperation::SubmitPortfolio {
+                game_id: "game_1".to_string(),
+                cryptocurrencies: cryptocurrencies.clone(),
+                short_picks: None,
+            }).blocking_wait();
+        }
+        add_notification(1, "leagues-2", TraditionalLeaguesMessage::PortfolioSyncFailed {
+            game_id: "game_1".to_string(),
+            tournament_id: "tournament_1".to_string(),
+            player_account: players[1].to_string(),
+            reason: "Wrong number of picks".to_string(),
+        });
+        process_notifications(&mut contract, 1, 2);
+
+        let prices = |price_usd| cryptocurrencies.iter().map(|crypto_id| PriceSnapshot {
+            crypto_id: crypto_id.clone(),
+            price_usd,
+            timestamp: 1_000,
+        }).collect::<Vec<_>>();
+        contract.runtime.set_authenticated_signer(admin);
+        contract.execute_operation(CoinDraftsOperation::StartGame {
+            game_id: "game_1".to_string(),
+            price_snapshot: prices(1_000_000),
+        }).blocking_wait();
+        contract.execute_operation(CoinDraftsOperation::EndGame {
+            game_id: "game_1".to_string(),
+            price_snapshot: prices(1_100_000),
+        }).blocking_wait();
+
+        let game = contract.state.games.get("game_1").blocking_wait().unwrap().unwrap();
+        assert_eq!(game.status, GameStatus::Completed);
+        assert_eq!(game.winners, vec![players[0].to_string()]);
+        let result = contract.state.game_history
+            .get(&(players[0].to_string(), "game_1".to_string()))
+            .blocking_wait()
+            .unwrap()
+            .expect("Missing result");
+        // Both entries fund the 10 USDC pool; first place takes half
+        assert_eq!((result.rank, result.prize_won), (1, 5));
+        let rejected = contract.state.game_history
+            .get(&(players[1].to_string(), "game_1".to_string()))
+            .blocking_wait()
+            .unwrap();
+        assert!(rejected.is_none());
+    }
+}
@@ -10,25 +10,21 @@ across the entire Linera microchain ecosystem.
 
 mod types;
 
-use async_graphql::{Request, Response, SimpleObject, Enum, InputObject};
+use async_graphql::{Request, Response, SimpleObject, Enum};
 use linera_sdk::{
     graphql::GraphQLMutationRoot,
-    linera_base_types::{ContractAbi, ServiceAbi},
+    linera_base_types::{ApplicationId, ChainId, ContractAbi, ServiceAbi},
 };
 use serde::{Deserialize, Serialize};
 
 // Re-export types for external use
 pub use types::*;
 
-/// Price snapshot at a specific timestamp
-#[derive(Debug, Clone, Serialize, Deserialize, SimpleObject, InputObject)]
-#[graphql(input_name = "PriceSnapshotInput")]
-pub struct PriceSnapshot {
-    pub crypto_id: String,
-    /// Price in micro-units (price * 1_000_000 for 6 decimal precision)
-    pub price_usd: u64,
-    pub timestamp: u64,
-}
+// Cross-application message protocol shared with the game mode applications
+pub use coindrafts_common::{
+    ArithmeticError, CoinDraftsEvent, CoinDraftsMessage, CORE_NOTIFICATION_STREAM, DeliveryStatus, execute, Fixed, FixedResult, GameEvent, MessageEnvelope, MessageRecord, page_slots, Rounding, PriceSnapshot, TournamentInfo, TournamentStatus,
+    TournamentType, TraditionalLeaguesMessage, ACHIEVEMENT_EVENT_STREAM, GAME_EVENT_STREAM,
+};
 
 pub struct CoinDraftsAbi;
 
@@ -51,12 +47,52 @@ pub enum CoinDraftsOperation {
         entry_fee_usdc: u64,
         duration_hours: u64
     },
+    /// Join a game as the signer. From a player's own chain this is sent to the game chain.
     RegisterPlayer { game_id: String, player_name: String },
+    /// Join a game on behalf of another account (application creator only, for seeding)
     RegisterPlayerWithAccount { game_id: String, player_name: String, player_account: String },
-    SubmitPortfolio { game_id: String, cryptocurrencies: Vec<String> },
-    SubmitPortfolioForAccount { game_id: String, player_account: String, cryptocurrencies: Vec<String> },
+    /// Submit the signer's portfolio, replacing any earlier one. From a player's own chain this
+    /// is sent to the game chain. `short_picks` lists the cryptocurrencies held short (returns inverted)
+    SubmitPortfolio { game_id: String, cryptocurrencies: Vec<String>, short_picks: Option<Vec<String>> },
+    /// Replace the signer's submitted portfolio before the game starts. From a player's own chain
+    /// this is sent to the game chain.
+    UpdatePortfolio { game_id: String, cryptocurrencies: Vec<String>, short_picks: Option<Vec<String>> },
+    /// Submit a portfolio on behalf of another account (application creator only, for seeding)
+    SubmitPortfolioForAccount { game_id: String, player_account: String, cryptocurrencies: Vec<String>, short_picks: Option<Vec<String>> },
     StartGame { game_id: String, price_snapshot: Vec<PriceSnapshot> },
     EndGame { game_id: String, price_snapshot: Vec<PriceSnapshot> },
+    /// Resend a request Traditional Leagues failed to apply (application creator only)
+    RetryMessage { message_id: String },
+    /// Follow the Traditional Leagues application on `chain_id` and call it directly when it
+    /// runs on this chain (application creator only)
+    ConnectLeagues { application_id: ApplicationId, chain_id: ChainId },
+}
+
+/// Messages between CoinDrafts Core chains. Players act from their own microchains: their
+/// requests travel, tracked and signed, to the game chain, which trusts the signer as the
+/// player's account and answers applied requests with a receipt. Rejected requests bounce.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum CoinDraftsCoreMessage {
+    /// Join a game
+    JoinGame { game_id: String, player_name: String },
+    /// Submit a portfolio, replacing any earlier one
+    SubmitPortfolio { game_id: String, cryptocurrencies: Vec<String>, short_picks: Option<Vec<String>> },
+    /// Replace a submitted portfolio before the game starts
+    UpdatePortfolio { game_id: String, cryptocurrencies: Vec<String>, short_picks: Option<Vec<String>> },
+    /// Receipt from the game chain: the player request `message_id` was applied
+    RequestApplied { message_id: String },
+}
+
+impl CoinDraftsCoreMessage {
+    /// Variant name, for delivery logs
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Self::JoinGame { .. } => "JoinGame",
+            Self::SubmitPortfolio { .. } => "SubmitPortfolio",
+            Self::UpdatePortfolio { .. } => "UpdatePortfolio",
+            Self::RequestApplied { .. } => "RequestApplied",
+        }
+    }
 }
 
 // Game status enum
@@ -65,6 +101,7 @@ pub enum GameStatus {
     WaitingForPlayers,
     Active,
     Completed,
+    Cancelled,
 }
 
 // Core game structure for GraphQL
@@ -81,70 +118,6 @@ pub struct Game {
     pub duration_hours: u64,
     pub start_prices: Option<Vec<PriceSnapshot>>,
     pub winners: Vec<String>,
+    /// Traditional Leagues tournament backing this game, set once leagues confirms creation
+    pub tournament_id: Option<String>,
 }
-
-/// Cross-chain messages for coordinating with game mode applications
-#[derive(Debug, Serialize, Deserialize)]
-pub enum CoinDraftsMessage {
-    /// Request to create a tournament for a Traditional League game
-    CreateTournament {
-        game_id: String,
-        tournament_name: String,
-        entry_fee_usdc: u64,
-        max_participants: u32,
-    },
-    /// Register a player for a specific tournament
-    RegisterPlayerForTournament {
-        game_id: String,
-        tournament_id: String,
-        player_profile: PlayerProfile,
-    },
-    /// Sync portfolio submission to tournament application
-    SyncPortfolio {
-        game_id: String,
-        tournament_id: String,
-        portfolio: Portfolio,
-    },
-    /// Request tournament status update
-    GetTournamentStatus {
-        tournament_id: String,
-    },
-    /// Response to player verification request
-    VerifyPlayer {
-        game_id: String,
-        player_account: String,
-        tournament_id: String,
-        verified: bool,
-    },
-}
-
-/// Messages received from Traditional Leagues application
-#[derive(Debug, Serialize, Deserialize)]
-pub enum TraditionalLeaguesMessage {
-    /// Tournament created confirmation from Traditional Leagues
-    TournamentCreated {
-        game_id: String,
-        tournament_id: String,
-        tournament_info: String, // JSON serialized tournament info
-    },
-    /// Tournament completed notification
-    TournamentCompleted {
-        game_id: String,
-        tournament_id: String,
-        winners: Vec<String>,
-        total_prize_pool: u64,
-    },
-    /// Player verification response
-    PlayerVerified {
-        game_id: String,
-        player_account: String,
-        verified: bool,
-    },
-    /// Tournament status update
-    TournamentStatusUpdate {
-        tournament_id: String,
-        status: String, // Serialized tournament status
-        current_round: u32,
-    },
-}
-
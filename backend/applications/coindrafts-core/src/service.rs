@@ -2,7 +2,8 @@
 # CoinDrafts Core Service
 
 Provides GraphQL interface for frontend integration.
-This handles all queries and mutations that the frontend needs to interact with games.
+This handles all queries and mutations that the frontend needs to interact with games,
+and subscriptions that report game status changes, new portfolios and leaderboard changes.
 */
 
 #![cfg_attr(target_arch = "wasm32", no_main)]
@@ -11,8 +12,9 @@ mod state;
 
 use std::sync::Arc;
 
-use async_graphql::{Request, Response, Schema, Object, EmptySubscription, SimpleObject};
-use coindrafts_core::{Achievement, CoinDraftsAbi, CoinDraftsOperation, Game, GameResult, PlayerProfile, PlayerTier, Portfolio};
+use async_graphql::futures_util::stream::{self, Stream};
+use async_graphql::{Request, Response, Schema, Object, SimpleObject, Subscription};
+use coindrafts_core::{Achievement, CoinDraftsAbi, CoinDraftsOperation, DeliveryStatus, execute, Game, GameResult, GameStatus, MessageRecord, PlayerProfile, PlayerTier, Portfolio, page_slots};
 use self::state::CoinDraftsState;
 use linera_sdk::{
     graphql::GraphQLMutationRoot,
@@ -52,11 +54,13 @@ impl Service for CoinDraftsService {
         let schema = Schema::build(
             query_root,
             CoinDraftsOperation::mutation_root(self.runtime.clone()),
-            EmptySubscription,
+            SubscriptionRoot {
+                state: self.state.clone(),
+            },
         )
         .finish();
         
-        schema.execute(request).await
+        execute(&schema, request).await
     }
 }
 
@@ -81,6 +85,46 @@ impl QueryRoot {
         self.state.games.get(&game_id).await.unwrap_or_default()
     }
 
+    /// Get the game backed by a Traditional Leagues tournament
+    async fn game_by_tournament(&self, tournament_id: String) -> Option<Game> {
+        let game_id = self.state.tournament_games.get(&tournament_id).await.ok().flatten()?;
+        self.state.games.get(&game_id).await.unwrap_or_default()
+    }
+
+    /// Get why a player's registration for a game's tournament was rejected, if it was
+    async fn registration_rejection(&self, game_id: String, player_account: String) -> Option<String> {
+        self.state.registration_rejections.get(&(game_id, player_account)).await.unwrap_or_default()
+    }
+
+    /// Get the entry fees refunded to a player who withdrew from a game's tournament
+    async fn refund(&self, game_id: String, player_account: String) -> u64 {
+        self.state.refunds.get(&(game_id, player_account)).await.ok().flatten().unwrap_or(0)
+    }
+
+    /// Get the delivery log of requests sent to Traditional Leagues or the game chain, optionally by status
+    async fn outbox(&self, status: Option<DeliveryStatus>) -> Vec<MessageRecord> {
+        let mut records = Vec::new();
+        self.state.outbox.for_each_index_value(|_message_id, record| {
+            if status.is_none_or(|status| record.status == status) {
+                records.push(record.into_owned());
+            }
+            Ok(())
+        }).await.unwrap_or_default();
+        records
+    }
+
+    /// Get the log of notifications received from Traditional Leagues, optionally by status
+    async fn inbox(&self, status: Option<DeliveryStatus>) -> Vec<MessageRecord> {
+        let mut records = Vec::new();
+        self.state.inbox.for_each_index_value(|_message_id, record| {
+            if status.is_none_or(|status| record.status == status) {
+                records.push(record.into_owned());
+            }
+            Ok(())
+        }).await.unwrap_or_default();
+        records
+    }
+
     /// Get all registered players
     async fn players(&self) -> Vec<PlayerProfile> {
         let mut players = Vec::new();
@@ -91,12 +135,22 @@ impl QueryRoot {
         players
     }
 
-    /// Get portfolios for a specific game
-    async fn portfolios(&self, game_id: String) -> Vec<Portfolio> {
-        self.state.portfolios.get(&game_id)
+    /// Get a page of portfolios for a specific game, in submission order
+    async fn portfolios(&self, game_id: String, offset: Option<u32>, limit: Option<u32>) -> Vec<Portfolio> {
+        let count = self.state.portfolio_count(&game_id).await.unwrap_or_default();
+        self.state.portfolio_page(&game_id, page_slots(offset, limit, count))
             .await
             .unwrap_or_default()
-            .unwrap_or_default()
+    }
+
+    /// Get why a player's portfolio was rejected by the game's tournament, if it was
+    async fn portfolio_rejection(&self, game_id: String, player_account: String) -> Option<String> {
+        self.state.portfolio_rejections.get(&(game_id, player_account)).await.unwrap_or_default()
+    }
+
+    /// Get the number of portfolios submitted for a game
+    async fn portfolio_count(&self, game_id: String) -> u32 {
+        self.state.portfolio_count(&game_id).await.unwrap_or_default()
     }
 
     /// Get total statistics
@@ -177,58 +231,114 @@ impl QueryRoot {
 
     /// Get global leaderboard
     async fn leaderboard(&self, tier_filter: Option<PlayerTier>, limit: Option<i32>) -> Vec<LeaderboardEntry> {
-        let limit = limit.unwrap_or(100) as usize;
-        
-        let mut players = Vec::new();
-        self.state.players.for_each_index_value(|_key, player| {
-            players.push(player.into_owned());
-            Ok(())
-        }).await.unwrap_or_default();
-        
-        // Filter by tier
-        if let Some(tier) = tier_filter {
-            players.retain(|p| p.tier == tier);
+        leaderboard_entries(&self.state, tier_filter, limit).await
+    }
+}
+
+struct SubscriptionRoot {
+    state: Arc<CoinDraftsState>,
+}
+
+#[Subscription]
+impl SubscriptionRoot {
+    /// Reports each status the game moved to after `known_revision`, oldest first
+    async fn game_status_changed(&self, game_id: String, known_revision: Option<u32>) -> impl Stream<Item = GameStatusChange> {
+        let revision = self.state.game_status_revisions.get(&game_id).await.ok().flatten().unwrap_or(0);
+        let mut changes = Vec::new();
+        for revision in known_revision.unwrap_or(0) + 1..=revision {
+            if let Ok(Some(status)) = self.state.game_status_changes.get(&(game_id.clone(), revision)).await {
+                changes.push(GameStatusChange { revision, status });
+            }
         }
-        
-        // Sort by earnings (primary) and win rate (secondary)
-        players.sort_by(|a, b| {
-            let cmp = b.total_earnings_usdc.cmp(&a.total_earnings_usdc);
-            if cmp == std::cmp::Ordering::Equal {
-                let a_win_rate = if a.stats.games_played > 0 {
-                    a.stats.games_won as f64 / a.stats.games_played as f64
-                } else {
-                    0.0
-                };
-                let b_win_rate = if b.stats.games_played > 0 {
-                    b.stats.games_won as f64 / b.stats.games_played as f64
-                } else {
-                    0.0
-                };
-                b_win_rate.partial_cmp(&a_win_rate).unwrap_or(std::cmp::Ordering::Equal)
+        stream::iter(changes)
+    }
+
+    /// Reports each portfolio submitted, replaced or withdrawn after `known_revision`,
+    /// oldest first. `portfolio` is the player's current portfolio, null once withdrawn.
+    async fn portfolio_submitted(&self, game_id: String, known_revision: Option<u32>) -> impl Stream<Item = PortfolioChange> {
+        let revision = self.state.portfolio_revisions.get(&game_id).await.ok().flatten().unwrap_or(0);
+        let mut changes = Vec::new();
+        for revision in known_revision.unwrap_or(0) + 1..=revision {
+            let Ok(Some(player_account)) = self.state.portfolio_changes.get(&(game_id.clone(), revision)).await else {
+                continue;
+            };
+            let portfolio = self.state.portfolios.get(&(game_id.clone(), player_account.clone())).await.ok().flatten();
+            changes.push(PortfolioChange { revision, player_account, portfolio });
+        }
+        stream::iter(changes)
+    }
+
+    /// Reports the global leaderboard when player profiles changed after `known_revision`
+    async fn leaderboard_changed(
+        &self,
+        tier_filter: Option<PlayerTier>,
+        limit: Option<i32>,
+        known_revision: Option<u64>,
+    ) -> impl Stream<Item = LeaderboardUpdate> {
+        let revision = *self.state.leaderboard_revision.get();
+        let update = if known_revision.is_none_or(|known_revision| known_revision < revision) {
+            let entries = leaderboard_entries(&self.state, tier_filter, limit).await;
+            Some(LeaderboardUpdate { revision, entries })
+        } else {
+            None
+        };
+        stream::iter(update)
+    }
+}
+
+/// Players ranked by earnings, then win rate
+async fn leaderboard_entries(state: &CoinDraftsState, tier_filter: Option<PlayerTier>, limit: Option<i32>) -> Vec<LeaderboardEntry> {
+    let limit = limit.unwrap_or(100) as usize;
+    
+    let mut players = Vec::new();
+    state.players.for_each_index_value(|_key, player| {
+        players.push(player.into_owned());
+        Ok(())
+    }).await.unwrap_or_default();
+    
+    // Filter by tier
+    if let Some(tier) = tier_filter {
+        players.retain(|p| p.tier == tier);
+    }
+    
+    // Sort by earnings (primary) and win rate (secondary)
+    players.sort_by(|a, b| {
+        let cmp = b.total_earnings_usdc.cmp(&a.total_earnings_usdc);
+        if cmp == std::cmp::Ordering::Equal {
+            let a_win_rate = if a.stats.games_played > 0 {
+                a.stats.games_won as f64 / a.stats.games_played as f64
             } else {
-                cmp
-            }
-        });
-        
-        // Convert to leaderboard entries
-        players.into_iter().take(limit).enumerate().map(|(idx, player)| {
-            let win_rate = if player.stats.games_played > 0 {
-                ((player.stats.games_won as f64 / player.stats.games_played as f64) * 100.0) as i32
+                0.0
+            };
+            let b_win_rate = if b.stats.games_played > 0 {
+                b.stats.games_won as f64 / b.stats.games_played as f64
             } else {
-                0
+                0.0
             };
-            LeaderboardEntry {
-                rank: (idx + 1) as i32,
-                player_account: player.account,
-                player_name: player.name,
-                total_games_played: player.stats.games_played as i32,
-                total_wins: player.stats.games_won as i32,
-                win_rate,
-                total_earnings_usdc: player.total_earnings_usdc,
-                current_tier: player.tier,
-            }
-        }).collect()
-    }
+            b_win_rate.partial_cmp(&a_win_rate).unwrap_or(std::cmp::Ordering::Equal)
+        } else {
+            cmp
+        }
+    });
+    
+    // Convert to leaderboard entries
+    players.into_iter().take(limit).enumerate().map(|(idx, player)| {
+        let win_rate = if player.stats.games_played > 0 {
+            ((player.stats.games_won as f64 / player.stats.games_played as f64) * 100.0) as i32
+        } else {
+            0
+        };
+        LeaderboardEntry {
+            rank: (idx + 1) as i32,
+            player_account: player.account,
+            player_name: player.name,
+            total_games_played: player.stats.games_played as i32,
+            total_wins: player.stats.games_won as i32,
+            win_rate,
+            total_earnings_usdc: player.total_earnings_usdc,
+            current_tier: player.tier,
+        }
+    }).collect()
 }
 
 #[derive(SimpleObject)]
@@ -244,7 +354,7 @@ struct PlayerStats {
     recent_games: Vec<GameResult>,
 }
 
-#[derive(SimpleObject)]
+#[derive(Debug, SimpleObject)]
 struct LeaderboardEntry {
     rank: i32,
     player_account: String,
@@ -255,3 +365,25 @@ struct LeaderboardEntry {
     total_earnings_usdc: u64,
     current_tier: PlayerTier,
 }
+
+/// Game status change with the revision to pass back as `knownRevision`
+#[derive(SimpleObject)]
+struct GameStatusChange {
+    revision: u32,
+    status: GameStatus,
+}
+
+/// Portfolio change with the revision to pass back as `knownRevision`
+#[derive(SimpleObject)]
+struct PortfolioChange {
+    revision: u32,
+    player_account: String,
+    portfolio: Option<Portfolio>,
+}
+
+/// Leaderboard with the revision to pass back as `knownRevision`
+#[derive(SimpleObject)]
+struct LeaderboardUpdate {
+    revision: u64,
+    entries: Vec<LeaderboardEntry>,
+}
@@ -5,7 +5,10 @@ Manages the persistent state for the CoinDrafts orchestration hub.
 Uses Linera views for efficient blockchain-native state management.
 */
 
-use coindrafts_core::{Achievement, Game, GameResult, PlayerProfile, Portfolio};
+use std::ops::RangeInclusive;
+
+use coindrafts_core::{Achievement, CoinDraftsMessage, Game, GameResult, GameStatus, MessageEnvelope, MessageRecord, PlayerProfile, Portfolio};
+use linera_sdk::linera_base_types::{AccountOwner, ApplicationId, ChainId};
 use linera_sdk::views::{linera_views, MapView, RegisterView, RootView, ViewStorageContext};
 
 /// The application state
@@ -16,12 +19,165 @@ pub struct CoinDraftsState {
     pub games: MapView<String, Game>,
     /// All registered players indexed by account string
     pub players: MapView<String, PlayerProfile>,
-    /// Portfolio submissions indexed by game_id
-    pub portfolios: MapView<String, Vec<Portfolio>>,
+    /// Statuses each game moved to per (game_id, revision), revisions numbered from 1
+    pub game_status_changes: MapView<(String, u32), GameStatus>,
+    /// Number of status changes per game_id
+    pub game_status_revisions: MapView<String, u32>,
+    /// Portfolio submissions indexed by (game_id, player_account)
+    pub portfolios: MapView<(String, String), Portfolio>,
+    /// Slot of each submission indexed by (game_id, player_account)
+    pub portfolio_positions: MapView<(String, String), u32>,
+    /// Submitting player per (game_id, slot), slots numbered from 1
+    pub portfolio_slots: MapView<(String, u32), String>,
+    /// Number of portfolio submissions per game_id
+    pub portfolio_counts: MapView<String, u32>,
+    /// Player whose portfolio was submitted, replaced or withdrawn per (game_id, revision),
+    /// revisions numbered from 1
+    pub portfolio_changes: MapView<(String, u32), String>,
+    /// Number of portfolio changes per game_id
+    pub portfolio_revisions: MapView<String, u32>,
+    /// Entry fee paid by each player indexed by (game_id, player_account)
+    pub entry_payments: MapView<(String, String), u64>,
+    /// Entry fees refunded to players who withdrew, indexed by (game_id, player_account)
+    pub refunds: MapView<(String, String), u64>,
+    /// Reason a player's tournament registration was rejected indexed by (game_id, player_account)
+    pub registration_rejections: MapView<(String, String), String>,
+    /// Reason a portfolio failed to sync into its game's tournament indexed by (game_id, player_account)
+    pub portfolio_rejections: MapView<(String, String), String>,
+    /// Game linked to each Traditional Leagues tournament, indexed by tournament_id
+    pub tournament_games: MapView<String, String>,
+    /// Advanced whenever a player profile changes, and with it the global leaderboard
+    pub leaderboard_revision: RegisterView<u64>,
     /// Global game counter for generating unique IDs
     pub game_counter: RegisterView<u64>,
     /// Player achievements indexed by (player_account, achievement_id)
     pub achievements: MapView<(String, String), Achievement>,
     /// Game history indexed by (player_account, game_id)
     pub game_history: MapView<(String, String), GameResult>,
-}
\ No newline at end of file
+    /// Account that instantiated the application, allowed to retry failed requests
+    pub admin: RegisterView<Option<AccountOwner>>,
+    /// Traditional Leagues application core follows, set by `ConnectLeagues`
+    pub leagues_application_id: RegisterView<Option<ApplicationId>>,
+    /// Chain the Traditional Leagues application runs on
+    pub leagues_chain_id: RegisterView<Option<ChainId>>,
+    /// Counter for generating request message IDs
+    pub message_counter: RegisterView<u64>,
+    /// Delivery log of requests sent to Traditional Leagues, or from a player chain to the
+    /// game chain, indexed by message_id
+    pub outbox: MapView<String, MessageRecord>,
+    /// Sent requests kept for retries, indexed by message_id
+    pub outbox_messages: MapView<String, MessageEnvelope<CoinDraftsMessage>>,
+    /// Leagues notifications handled so far indexed by message_id, used to drop duplicates
+    pub inbox: MapView<String, MessageRecord>,
+}
+
+impl CoinDraftsState {
+    /// Generate a message ID unique across chains
+    #[allow(dead_code)]
+    pub fn generate_message_id(&mut self, chain_id: ChainId) -> String {
+        let counter = *self.message_counter.get() + 1;
+        self.message_counter.set(counter);
+        format!("{}-{}", chain_id, counter)
+    }
+
+    /// Number of portfolios submitted for a game
+    #[allow(dead_code)]
+    pub async fn portfolio_count(&self, game_id: &str) -> Result<u32, String> {
+        self.portfolio_counts.get(game_id).await
+            .map(|count| count.unwrap_or(0))
+            .map_err(|e| format!("Failed to get portfolio count: {}", e))
+    }
+
+    /// Store a player's portfolio, replacing any earlier submission for the same game
+    #[allow(dead_code)]
+    pub async fn upsert_portfolio(&mut self, portfolio: Portfolio) -> Result<(), String> {
+        let key = (portfolio.game_id.clone(), portfolio.player_account.clone());
+        let exists = self.portfolio_positions.contains_key(&key).await
+            .map_err(|e| format!("Failed to get portfolio: {}", e))?;
+
+        if !exists {
+            let slot = self.portfolio_count(&portfolio.game_id).await? + 1;
+            self.portfolio_positions.insert(&key, slot)
+                .map_err(|e| format!("Failed to update portfolios: {}", e))?;
+            self.portfolio_slots.insert(&(portfolio.game_id.clone(), slot), portfolio.player_account.clone())
+                .map_err(|e| format!("Failed to update portfolios: {}", e))?;
+            self.portfolio_counts.insert(&portfolio.game_id, slot)
+                .map_err(|e| format!("Failed to update portfolio count: {}", e))?;
+        }
+        self.record_portfolio_change(&portfolio.game_id, &portfolio.player_account).await?;
+        self.portfolios.insert(&key, portfolio)
+            .map_err(|e| format!("Failed to store portfolio: {}", e))
+    }
+
+    /// Remove a player's portfolio. The last submission moves into the freed slot
+    /// so slots stay contiguous. Returns false if the player had not submitted.
+    #[allow(dead_code)]
+    pub async fn remove_portfolio(&mut self, game_id: &str, player_account: &str) -> Result<bool, String> {
+        let key = (game_id.to_string(), player_account.to_string());
+        let Some(slot) = self.portfolio_positions.get(&key).await
+            .map_err(|e| format!("Failed to get portfolio: {}", e))?
+        else {
+            return Ok(false);
+        };
+
+        let count = self.portfolio_count(game_id).await?;
+        let last_key = (game_id.to_string(), count);
+        if slot != count {
+            if let Some(last_player) = self.portfolio_slots.get(&last_key).await
+                .map_err(|e| format!("Failed to get portfolios: {}", e))?
+            {
+                self.portfolio_slots.insert(&(game_id.to_string(), slot), last_player.clone())
+                    .map_err(|e| format!("Failed to update portfolios: {}", e))?;
+                self.portfolio_positions.insert(&(game_id.to_string(), last_player), slot)
+                    .map_err(|e| format!("Failed to update portfolios: {}", e))?;
+            }
+        }
+        self.portfolio_slots.remove(&last_key)
+            .map_err(|e| format!("Failed to update portfolios: {}", e))?;
+        self.portfolio_counts.insert(&game_id.to_string(), count.saturating_sub(1))
+            .map_err(|e| format!("Failed to update portfolio count: {}", e))?;
+        self.portfolio_positions.remove(&key)
+            .map_err(|e| format!("Failed to remove portfolio: {}", e))?;
+        self.portfolios.remove(&key)
+            .map_err(|e| format!("Failed to remove portfolio: {}", e))?;
+        self.record_portfolio_change(game_id, player_account).await?;
+        Ok(true)
+    }
+
+    /// Log a change to a player's portfolio under the game's next revision
+    async fn record_portfolio_change(&mut self, game_id: &str, player_account: &str) -> Result<(), String> {
+        let revision = self.portfolio_revisions.get(game_id).await
+            .map_err(|e| format!("Failed to get portfolio revision: {}", e))?
+            .unwrap_or(0) + 1;
+        self.portfolio_changes.insert(&(game_id.to_string(), revision), player_account.to_string())
+            .map_err(|e| format!("Failed to record portfolio change: {}", e))?;
+        self.portfolio_revisions.insert(&game_id.to_string(), revision)
+            .map_err(|e| format!("Failed to record portfolio change: {}", e))
+    }
+
+    /// Portfolios in the given slot range, in submission order
+    #[allow(dead_code)]
+    pub async fn portfolio_page(&self, game_id: &str, slots: RangeInclusive<u32>) -> Result<Vec<Portfolio>, String> {
+        let mut portfolios = Vec::new();
+        for slot in slots {
+            let Some(player_account) = self.portfolio_slots.get(&(game_id.to_string(), slot)).await
+                .map_err(|e| format!("Failed to get portfolios: {}", e))?
+            else {
+                continue;
+            };
+            if let Some(portfolio) = self.portfolios.get(&(game_id.to_string(), player_account)).await
+                .map_err(|e| format!("Failed to get portfolio: {}", e))?
+            {
+                portfolios.push(portfolio);
+            }
+        }
+        Ok(portfolios)
+    }
+
+    /// Every portfolio submitted for a game
+    #[allow(dead_code)]
+    pub async fn game_portfolios(&self, game_id: &str) -> Result<Vec<Portfolio>, String> {
+        let count = self.portfolio_count(game_id).await?;
+        self.portfolio_page(game_id, 1..=count).await
+    }
+}
@@ -9,7 +9,6 @@ pub mod constants;
 pub mod error;
 pub mod game;
 pub mod player;
-pub mod portfolio;
 pub mod scoring;
 pub mod validation;
 
@@ -18,6 +17,10 @@ pub use constants::*;
 pub use error::*;
 pub use game::*;
 pub use player::*;
-pub use portfolio::*;
 pub use scoring::*;
-pub use validation::*;
\ No newline at end of file
+pub use validation::*;
+
+// Cross-application types owned by coindrafts-common
+pub use coindrafts_common::{
+    CryptoHolding, PlayerProfile, PlayerStats, PlayerTier, Portfolio, PortfolioStatus, MAX_HOLDINGS, MAX_SHORT_PERCENT,
+};
\ No newline at end of file
@@ -1,10 +1,10 @@
 #![cfg_attr(target_arch = "wasm32", no_main)]
 
 use price_prediction::{
-    PricePredictionAbi, PredictionOperation, PredictionMarket, Prediction, MarketStatus,
+    PricePredictionAbi, PredictionOperation, PredictionMarket, Prediction, MarketStatus, GameEvent,
     state::PricePredictionState,
 };
-use linera_sdk::{linera_base_types::WithContractAbi, Contract, ContractRuntime, views::{RootView, View}};
+use linera_sdk::{linera_base_types::{StreamName, WithContractAbi}, Contract, ContractRuntime, views::{RootView, View}};
 
 pub struct PricePredictionContract {
     state: PricePredictionState,
@@ -21,7 +21,7 @@ impl Contract for PricePredictionContract {
     type Message = ();
     type Parameters = ();
     type InstantiationArgument = ();
-    type EventValue = ();
+    type EventValue = GameEvent;
 
     async fn load(runtime: ContractRuntime<Self>) -> Self {
         let state = PricePredictionState::load(runtime.root_view_storage_context())
@@ -114,6 +114,7 @@ impl PricePredictionContract {
         market.status = MarketStatus::Settling;
         market.final_price = Some(final_price);
         let entry_fee = market.entry_fee;
+        let mut winners = Vec::new();
         
         // Process each player's prediction directly - no iteration needed
         for player in players {
@@ -121,25 +122,31 @@ impl PricePredictionContract {
             
             if let Ok(Some(mut pred)) = self.state.predictions.get(&prediction_key).await {
                 if final_price >= pred.min_price && final_price <= pred.max_price {
-                    let range_width = pred.max_price.saturating_sub(pred.min_price);
-                    let range_multiplier = match range_width {
-                        0..=1_000_000 => 20.0,
-                        1_000_001..=5_000_000 => 10.0,
-                        5_000_001..=10_000_000 => 5.0,
-                        _ => 2.0,
-                    };
-                    let multiplier = range_multiplier * (pred.confidence as f64 / 100.0);
-                    let reward = (entry_fee as f64 * multiplier) as u64;
-                    
-                    pred.multiplier = Some(multiplier);
-                    pred.reward = Some(reward);
-                    
-                    let _ = self.state.predictions.insert(&prediction_key, pred);
+                    match pred.calculate_reward(entry_fee) {
+                        Ok((multiplier, reward)) => {
+                            pred.multiplier = Some(multiplier);
+                            pred.reward = Some(reward);
+                            
+                            let _ = self.state.predictions.insert(&prediction_key, pred);
+                            winners.push(player);
+                        }
+                        Err(e) => {
+                            log::error!("Reward for {} in market {} could not be computed: {}", player, market_id, e);
+                        }
+                    }
                 }
             }
         }
         
         market.status = MarketStatus::Completed;
         self.state.markets.insert(&market_id, market).expect("Failed to update market");
+
+        let event = GameEvent::MarketSettled {
+            market_id,
+            final_price,
+            winners,
+            settled_at: self.runtime.system_time().micros(),
+        };
+        self.runtime.emit(StreamName::from(event.stream_name()), &event);
     }
 }
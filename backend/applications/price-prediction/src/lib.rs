@@ -1,6 +1,8 @@
 use async_graphql::SimpleObject;
 use serde::{Deserialize, Serialize};
 
+pub use coindrafts_common::{execute, Fixed, FixedResult, GameEvent, Rounding, FIXED_SCALE, MARKET_EVENT_STREAM};
+
 pub mod state;
 
 // Types
@@ -26,7 +28,8 @@ pub struct Prediction {
     pub confidence: u8, // 0-100
     pub ai_assisted: bool,
     pub submitted_at: u64,
-    pub multiplier: Option<f64>,
+    /// Reward multiplier in fixed point (e.g., 10.0x = 100000)
+    pub multiplier: Option<Fixed>,
     pub reward: Option<u64>,
 }
 
@@ -37,6 +40,23 @@ pub enum MarketStatus {
     Completed,
 }
 
+impl Prediction {
+    /// Multiplier and payout for a winning prediction.
+    /// Narrower ranges pay more, scaled by the player's confidence.
+    pub fn calculate_reward(&self, entry_fee: u64) -> FixedResult<(Fixed, u64)> {
+        let range_width = self.max_price.saturating_sub(self.min_price);
+        let range_multiplier = Fixed::from_raw(FIXED_SCALE * match range_width {
+            0..=1_000_000 => 20,
+            1_000_001..=5_000_000 => 10,
+            5_000_001..=10_000_000 => 5,
+            _ => 2,
+        });
+        let multiplier = range_multiplier.checked_mul(Fixed::from_percent(self.confidence as u32), Rounding::Down)?;
+        let reward = multiplier.apply_to_amount(entry_fee, Rounding::Down)?;
+        Ok((multiplier, reward))
+    }
+}
+
 // Operations
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum PredictionOperation {
@@ -1,9 +1,10 @@
 #![cfg_attr(target_arch = "wasm32", no_main)]
 
-use async_graphql::{Object, Request, Response, Schema, EmptySubscription, Context};
+use async_graphql::futures_util::stream::{self, Stream};
+use async_graphql::{Object, Request, Response, Schema, Context, Subscription};
 use linera_sdk::{linera_base_types::WithServiceAbi, Service, ServiceRuntime, views::View};
 use std::sync::Arc;
-use price_prediction::{PricePredictionAbi, PredictionMarket, Prediction, PredictionOperation, state::PricePredictionState};
+use price_prediction::{execute, MarketStatus, PricePredictionAbi, PredictionMarket, Prediction, PredictionOperation, state::PricePredictionState};
 
 pub struct PricePredictionService {
     state: Arc<PricePredictionState>,
@@ -35,12 +36,12 @@ impl Service for PricePredictionService {
             MutationRoot {
                 runtime: self.runtime.clone(),
             },
-            EmptySubscription,
+            SubscriptionRoot,
         )
         .data(self.state.clone())
         .finish();
 
-        schema.execute(request).await
+        execute(&schema, request).await
     }
 }
 
@@ -118,6 +119,19 @@ impl QueryRoot {
     }
 }
 
+struct SubscriptionRoot;
+
+#[Subscription]
+impl SubscriptionRoot {
+    /// Reports the market once it has settled
+    async fn market_settled(&self, ctx: &async_graphql::Context<'_>, market_id: String) -> impl Stream<Item = PredictionMarket> {
+        let state = ctx.data::<Arc<PricePredictionState>>().unwrap();
+        let market = state.markets.get(&market_id).await.ok().flatten()
+            .filter(|market| market.status == MarketStatus::Completed);
+        stream::iter(market)
+    }
+}
+
 struct MutationRoot {
     runtime: Arc<ServiceRuntime<PricePredictionService>>,
 }
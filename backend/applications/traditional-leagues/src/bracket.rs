@@ -0,0 +1,521 @@
+/*!
+# Elimination Brackets
+
+Bracket construction and round resolution for single- and double-elimination tournaments.
+Each matchup is scored head-to-head over its round's own price window.
+
+In double elimination every player starts with two lives. Undefeated players play in the
+winners bracket, players with one loss drop into the losers bracket, and the two bracket
+champions meet in a grand final. If the losers-bracket champion wins the grand final, both
+finalists have one loss left and the bracket resets for a deciding match.
+*/
+
+use std::collections::BTreeMap;
+
+use async_graphql::{Enum, SimpleObject};
+use serde::{Deserialize, Serialize};
+
+use crate::{Fixed, FixedResult, PriceData, PriceSnapshot, ScoringEngine, TournamentPortfolio};
+
+/// Which part of the bracket a matchup belongs to
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Enum)]
+pub enum BracketSide {
+    /// Undefeated players
+    Winners,
+    /// Players with one loss (double elimination only)
+    Losers,
+    /// Winners-bracket champion against losers-bracket champion, including a reset match
+    GrandFinal,
+}
+
+/// Head-to-head matchup between two players (or one player and a bye)
+#[derive(Debug, Clone, Serialize, Deserialize, SimpleObject)]
+pub struct Matchup {
+    /// Round this matchup belongs to (1-based)
+    pub round: u32,
+    /// Position within its side of the round; winners-bracket position feeds position / 2
+    pub position: u32,
+    pub side: BracketSide,
+    pub player_a: Option<String>,
+    pub seed_a: Option<u32>,
+    pub player_b: Option<String>,
+    pub seed_b: Option<u32>,
+    /// Weighted return of player A over the round's price window
+    pub score_a: Option<Fixed>,
+    /// Weighted return of player B over the round's price window
+    pub score_b: Option<Fixed>,
+    pub winner: Option<String>,
+}
+
+/// One round of a bracket with its own price window
+#[derive(Debug, Clone, Serialize, Deserialize, SimpleObject)]
+pub struct BracketRound {
+    pub round: u32,
+    pub matchups: Vec<Matchup>,
+    /// Prices when the round started
+    pub start_prices: Vec<PriceSnapshot>,
+    /// Prices when the round was scored
+    pub end_prices: Option<Vec<PriceSnapshot>>,
+}
+
+/// Elimination bracket for a tournament
+#[derive(Debug, Clone, Serialize, Deserialize, SimpleObject)]
+pub struct Bracket {
+    pub tournament_id: String,
+    /// Seeded players; index 0 is seed 1
+    pub seeds: Vec<String>,
+    /// Losses a player can take before elimination (1 = single, 2 = double elimination)
+    pub max_lives: u32,
+    pub rounds: Vec<BracketRound>,
+    pub champion: Option<String>,
+}
+
+/// Remaining lives of a player in an elimination bracket
+#[derive(Debug, Clone, Serialize, Deserialize, SimpleObject)]
+pub struct PlayerLives {
+    pub player_account: String,
+    pub seed: u32,
+    pub lives: u32,
+    pub side: Option<BracketSide>,
+}
+
+impl Matchup {
+    fn new(round: u32, position: u32, side: BracketSide, player_a: Option<(String, u32)>, player_b: Option<(String, u32)>) -> Self {
+        let (player_a, seed_a) = player_a.map_or((None, None), |(player, seed)| (Some(player), Some(seed)));
+        let (player_b, seed_b) = player_b.map_or((None, None), |(player, seed)| (Some(player), Some(seed)));
+        let winner = match (&player_a, &player_b) {
+            (Some(player), None) | (None, Some(player)) => Some(player.clone()),
+            _ => None,
+        };
+        Self {
+            round,
+            position,
+            side,
+            player_a,
+            seed_a,
+            player_b,
+            seed_b,
+            score_a: None,
+            score_b: None,
+            winner,
+        }
+    }
+
+    /// A matchup with only one player advances that player automatically
+    pub fn is_bye(&self) -> bool {
+        self.player_a.is_none() || self.player_b.is_none()
+    }
+
+    /// Player who lost this matchup, if it has been decided
+    pub fn loser(&self) -> Option<&String> {
+        let winner = self.winner.as_ref()?;
+        [&self.player_a, &self.player_b]
+            .into_iter()
+            .flatten()
+            .find(|player| *player != winner)
+    }
+
+    fn players(&self) -> impl Iterator<Item = &String> {
+        self.player_a.iter().chain(self.player_b.iter())
+    }
+
+    fn score_of(&self, player: &str) -> Option<Fixed> {
+        if self.player_a.as_deref() == Some(player) {
+            self.score_a
+        } else if self.player_b.as_deref() == Some(player) {
+            self.score_b
+        } else {
+            None
+        }
+    }
+}
+
+/// Standard bracket order of seeds so that top seeds meet as late as possible.
+/// For a bracket of 8 this is `[1, 8, 4, 5, 2, 7, 3, 6]`.
+pub fn seeding_order(bracket_size: u32) -> Vec<u32> {
+    let mut order = vec![1];
+    let mut size = 1;
+    while size < bracket_size {
+        size *= 2;
+        order = order.into_iter().flat_map(|seed| [seed, size + 1 - seed]).collect();
+    }
+    order
+}
+
+/// Score both players over the round window; ties go to the better (lower) seed
+fn decide(
+    matchup: &mut Matchup,
+    portfolios: &BTreeMap<String, TournamentPortfolio>,
+    price_data: &[PriceData],
+) -> FixedResult<()> {
+    let score = |player: &Option<String>| -> FixedResult<Fixed> {
+        match player.as_ref().and_then(|player| portfolios.get(player)) {
+            Some(portfolio) => ScoringEngine::calculate_portfolio_performance(portfolio, price_data),
+            None => Ok(Fixed::ZERO),
+        }
+    };
+    let score_a = score(&matchup.player_a)?;
+    let score_b = score(&matchup.player_b)?;
+
+    let a_wins = match score_a.cmp(&score_b) {
+        std::cmp::Ordering::Greater => true,
+        std::cmp::Ordering::Less => false,
+        std::cmp::Ordering::Equal => matchup.seed_a.unwrap_or(u32::MAX) <= matchup.seed_b.unwrap_or(u32::MAX),
+    };
+
+    matchup.score_a = Some(score_a);
+    matchup.score_b = Some(score_b);
+    matchup.winner = if a_wins { matchup.player_a.clone() } else { matchup.player_b.clone() };
+    Ok(())
+}
+
+impl Bracket {
+    /// Draw a single-elimination bracket
+    pub fn single_elimination(tournament_id: String, seeds: Vec<String>, start_prices: Vec<PriceSnapshot>) -> Self {
+        Self::draw(tournament_id, seeds, start_prices, 1)
+    }
+
+    /// Draw a double-elimination bracket
+    pub fn double_elimination(tournament_id: String, seeds: Vec<String>, start_prices: Vec<PriceSnapshot>) -> Self {
+        Self::draw(tournament_id, seeds, start_prices, 2)
+    }
+
+    /// Seed players in the given order and draw the first winners-bracket round.
+    /// Fields that are not a power of two give the top seeds automatic byes.
+    fn draw(tournament_id: String, seeds: Vec<String>, start_prices: Vec<PriceSnapshot>, max_lives: u32) -> Self {
+        let bracket_size = (seeds.len().max(1) as u32).next_power_of_two();
+        let slot = |seed: u32| seeds.get(seed as usize - 1).map(|player| (player.clone(), seed));
+
+        let matchups = seeding_order(bracket_size)
+            .chunks(2)
+            .enumerate()
+            .map(|(position, pair)| {
+                let player_b = pair.get(1).and_then(|seed| slot(*seed));
+                Matchup::new(1, position as u32, BracketSide::Winners, slot(pair[0]), player_b)
+            })
+            .collect();
+
+        let champion = if seeds.len() == 1 { seeds.first().cloned() } else { None };
+
+        Self {
+            tournament_id,
+            seeds,
+            max_lives,
+            rounds: vec![BracketRound {
+                round: 1,
+                matchups,
+                start_prices,
+                end_prices: None,
+            }],
+            champion,
+        }
+    }
+
+    /// Round currently being played
+    pub fn current_round(&self) -> Option<&BracketRound> {
+        self.rounds.last()
+    }
+
+    /// Seed number of a player
+    pub fn seed_of(&self, player: &str) -> Option<u32> {
+        self.seeds.iter().position(|seed| seed == player).map(|index| index as u32 + 1)
+    }
+
+    /// Players still to be scored in the current round
+    pub fn active_players(&self) -> Vec<String> {
+        self.current_round()
+            .map(|round| {
+                round.matchups.iter()
+                    .filter(|matchup| !matchup.is_bye())
+                    .flat_map(|matchup| matchup.players().cloned())
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    /// Losses taken by every player so far
+    fn losses(&self) -> BTreeMap<String, u32> {
+        let mut losses = BTreeMap::new();
+        for matchup in self.rounds.iter().flat_map(|round| round.matchups.iter()) {
+            if let Some(loser) = matchup.loser() {
+                *losses.entry(loser.clone()).or_insert(0) += 1;
+            }
+        }
+        losses
+    }
+
+    /// Remaining lives of every seeded player
+    pub fn lives(&self) -> Vec<PlayerLives> {
+        let losses = self.losses();
+        self.seeds.iter().enumerate().map(|(index, player)| {
+            let lives = self.max_lives.saturating_sub(losses.get(player).copied().unwrap_or(0));
+            let side = match (lives, self.max_lives.saturating_sub(lives)) {
+                (0, _) => None,
+                (_, 0) => Some(BracketSide::Winners),
+                _ => Some(BracketSide::Losers),
+            };
+            PlayerLives {
+                player_account: player.clone(),
+                seed: index as u32 + 1,
+                lives,
+                side,
+            }
+        }).collect()
+    }
+
+    /// Score the current round against `end_prices` and draw the next round.
+    /// Returns the new round number, or `None` once the champion is decided.
+    pub fn advance_round(
+        &mut self,
+        end_prices: Vec<PriceSnapshot>,
+        portfolios: &BTreeMap<String, TournamentPortfolio>,
+        return_scale: i64,
+    ) -> FixedResult<Option<u32>> {
+        let Some(round) = self.rounds.last_mut() else {
+            return Ok(None);
+        };
+
+        let price_data = PriceData::from_snapshots(&round.start_prices, &end_prices, return_scale)?;
+        for matchup in round.matchups.iter_mut().filter(|matchup| !matchup.is_bye()) {
+            decide(matchup, portfolios, &price_data)?;
+        }
+        round.end_prices = Some(end_prices.clone());
+        let next_round = round.round + 1;
+
+        let matchups = self.draw_next_round(next_round);
+        if matchups.is_empty() {
+            return Ok(None);
+        }
+
+        self.rounds.push(BracketRound {
+            round: next_round,
+            matchups,
+            start_prices: end_prices,
+            end_prices: None,
+        });
+        Ok(Some(next_round))
+    }
+
+    /// Pair the surviving players for the next round, or crown the champion
+    fn draw_next_round(&mut self, next_round: u32) -> Vec<Matchup> {
+        let losses = self.losses();
+        let alive = |player: &String| losses.get(player).copied().unwrap_or(0) < self.max_lives;
+        let Some(last) = self.rounds.last() else {
+            return Vec::new();
+        };
+        let with_seed = |player: &String| (player.clone(), self.seed_of(player).unwrap_or(u32::MAX));
+
+        // Winners bracket keeps its tree order: winners of adjacent positions meet next
+        let mut winners_side: Vec<String> = last.matchups.iter()
+            .filter(|matchup| matchup.side == BracketSide::Winners)
+            .filter_map(|matchup| matchup.winner.clone())
+            .collect();
+        if winners_side.is_empty() {
+            // Winners-bracket champion waiting for the losers bracket to finish
+            winners_side = self.seeds.iter()
+                .filter(|player| !losses.contains_key(*player))
+                .cloned()
+                .collect();
+        }
+
+        // Losers bracket: survivors first (including a grand-final loser who still has a
+        // life left), then fresh drop-downs from the winners bracket
+        let mut losers_side: Vec<String> = Vec::new();
+        if self.max_lives > 1 {
+            let survivors = last.matchups.iter()
+                .filter(|matchup| matchup.side != BracketSide::Winners)
+                .flat_map(|matchup| matchup.winner.iter().chain(matchup.loser()).cloned().collect::<Vec<_>>());
+            let dropped = last.matchups.iter()
+                .filter(|matchup| matchup.side == BracketSide::Winners)
+                .filter_map(|matchup| matchup.loser().cloned());
+            for player in survivors.chain(dropped) {
+                if alive(&player) && !winners_side.contains(&player) && !losers_side.contains(&player) {
+                    losers_side.push(player);
+                }
+            }
+        }
+
+        let remaining = winners_side.len() + losers_side.len();
+        if remaining <= 1 {
+            self.champion = winners_side.into_iter().chain(losers_side).next();
+            return Vec::new();
+        }
+
+        if self.max_lives > 1 && remaining == 2 {
+            // Grand final (or its reset): fewest losses plays as player A
+            let mut finalists: Vec<String> = winners_side.into_iter().chain(losers_side).collect();
+            finalists.sort_by_key(|player| losses.get(player).copied().unwrap_or(0));
+            return vec![Matchup::new(
+                next_round,
+                0,
+                BracketSide::GrandFinal,
+                Some(with_seed(&finalists[0])),
+                Some(with_seed(&finalists[1])),
+            )];
+        }
+
+        let mut matchups = Vec::new();
+        if winners_side.len() > 1 {
+            for (position, pair) in winners_side.chunks(2).enumerate() {
+                matchups.push(Matchup::new(
+                    next_round,
+                    position as u32,
+                    BracketSide::Winners,
+                    Some(with_seed(&pair[0])),
+                    pair.get(1).map(with_seed),
+                ));
+            }
+        }
+        if losers_side.len() > 1 {
+            for (position, pair) in losers_side.chunks(2).enumerate() {
+                matchups.push(Matchup::new(
+                    next_round,
+                    position as u32,
+                    BracketSide::Losers,
+                    Some(with_seed(&pair[0])),
+                    pair.get(1).map(with_seed),
+                ));
+            }
+        } else if let Some(player) = losers_side.first() {
+            // Lone losers-bracket player waits for the winners-bracket final
+            matchups.push(Matchup::new(next_round, 0, BracketSide::Losers, Some(with_seed(player)), None));
+        }
+        matchups
+    }
+
+    /// Final placements: champion first, then players by the round they were
+    /// eliminated in (latest first), ties broken by score in that round and seed
+    pub fn placements(&self) -> Vec<String> {
+        let mut losses: BTreeMap<&String, u32> = BTreeMap::new();
+        let mut eliminated: Vec<(u32, Option<Fixed>, u32, String)> = Vec::new();
+        for round in &self.rounds {
+            for matchup in &round.matchups {
+                let Some(loser) = matchup.loser() else { continue };
+                let count = losses.entry(loser).or_insert(0);
+                *count += 1;
+                if *count == self.max_lives {
+                    let seed = self.seed_of(loser).unwrap_or(u32::MAX);
+                    eliminated.push((round.round, matchup.score_of(loser), seed, loser.clone()));
+                }
+            }
+        }
+        eliminated.sort_by(|a, b| b.0.cmp(&a.0).then(b.1.cmp(&a.1)).then(a.2.cmp(&b.2)));
+
+        self.champion.iter().cloned()
+            .chain(eliminated.into_iter().map(|(_, _, _, player)| player))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn snapshot(crypto_id: &str, price_usd: u64) -> PriceSnapshot {
+        PriceSnapshot { crypto_id: crypto_id.to_string(), price_usd, timestamp: 0 }
+    }
+
+    fn prices(btc: u64, eth: u64, sol: u64) -> Vec<PriceSnapshot> {
+        vec![snapshot("BTC", btc), snapshot("ETH", eth), snapshot("SOL", sol)]
+    }
+
+    fn portfolio(pick: &str) -> TournamentPortfolio {
+        TournamentPortfolio { crypto_picks: vec![pick.to_string()], strategy_notes: None, captain: None, vice_captain: None, short_picks: vec![] }
+    }
+
+    fn players(names: &[&str]) -> Vec<String> {
+        names.iter().map(|name| name.to_string()).collect()
+    }
+
+    #[test]
+    fn test_seeding_order() {
+        assert_eq!(seeding_order(1), vec![1]);
+        assert_eq!(seeding_order(4), vec![1, 4, 2, 3]);
+        assert_eq!(seeding_order(8), vec![1, 8, 4, 5, 2, 7, 3, 6]);
+    }
+
+    #[test]
+    fn test_byes_for_top_seeds() {
+        let bracket = Bracket::single_elimination("t".to_string(), players(&["a", "b", "c", "d", "e"]), vec![]);
+        let round = bracket.current_round().unwrap();
+
+        assert_eq!(round.matchups.len(), 4);
+        // Seeds 1, 2 and 3 get byes; only 4 vs 5 is played
+        let byes: Vec<_> = round.matchups.iter().filter(|m| m.is_bye()).filter_map(|m| m.winner.clone()).collect();
+        assert_eq!(byes, vec!["a", "b", "c"]);
+        assert_eq!(bracket.active_players(), vec!["d", "e"]);
+    }
+
+    #[test]
+    fn test_single_elimination_to_champion() {
+        let portfolios: BTreeMap<String, TournamentPortfolio> = [
+            ("a".to_string(), portfolio("BTC")),
+            ("b".to_string(), portfolio("ETH")),
+            ("c".to_string(), portfolio("SOL")),
+        ].into_iter().collect();
+        let mut bracket = Bracket::single_elimination("t".to_string(), players(&["a", "b", "c"]), prices(100, 100, 100));
+
+        // Round 1: "a" has a bye, "c" beats "b"
+        assert_eq!(bracket.advance_round(prices(100, 90, 110), &portfolios, 1).unwrap(), Some(2));
+        assert_eq!(bracket.active_players(), vec!["a", "c"]);
+
+        // Final: "c" beats "a" over the second window only
+        assert_eq!(bracket.advance_round(prices(100, 90, 121), &portfolios, 1).unwrap(), None);
+        assert_eq!(bracket.champion.as_deref(), Some("c"));
+        assert_eq!(bracket.placements(), vec!["c", "a", "b"]);
+    }
+
+    #[test]
+    fn test_double_elimination_with_bracket_reset() {
+        let portfolios: BTreeMap<String, TournamentPortfolio> = [
+            ("a".to_string(), portfolio("BTC")),
+            ("b".to_string(), portfolio("ETH")),
+        ].into_iter().collect();
+        let mut bracket = Bracket::double_elimination("t".to_string(), players(&["a", "b"]), prices(100, 100, 100));
+
+        // Winners final: "a" wins, "b" drops to the losers bracket
+        assert_eq!(bracket.advance_round(prices(110, 100, 100), &portfolios, 1).unwrap(), Some(2));
+        let lives: Vec<u32> = bracket.lives().iter().map(|l| l.lives).collect();
+        assert_eq!(lives, vec![2, 1]);
+        assert_eq!(bracket.current_round().unwrap().matchups[0].side, BracketSide::GrandFinal);
+
+        // Grand final: "b" wins, forcing a reset
+        assert_eq!(bracket.advance_round(prices(110, 120, 100), &portfolios, 1).unwrap(), Some(3));
+        assert_eq!(bracket.current_round().unwrap().matchups[0].side, BracketSide::GrandFinal);
+
+        // Reset: "b" wins again and takes the title
+        assert_eq!(bracket.advance_round(prices(110, 130, 100), &portfolios, 1).unwrap(), None);
+        assert_eq!(bracket.champion.as_deref(), Some("b"));
+        assert_eq!(bracket.placements(), vec!["b", "a"]);
+    }
+
+    #[test]
+    fn test_double_elimination_losers_bracket() {
+        let portfolios: BTreeMap<String, TournamentPortfolio> = [
+            ("a".to_string(), portfolio("BTC")),
+            ("b".to_string(), portfolio("ETH")),
+            ("c".to_string(), portfolio("SOL")),
+            ("d".to_string(), portfolio("DOT")),
+        ].into_iter().collect();
+        let mut bracket = Bracket::double_elimination("t".to_string(), players(&["a", "b", "c", "d"]), vec![]);
+
+        // Round 1 (a vs d, b vs c): scores are all zero, so the better seeds win
+        assert_eq!(bracket.advance_round(vec![], &portfolios, 1).unwrap(), Some(2));
+        let sides: Vec<BracketSide> = bracket.current_round().unwrap().matchups.iter().map(|m| m.side).collect();
+        assert_eq!(sides, vec![BracketSide::Winners, BracketSide::Losers]);
+
+        // Round 2: "a" beats "b" in the winners final, "c" knocks out "d"
+        assert_eq!(bracket.advance_round(vec![], &portfolios, 1).unwrap(), Some(3));
+        let lives: Vec<u32> = bracket.lives().iter().map(|l| l.lives).collect();
+        assert_eq!(lives, vec![2, 1, 1, 0]);
+
+        // Round 3: losers final "c" vs "b" while "a" waits
+        assert_eq!(bracket.active_players(), vec!["c", "b"]);
+        assert_eq!(bracket.advance_round(vec![], &portfolios, 1).unwrap(), Some(4));
+
+        // Round 4: grand final "a" vs "b"; "a" wins without a reset
+        assert_eq!(bracket.active_players(), vec!["a", "b"]);
+        assert_eq!(bracket.advance_round(vec![], &portfolios, 1).unwrap(), None);
+        assert_eq!(bracket.placements(), vec!["a", "b", "c", "d"]);
+    }
+}
@@ -0,0 +1,96 @@
+/*!
+# Crypto Categories
+
+Themed asset pools for tournaments. Each category is a fixed membership list of asset IDs
+(CoinCap slugs such as `bitcoin` or `avalanche-2`), matching the frontend's category data.
+*/
+
+use async_graphql::Enum;
+use serde::{Deserialize, Serialize};
+
+/// Layer 1 blockchains
+pub const L1_CHAIN_ASSETS: &[&str] = &[
+    "bitcoin",
+    "ethereum",
+    "solana",
+    "cardano",
+    "polkadot",
+    "avalanche-2",
+    "algorand",
+    "cosmos",
+    "near-protocol",
+    "aptos",
+];
+
+/// Layer 2 scaling solutions
+pub const L2_CHAIN_ASSETS: &[&str] = &[
+    "polygon",
+    "arbitrum",
+    "optimism",
+    "immutable-x",
+    "loopring",
+    "metis",
+    "boba-network",
+    "starknet",
+];
+
+/// Community-driven meme coins
+pub const MEME_COIN_ASSETS: &[&str] = &[
+    "dogecoin",
+    "shiba-inu",
+    "pepe",
+    "floki",
+    "bonk",
+    "dogwifhat",
+    "baby-doge-coin",
+    "wojak",
+];
+
+/// Decentralized finance protocol tokens
+pub const DEFI_TOKEN_ASSETS: &[&str] = &[
+    "uniswap",
+    "aave",
+    "chainlink",
+    "curve-dao-token",
+    "maker",
+    "compound",
+    "synthetix-network-token",
+    "the-graph",
+    "pancakeswap",
+    "1inch",
+];
+
+/// Crypto category a tournament draws its picks from
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize, Enum)]
+pub enum CryptoCategory {
+    #[graphql(name = "L1_CHAINS")]
+    L1Chains,
+    #[graphql(name = "L2_CHAINS")]
+    L2Chains,
+    #[graphql(name = "MEME_COINS")]
+    MemeCoins,
+    #[graphql(name = "DEFI_TOKENS")]
+    DefiTokens,
+    /// Every asset from the other categories
+    #[default]
+    #[graphql(name = "ALL_CATEGORIES")]
+    AllCategories,
+}
+
+impl CryptoCategory {
+    /// Asset IDs eligible for picks in this category
+    pub fn assets(self) -> Vec<&'static str> {
+        match self {
+            CryptoCategory::L1Chains => L1_CHAIN_ASSETS.to_vec(),
+            CryptoCategory::L2Chains => L2_CHAIN_ASSETS.to_vec(),
+            CryptoCategory::MemeCoins => MEME_COIN_ASSETS.to_vec(),
+            CryptoCategory::DefiTokens => DEFI_TOKEN_ASSETS.to_vec(),
+            CryptoCategory::AllCategories => [L1_CHAIN_ASSETS, L2_CHAIN_ASSETS, MEME_COIN_ASSETS, DEFI_TOKEN_ASSETS].concat(),
+        }
+    }
+
+    /// Check whether an asset may be picked in this category
+    pub fn contains(self, asset_id: &str) -> bool {
+        self.assets().contains(&asset_id)
+    }
+}
@@ -6,21 +6,32 @@ Handles tournament creation, registration, and progression for traditional leagu
 
 #![cfg_attr(target_arch = "wasm32", no_main)]
 
-mod state;
+use std::collections::BTreeMap;
 
 use traditional_leagues::{
-    TraditionalLeaguesAbi, TraditionalLeaguesOperation, TraditionalLeaguesResponse,
-    Tournament, TournamentStatus, TournamentType,
-    PriceData, ScoringEngine,
-    CoinDraftsMessage, TournamentPortfolio,
+    TraditionalLeaguesAbi, TraditionalLeaguesOperation, TraditionalLeaguesParameters, TraditionalLeaguesResponse,
+    TournamentOptions, Bracket, CryptoCategory, PortfolioError, RoundRobin, Swiss, Tournament, TournamentStatus, TournamentType,
+    LeaderboardEntry, ScoringEngine, Team, apply_team_payouts, team_leaderboard,
+    Season, season_standings, DEFAULT_BONUS_SPLIT_PERCENT, DEFAULT_POINTS_TABLE,
+    CoinDraftsEvent, CoinDraftsMessage, DeliveryStatus, GameEvent, MessageEnvelope, MessageRecord, Portfolio, TournamentPortfolio, TraditionalLeaguesMessage,
+    CORE_NOTIFICATION_STREAM,
+    DEFAULT_MIN_PARTICIPANTS, MINUTE_MICROS,
 };
 use linera_sdk::{
 
-    linera_base_types::WithContractAbi,
+    linera_base_types::{StreamName, WithContractAbi},
     views::{RootView, View},
     Contract, ContractRuntime,
 };
-use state::TraditionalLeaguesState;
+use traditional_leagues::state::TraditionalLeaguesState;
+
+/// Result of scoring one round of a round-based tournament
+enum RoundOutcome {
+    /// Next round to play
+    Next(u32),
+    /// Final placements, best first
+    Finished(Vec<String>),
+}
 
 pub struct TraditionalLeaguesContract {
     state: TraditionalLeaguesState,
@@ -35,9 +46,9 @@ impl WithContractAbi for TraditionalLeaguesContract {
 
 impl Contract for TraditionalLeaguesContract {
     type InstantiationArgument = ();
-    type Parameters = ();
-    type EventValue = ();
-    type Message = CoinDraftsMessage;
+    type Parameters = TraditionalLeaguesParameters;
+    type EventValue = CoinDraftsEvent;
+    type Message = MessageEnvelope<CoinDraftsMessage>;
 
     async fn load(runtime: ContractRuntime<Self>) -> Self {
         let state = TraditionalLeaguesState::load(runtime.root_view_storage_context())
@@ -49,26 +60,54 @@ impl Contract for TraditionalLeaguesContract {
     async fn instantiate(&mut self, _argument: ()) {
         // Initialize the tournament counter
         self.state.tournament_counter.set(1);
+        self.state.admin.set(self.runtime.authenticated_signer());
     }
 
     async fn execute_operation(&mut self, operation: TraditionalLeaguesOperation) -> TraditionalLeaguesResponse {
         // Linera applications are reactive only - no background timers!
         // Tournament completion must be triggered by explicit operations or messages
-        
+
+        if operation.requires_core() && !self.called_by_core() {
+            log::warn!("Ignoring operation not sent by CoinDrafts Core: {:?}", operation);
+            return Self::untrusted_caller_response(&operation);
+        }
+
         match operation {
             TraditionalLeaguesOperation::CreateTournament {
                 name,
                 entry_fee_usdc,
                 max_participants,
                 tournament_type,
-                category,
-            } => self.create_tournament(name, entry_fee_usdc, max_participants, tournament_type, category).await,
+                options,
+            } => self.create_tournament(name, entry_fee_usdc, max_participants, tournament_type, options).await,
 
             TraditionalLeaguesOperation::RegisterForTournament {
                 tournament_id,
                 player_account,
             } => self.register_for_tournament(tournament_id, player_account).await,
 
+            TraditionalLeaguesOperation::VerifyPlayer { tournament_id, player_account, verified, reason } => {
+                self.complete_registration(tournament_id, player_account, verified, reason).await
+            }
+
+            TraditionalLeaguesOperation::UnregisterFromTournament { tournament_id } => {
+                self.unregister_from_tournament(tournament_id).await
+            }
+
+            TraditionalLeaguesOperation::CreateTeam {
+                tournament_id,
+                name,
+            } => self.create_team(tournament_id, name).await,
+
+            TraditionalLeaguesOperation::InviteToTeam {
+                team_id,
+                player_account,
+            } => self.invite_to_team(team_id, player_account).await,
+
+            TraditionalLeaguesOperation::AcceptTeamInvitation { team_id } => {
+                self.accept_team_invitation(team_id).await
+            }
+
             TraditionalLeaguesOperation::SubmitPortfolio {
                 tournament_id,
                 portfolio,
@@ -90,124 +129,156 @@ impl Contract for TraditionalLeaguesContract {
                 end_prices,
             } => self.end_tournament(tournament_id, end_prices).await,
 
-            TraditionalLeaguesOperation::CompleteTournament { tournament_id } => {
-                self.complete_tournament(tournament_id).await
+            TraditionalLeaguesOperation::CompleteTournament { tournament_id, end_prices } => {
+                self.complete_tournament(tournament_id, end_prices).await
             }
 
             TraditionalLeaguesOperation::CheckExpiredTournaments => {
                 self.check_expired_tournaments_reactive().await
             }
-        }
-    }
 
-    async fn execute_message(&mut self, message: CoinDraftsMessage) {
-        match message {
-            CoinDraftsMessage::CreateTournament { game_id, tournament_name, entry_fee_usdc, max_participants } => {
-                // Handle tournament creation request from CoinDrafts Core
-                log::info!("Creating tournament: {} for game {}", tournament_name, game_id);
-                
-                // Create tournament using existing method
-                let response = self.create_tournament(
-                    tournament_name.clone(),
-                    entry_fee_usdc,
-                    max_participants,
-                    TournamentType::SingleElimination, // Default for now
-                    "ALL_CATEGORIES".to_string(), // Default category
-                ).await;
-                
-                // Extract tournament_id from response
-                if let TraditionalLeaguesResponse::TournamentCreated { tournament_id } = response {
-                    // Get tournament details to send back
-                    if let Ok(Some(_tournament)) = self.state.tournaments.get(&tournament_id).await {
-                        // Send confirmation back to CoinDrafts Core
-                        let origin_chain_id = self.runtime.message_origin_chain_id()
-                            .expect("Message origin should be available when executing a message");
-                            
-                        let confirmation_message = CoinDraftsMessage::CreateTournament {
-                            game_id: game_id.clone(),
-                            tournament_name: format!("Tournament {} created", tournament_id),
-                            entry_fee_usdc: entry_fee_usdc,
-                            max_participants: max_participants,
-                        };
-                        
-                        self.runtime
-                            .prepare_message(confirmation_message)
-                            .with_authentication()
-                            .send_to(origin_chain_id);
-                    }
-                }
+            TraditionalLeaguesOperation::AdvanceRound {
+                tournament_id,
+                end_prices,
+            } => self.advance_round(tournament_id, end_prices).await,
+
+            TraditionalLeaguesOperation::CreateSeason {
+                name,
+                starts_at,
+                ends_at,
+                points_table,
+                bonus_pool_usdc,
+                bonus_split_percent,
+            } => self.create_season(name, starts_at, ends_at, points_table, bonus_pool_usdc, bonus_split_percent).await,
+
+            TraditionalLeaguesOperation::EndSeason { season_id } => self.end_season(season_id).await,
+
+            TraditionalLeaguesOperation::CreateTournamentForGame { game_id, name, entry_fee_usdc, max_participants, duration_minutes } => {
+                self.create_game_tournament(game_id, name, entry_fee_usdc, max_participants, duration_minutes).await
             }
-            
-            CoinDraftsMessage::RegisterPlayerForTournament { game_id, tournament_id, player_profile } => {
-                // Handle player registration request from CoinDrafts Core
-                log::info!("Registering player {} for tournament {} in game {}", 
-                          player_profile, tournament_id, game_id);
-                
-                // Register player using existing method
-                let response = self.register_for_tournament(
-                    tournament_id.clone(),
-                    player_profile,
-                ).await;
-                
-                // Could send back confirmation if needed
-                log::info!("Player registration result: {:?}", response);
+
+            TraditionalLeaguesOperation::SyncPortfolio { game_id, tournament_id, portfolio } => {
+                self.sync_portfolio(game_id, tournament_id, portfolio).await
             }
-            
-            CoinDraftsMessage::SyncPortfolio { game_id, tournament_id, portfolio } => {
-                // Handle portfolio sync from CoinDrafts Core
-                log::info!("Syncing portfolio for tournament {} in game {} with portfolio: {}", tournament_id, game_id, portfolio);
-                
-                // Create a default tournament portfolio since portfolio is a JSON string
-                // TODO: Parse JSON portfolio string for real implementation
-                let tournament_portfolio = TournamentPortfolio {
-                    crypto_picks: vec![
-                        "BTC".to_string(),
-                        "ETH".to_string(),
-                        "SOL".to_string(),
-                        "ADA".to_string(),
-                        "DOT".to_string(),
-                    ],
-                    strategy_notes: Some(format!("Portfolio synced from game {}", game_id)),
-                };
-                
-                // Submit portfolio using existing method
-                let response = self.submit_portfolio(
-                    tournament_id,
-                    tournament_portfolio,
-                ).await;
-                
-                log::info!("Portfolio submission result: {:?}", response);
+
+            TraditionalLeaguesOperation::Acknowledge { message_id, error } => {
+                let success = self.settle_notification(message_id, error).await;
+                TraditionalLeaguesResponse::Acknowledged { success }
             }
-            
-            CoinDraftsMessage::GetTournamentStatus { tournament_id } => {
-                // Handle tournament status request
-                log::info!("Getting status for tournament {}", tournament_id);
-                
-                // Could send back status update if needed
-                // For now, just log the request
+
+            TraditionalLeaguesOperation::ReceiveCoreRequest { envelope } => {
+                let leagues_chain = self.runtime.application_creator_chain_id();
+                if self.runtime.chain_id() == leagues_chain {
+                    self.receive_core_request(envelope).await;
+                } else {
+                    // Only this application's own instances can message the leagues chain
+                    self.runtime.send_message(leagues_chain, envelope);
+                }
+                TraditionalLeaguesResponse::CoreRequestReceived { success: true }
             }
-            
-            CoinDraftsMessage::VerifyPlayer { game_id: _, player_account: _, tournament_id: _, verified: _ } => {
-                // This message type seems wrong - Traditional Leagues should be sending verification requests, not receiving them
-                log::warn!("Received unexpected VerifyPlayer message - this should be sent BY Traditional Leagues");
+
+            TraditionalLeaguesOperation::ReadNotification { chain_id, index } => {
+                let envelope = match self.runtime.read_event(chain_id, StreamName::from(CORE_NOTIFICATION_STREAM), index) {
+                    CoinDraftsEvent::Notification(envelope) => Some(envelope),
+                    event => {
+                        log::warn!("Event {} on the notification stream is not a notification: {:?}", index, event);
+                        None
+                    }
+                };
+                TraditionalLeaguesResponse::Notification { envelope }
+            }
+
+            TraditionalLeaguesOperation::RetryMessage { message_id } => {
+                self.retry_notification(message_id).await
             }
         }
     }
 
+    /// Core requests forwarded by this application's instance on the core chain, which
+    /// accepted them from the core application itself
+    async fn execute_message(&mut self, envelope: MessageEnvelope<CoinDraftsMessage>) {
+        self.receive_core_request(envelope).await;
+    }
+
     async fn store(mut self) {
         self.state.save().await.expect("Failed to save state");
     }
 }
 
 impl TraditionalLeaguesContract {
+    /// Whether the operation was called by the configured CoinDrafts Core application
+    fn called_by_core(&mut self) -> bool {
+        let core = self.runtime.application_parameters().core_application_id;
+        core.is_some() && self.runtime.authenticated_caller_id() == core
+    }
+
+    /// Whether the operation is signed by the account that created the application
+    fn signed_by_admin(&mut self) -> bool {
+        let signer = self.runtime.authenticated_signer();
+        signer.is_some() && signer == *self.state.admin.get()
+    }
+
+    /// Answer to a core-only operation called by anyone else
+    fn untrusted_caller_response(operation: &TraditionalLeaguesOperation) -> TraditionalLeaguesResponse {
+        match operation {
+            TraditionalLeaguesOperation::VerifyPlayer { .. } => TraditionalLeaguesResponse::RegistrationRejected {
+                reason: "Verification must come from CoinDrafts Core".to_string(),
+            },
+            // Portfolios are stored under the account core names, so only core may sync them
+            TraditionalLeaguesOperation::SyncPortfolio { .. } => {
+                TraditionalLeaguesResponse::PortfolioRejected { error: PortfolioError::UntrustedSync }
+            }
+            TraditionalLeaguesOperation::Acknowledge { .. } => TraditionalLeaguesResponse::Acknowledged { success: false },
+            TraditionalLeaguesOperation::ReceiveCoreRequest { .. } => {
+                TraditionalLeaguesResponse::CoreRequestReceived { success: false }
+            }
+            TraditionalLeaguesOperation::ReadNotification { .. } => TraditionalLeaguesResponse::Notification { envelope: None },
+            operation => unreachable!("{:?} is open to every caller", operation),
+        }
+    }
+
     async fn create_tournament(
         &mut self,
         name: String,
         entry_fee_usdc: u64,
         max_participants: u32,
         tournament_type: TournamentType,
-        category: String,
+        options: TournamentOptions,
     ) -> TraditionalLeaguesResponse {
+        let TournamentOptions {
+            category,
+            duration_minutes,
+            return_scale,
+            round_count,
+            team_size,
+            team_scoring,
+            captain_picks,
+            min_participants,
+            registration_minutes,
+        } = options;
+        if duration_minutes == 0 || return_scale == 0 {
+            log::warn!("Rejecting tournament {}: duration and return scale must be positive", name);
+            return TraditionalLeaguesResponse::TournamentCreated {
+                tournament_id: "error".to_string(),
+            };
+        }
+
+        // Teams score on combined returns, which round-based pairings cannot express
+        if team_size == 1 || (team_size > 1 && tournament_type.is_round_based()) {
+            log::warn!("Rejecting tournament {}: teams need at least 2 members and a non-round-based format", name);
+            return TraditionalLeaguesResponse::TournamentCreated {
+                tournament_id: "error".to_string(),
+            };
+        }
+
+        let min_participants = min_participants.max(DEFAULT_MIN_PARTICIPANTS);
+        if min_participants > max_participants {
+            log::warn!("Rejecting tournament {}: needs {} entrants but holds {}", name, min_participants, max_participants);
+            return TraditionalLeaguesResponse::TournamentCreated {
+                tournament_id: "error".to_string(),
+            };
+        }
+
         let tournament_id = self.state.generate_tournament_id().await;
         let timestamp = self.runtime.system_time();
 
@@ -222,19 +293,37 @@ impl TraditionalLeaguesContract {
             created_at: timestamp.micros(),
             started_at: None,
             completed_at: None,
-            category: category.clone(),
+            category,
             start_prices: None,
             end_prices: None,
+            duration_minutes,
+            return_scale,
+            current_round: 0,
+            round_count,
+            game_id: None,
+            team_size,
+            team_scoring,
+            captain_picks,
+            min_participants,
+            registration_deadline: (registration_minutes > 0)
+                .then(|| timestamp.micros().saturating_add(registration_minutes.saturating_mul(MINUTE_MICROS))),
         };
 
         // Store tournament
-        if let Err(_e) = self.state.tournaments.insert(&tournament_id, tournament.clone()) {
+        if let Err(_e) = self.save_tournament(tournament.clone()).await {
             return TraditionalLeaguesResponse::TournamentCreated {
                 tournament_id: "error".to_string(),
             };
         }
 
         // Tournament created successfully
+        self.publish(GameEvent::GameCreated {
+            game_id: tournament_id.clone(),
+            name,
+            entry_fee_usdc,
+            max_players: max_participants,
+            created_at: timestamp.micros(),
+        });
 
         TraditionalLeaguesResponse::TournamentCreated { tournament_id }
     }
@@ -244,287 +333,838 @@ impl TraditionalLeaguesContract {
         tournament_id: String,
         player_account: String,
     ) -> TraditionalLeaguesResponse {
+        // Players register themselves; only core may name the account it registers
+        if !self.called_by_core() {
+            let Some(signer) = self.runtime.authenticated_signer() else {
+                log::warn!("Rejecting registration for {}: no authenticated signer", tournament_id);
+                return TraditionalLeaguesResponse::RegistrationRejected {
+                    reason: "Registration must be signed by the player".to_string(),
+                };
+            };
+            if signer.to_string() != player_account {
+                log::warn!("Rejecting registration of {} for {} signed by {}", player_account, tournament_id, signer);
+                return TraditionalLeaguesResponse::RegistrationRejected {
+                    reason: "Players can only register themselves".to_string(),
+                };
+            }
+        }
+
         // Check if tournament exists and is accepting registrations
         match self.state.tournaments.get(&tournament_id).await {
-            Ok(Some(mut tournament)) if tournament.status == TournamentStatus::Registration => {
-                // Check if tournament is full
-                match self.state.is_tournament_full(&tournament_id).await {
-                    Ok(false) => {
-                        // Add participant
-                        if let Err(_e) = self.state.add_participant(&tournament_id, player_account).await {
-                            return TraditionalLeaguesResponse::PlayerRegistered { success: false };
-                        }
-                        
-                        // Update tournament current_participants count
-                        tournament.current_participants += 1;
-                        if let Err(_e) = self.state.tournaments.insert(&tournament_id, tournament.clone()) {
-                            return TraditionalLeaguesResponse::PlayerRegistered { success: false };
-                        }
-                        
-                        TraditionalLeaguesResponse::PlayerRegistered { success: true }
-                    }
-                    Ok(true) => {
-                        TraditionalLeaguesResponse::PlayerRegistered { success: false }
-                    }
-                    Err(_e) => {
+            Ok(Some(tournament)) if tournament.status == TournamentStatus::Registration => {
+                // Tournaments behind a core game wait for core to verify the player
+                if let Some(game_id) = tournament.game_id.clone() {
+                    return self.hold_registration(tournament, game_id, player_account).await;
+                }
+
+                match self.admit_participant(tournament, &player_account).await {
+                    Ok(()) => TraditionalLeaguesResponse::PlayerRegistered { success: true },
+                    Err(e) => {
+                        log::warn!("Failed to register {} for {}: {}", player_account, tournament_id, e);
                         TraditionalLeaguesResponse::PlayerRegistered { success: false }
                     }
                 }
             }
-            Ok(Some(_)) => {
-                TraditionalLeaguesResponse::PlayerRegistered { success: false }
-            }
-            Ok(None) => {
-                TraditionalLeaguesResponse::PlayerRegistered { success: false }
-            }
-            Err(_e) => {
-                TraditionalLeaguesResponse::PlayerRegistered { success: false }
-            }
+            _ => TraditionalLeaguesResponse::PlayerRegistered { success: false },
         }
     }
 
-    async fn submit_portfolio(
+    /// Add a player to a tournament that is open for registration
+    async fn admit_participant(&mut self, mut tournament: Tournament, player_account: &str) -> Result<(), String> {
+        if tournament.current_participants >= tournament.max_participants {
+            return Err("Tournament is full".to_string());
+        }
+
+        // Add participant; registering twice is rejected
+        if !self.state.add_participant(&tournament.id, player_account, tournament.current_participants).await? {
+            return Err("Player is already registered".to_string());
+        }
+
+        // Update tournament current_participants count
+        tournament.current_participants += 1;
+        let tournament_id = tournament.id.clone();
+        self.save_tournament(tournament).await?;
+
+        let joined_at = self.runtime.system_time().micros();
+        self.publish(GameEvent::PlayerJoined {
+            game_id: tournament_id,
+            player_account: player_account.to_string(),
+            joined_at,
+        });
+        Ok(())
+    }
+
+    /// Hold a registration for a game-linked tournament until core verifies the player
+    async fn hold_registration(
         &mut self,
-        tournament_id: String,
-        portfolio: traditional_leagues::TournamentPortfolio,
+        tournament: Tournament,
+        game_id: String,
+        player_account: String,
     ) -> TraditionalLeaguesResponse {
-        let portfolio_key = format!("{}-{}", tournament_id, self.runtime.authenticated_signer().unwrap());
-        
-        if let Err(_e) = self.state.portfolios.insert(&portfolio_key, portfolio) {
-            return TraditionalLeaguesResponse::PortfolioSubmitted { success: false };
+        let key = (tournament.id.clone(), player_account.clone());
+        let pending = self.state.pending_registrations.contains_key(&key).await.unwrap_or(true);
+        let registered = self.state.is_participant(&tournament.id, &player_account).await.unwrap_or(true);
+        if pending || registered || tournament.current_participants >= tournament.max_participants {
+            return TraditionalLeaguesResponse::RegistrationPending { success: false };
         }
 
-        TraditionalLeaguesResponse::PortfolioSubmitted { success: true }
+        let requested_at = self.runtime.system_time().micros();
+        if let Err(e) = self.state.pending_registrations.insert(&key, requested_at) {
+            log::error!("Failed to hold registration of {} for {}: {}", player_account, tournament.id, e);
+            return TraditionalLeaguesResponse::RegistrationPending { success: false };
+        }
+        let _ = self.state.registration_rejections.remove(&key);
+
+        // Core calling on the same chain verifies right away; anyone else waits for core's answer
+        if !self.called_by_core() {
+            self.notify_core(TraditionalLeaguesMessage::VerifyPlayer {
+                game_id,
+                player_account: player_account.clone(),
+                tournament_id: tournament.id.clone(),
+            });
+        }
+
+        log::info!("Registration of {} for {} awaits verification", player_account, tournament.id);
+        TraditionalLeaguesResponse::RegistrationPending { success: true }
     }
 
-    async fn submit_portfolio_for_account(
+    /// Complete a held registration once core has verified or rejected the player
+    async fn complete_registration(
         &mut self,
         tournament_id: String,
         player_account: String,
-        portfolio: traditional_leagues::TournamentPortfolio,
+        verified: bool,
+        reason: Option<String>,
     ) -> TraditionalLeaguesResponse {
-        // Use the provided player_account instead of authenticated signer
-        let portfolio_key = format!("{}-{}", tournament_id, player_account);
-        
-        if let Err(_e) = self.state.portfolios.insert(&portfolio_key, portfolio.clone()) {
-            return TraditionalLeaguesResponse::PortfolioSubmitted { success: false };
+        let key = (tournament_id.clone(), player_account.clone());
+        if !self.state.pending_registrations.contains_key(&key).await.unwrap_or(false) {
+            log::warn!("No pending registration of {} for {}", player_account, tournament_id);
+            return TraditionalLeaguesResponse::RegistrationRejected { reason: "No pending registration".to_string() };
+        }
+        if let Err(e) = self.state.pending_registrations.remove(&key) {
+            log::error!("Failed to clear pending registration of {} for {}: {}", player_account, tournament_id, e);
+            return TraditionalLeaguesResponse::RegistrationRejected { reason: "Failed to update registration".to_string() };
         }
 
-        TraditionalLeaguesResponse::PortfolioSubmitted { success: true }
-    }
+        let Ok(Some(tournament)) = self.state.tournaments.get(&tournament_id).await else {
+            return TraditionalLeaguesResponse::RegistrationRejected { reason: "Tournament not found".to_string() };
+        };
+        let game_id = tournament.game_id.clone().unwrap_or_default();
 
-    async fn complete_tournament(&mut self, tournament_id: String) -> TraditionalLeaguesResponse {
-        match self.state.tournaments.get(&tournament_id).await {
-            Ok(Some(mut tournament)) => {
-                // Check if tournament should be completed (10 minutes elapsed since start)
-                let current_time = self.runtime.system_time().micros();
-                let tournament_duration_micros = 10 * 60 * 1_000_000; // 10 minutes in microseconds
-                
-                let should_complete = match tournament.started_at {
-                    Some(start_time) => {
-                        // Auto-complete if 10 minutes have passed since start
-                        current_time >= start_time + tournament_duration_micros
-                    }
-                    None => {
-                        // Tournament hasn't started yet, can't complete
-                        false
-                    }
-                };
+        let outcome = if !verified {
+            Err(reason.unwrap_or_else(|| "Player verification failed".to_string()))
+        } else if tournament.status != TournamentStatus::Registration {
+            Err("Registration has closed".to_string())
+        } else {
+            self.admit_participant(tournament, &player_account).await
+        };
 
-                // Only complete if tournament is in progress and time has elapsed
-                if tournament.status != TournamentStatus::InProgress || !should_complete {
-                    return TraditionalLeaguesResponse::TournamentCompleted { winners: vec![] };
+        self.notify_core(TraditionalLeaguesMessage::PlayerVerified {
+            game_id,
+            tournament_id: tournament_id.clone(),
+            player_account: player_account.clone(),
+            verified: outcome.is_ok(),
+            reason: outcome.clone().err(),
+        });
+
+        match outcome {
+            Ok(()) => TraditionalLeaguesResponse::PlayerRegistered { success: true },
+            Err(reason) => {
+                log::warn!("Rejected registration of {} for {}: {}", player_account, tournament_id, reason);
+                if let Err(e) = self.state.registration_rejections.insert(&key, reason.clone()) {
+                    log::error!("Failed to record rejection of {} for {}: {}", player_account, tournament_id, e);
                 }
+                TraditionalLeaguesResponse::RegistrationRejected { reason }
+            }
+        }
+    }
 
-                // Update status to Completed
-                tournament.status = TournamentStatus::Completed;
-                tournament.completed_at = Some(current_time);
+    /// Withdraw the signer during registration: drop their entry and portfolio and refund the fee
+    async fn unregister_from_tournament(&mut self, tournament_id: String) -> TraditionalLeaguesResponse {
+        let failure = || TraditionalLeaguesResponse::PlayerUnregistered { success: false, refund_usdc: 0 };
+        let Some(signer) = self.runtime.authenticated_signer() else {
+            log::warn!("Rejecting withdrawal from {}: no authenticated signer", tournament_id);
+            return failure();
+        };
+        let player_account = signer.to_string();
 
-                // Calculate tournament winners using scoring engine
-                let winners = self.calculate_tournament_winners(&tournament_id).await;
+        let mut tournament = match self.state.tournaments.get(&tournament_id).await {
+            Ok(Some(tournament)) if tournament.status == TournamentStatus::Registration => tournament,
+            _ => {
+                log::warn!("Tournament {} is not open for withdrawal", tournament_id);
+                return failure();
+            }
+        };
 
-                // Store updated tournament
-                if let Err(_e) = self.state.tournaments.insert(&tournament_id, tournament) {
-                    return TraditionalLeaguesResponse::TournamentCompleted { winners: vec![] };
-                }
+        let refund_usdc = match self.withdraw_participant(&mut tournament, &player_account).await {
+            Ok(refund_usdc) => refund_usdc,
+            Err(e) => {
+                log::warn!("{}", e);
+                return failure();
+            }
+        };
+        if let Err(e) = self.save_tournament(tournament).await {
+            log::error!("Failed to update tournament {}: {:?}", tournament_id, e);
+            return failure();
+        }
 
-                // Store results
-                if let Err(_e) = self.state.results.insert(&tournament_id, winners.clone()) {
-                    // Continue even if results storage fails  
-                }
+        log::info!("Player {} withdrew from {} and was refunded {}", player_account, tournament_id, refund_usdc);
+        TraditionalLeaguesResponse::PlayerUnregistered { success: true, refund_usdc }
+    }
 
-                // Tournament completed successfully
-                TraditionalLeaguesResponse::TournamentCompleted { winners }
-            }
-            _ => TraditionalLeaguesResponse::TournamentCompleted { winners: vec![] },
+    /// Remove a registered player and refund their entry fee, telling core when the
+    /// tournament backs a game. The caller saves the updated tournament.
+    async fn withdraw_participant(&mut self, tournament: &mut Tournament, player_account: &str) -> Result<u64, String> {
+        if !self.state.remove_participant(&tournament.id, player_account, tournament.current_participants).await? {
+            return Err(format!("Player {} is not registered for {}", player_account, tournament.id));
+        }
+        tournament.current_participants = tournament.current_participants.saturating_sub(1);
+        self.state.leave_team(&tournament.id, player_account).await?;
+
+        let portfolio_key = format!("{}-{}", tournament.id, player_account);
+        self.state.portfolios.remove(&portfolio_key)
+            .map_err(|e| format!("Failed to remove portfolio {}: {}", portfolio_key, e))?;
+        self.record_portfolio_change(&tournament.id, player_account).await?;
+        let refund_usdc = tournament.entry_fee_usdc;
+        self.state.refunds.insert(&portfolio_key, refund_usdc)
+            .map_err(|e| format!("Failed to record refund {}: {}", portfolio_key, e))?;
+
+        if let Some(game_id) = tournament.game_id.clone() {
+            self.notify_core(TraditionalLeaguesMessage::PlayerUnregistered {
+                game_id,
+                tournament_id: tournament.id.clone(),
+                player_account: player_account.to_string(),
+                refund_usdc,
+            });
         }
+        Ok(refund_usdc)
     }
 
-    /// Reactive alternative to background timers - check for expired tournaments
-    /// Must be triggered explicitly by users/frontend or scheduled operations
-    async fn check_expired_tournaments_reactive(&mut self) -> TraditionalLeaguesResponse {
-        let mut completed_tournaments = Vec::new();
-        let current_time = self.runtime.system_time().micros();
-        let tournament_duration_micros = 10 * 60 * 1_000_000; // 10 minutes in microseconds
+    /// Players of a team tournament who are not on a full team. They are withdrawn and
+    /// refunded when the tournament starts, so teams compete at equal strength and nobody
+    /// pays into a prize they cannot win.
+    async fn players_without_full_team(&self, tournament: &Tournament) -> Result<Vec<String>, String> {
+        let full_teams: Vec<String> = self.state.tournament_teams(&tournament.id).await?
+            .into_iter()
+            .filter(|team| team.is_full(tournament.team_size))
+            .map(|team| team.id)
+            .collect();
 
-        // Check all tournaments for expiration using proper Linera MapView iteration
-        let mut tournaments_to_complete = Vec::new();
-        
-        if let Err(_) = self.state.tournaments.for_each_index_value(|tournament_id, tournament| {
-            // Check if tournament is in progress and has expired
-            if tournament.status == TournamentStatus::InProgress {
-                if let Some(start_time) = tournament.started_at {
-                    if current_time >= start_time + tournament_duration_micros {
-                        // Tournament has expired, mark for completion
-                        tournaments_to_complete.push(tournament_id.clone());
-                    }
-                }
+        let mut players = Vec::new();
+        for player_account in self.state.all_participants(&tournament.id, tournament.current_participants).await? {
+            let team_id = self.state.team_of(&tournament.id, &player_account).await?;
+            if !team_id.is_some_and(|team_id| full_teams.contains(&team_id)) {
+                players.push(player_account);
             }
-            Ok(())
-        }).await {
-            return TraditionalLeaguesResponse::ExpiredTournamentsChecked { 
-                completed_tournaments: vec![] 
-            };
         }
+        Ok(players)
+    }
 
-        // Complete expired tournaments
-        for tournament_id in tournaments_to_complete {
-            let response = self.complete_tournament(tournament_id.clone()).await;
-            if let TraditionalLeaguesResponse::TournamentCompleted { winners } = response {
-                if !winners.is_empty() {
-                    completed_tournaments.push(tournament_id);
-                }
+    /// Create a team captained by the signer, who must be registered and not yet on a team
+    async fn create_team(&mut self, tournament_id: String, name: String) -> TraditionalLeaguesResponse {
+        let error = || TraditionalLeaguesResponse::TeamCreated { team_id: "error".to_string() };
+        let Some(signer) = self.runtime.authenticated_signer() else {
+            log::warn!("Rejecting team for {}: no authenticated signer", tournament_id);
+            return error();
+        };
+        let captain_account = signer.to_string();
+
+        match self.state.tournaments.get(&tournament_id).await {
+            Ok(Some(tournament))
+                if tournament.is_team_tournament() && tournament.status == TournamentStatus::Registration => {}
+            _ => {
+                log::warn!("Tournament {} is not a team tournament open for registration", tournament_id);
+                return error();
             }
         }
 
-        TraditionalLeaguesResponse::ExpiredTournamentsChecked { completed_tournaments }
+        if !self.state.is_participant(&tournament_id, &captain_account).await.unwrap_or(false)
+            || self.state.team_of(&tournament_id, &captain_account).await.unwrap_or(None).is_some()
+        {
+            log::warn!("Player {} must be registered for {} and not already on a team", captain_account, tournament_id);
+            return error();
+        }
+
+        match self.state.add_team(&tournament_id, name, captain_account).await {
+            Ok(team_id) => {
+                log::info!("Team {} created in {}", team_id, tournament_id);
+                TraditionalLeaguesResponse::TeamCreated { team_id }
+            }
+            Err(e) => {
+                log::error!("{}", e);
+                error()
+            }
+        }
     }
 
-    /// Calculate tournament winners using portfolio performance scoring
-    async fn calculate_tournament_winners(&mut self, tournament_id: &str) -> Vec<String> {
-        // Get tournament participants to collect their portfolios
-        let participants = match self.state.participants.get(tournament_id).await {
-            Ok(Some(participants)) => participants,
-            _ => return vec![], // No participants
+    /// Invite a registered player who is not yet on a team; only the captain may invite
+    async fn invite_to_team(&mut self, team_id: String, player_account: String) -> TraditionalLeaguesResponse {
+        let Some(signer) = self.runtime.authenticated_signer() else {
+            log::warn!("Rejecting invitation to {}: no authenticated signer", team_id);
+            return TraditionalLeaguesResponse::TeamInvitationSent { success: false };
         };
-
-        // Get the tournament to know which round to check
-        let _tournament = match self.state.tournaments.get(tournament_id).await {
-            Ok(Some(tournament)) => tournament,
-            _ => return vec![], // Tournament not found
+        let Some((tournament, mut team)) = self.open_team(&team_id).await else {
+            return TraditionalLeaguesResponse::TeamInvitationSent { success: false };
         };
 
-        // Collect portfolios from all participants
-        let mut portfolio_vec: Vec<(String, traditional_leagues::TournamentPortfolio)> = Vec::new();
-        
-        for participant in participants {
-            let portfolio_key = format!("{}-{}", tournament_id, participant);
-            if let Ok(Some(portfolio)) = self.state.portfolios.get(&portfolio_key).await {
-                portfolio_vec.push((participant, portfolio));
-            }
+        if team.captain != signer.to_string() {
+            log::warn!("Only the captain of {} can invite players", team_id);
+            return TraditionalLeaguesResponse::TeamInvitationSent { success: false };
+        }
+        if team.is_full(tournament.team_size)
+            || !self.state.is_participant(&tournament.id, &player_account).await.unwrap_or(false)
+            || self.state.team_of(&tournament.id, &player_account).await.unwrap_or(None).is_some()
+            || !team.invite(&player_account)
+        {
+            log::warn!("Cannot invite {} to team {}", player_account, team_id);
+            return TraditionalLeaguesResponse::TeamInvitationSent { success: false };
         }
 
-        if portfolio_vec.is_empty() {
-            return vec![];
+        if let Err(e) = self.state.teams.insert(&team_id, team) {
+            log::error!("Failed to update team {}: {:?}", team_id, e);
+            return TraditionalLeaguesResponse::TeamInvitationSent { success: false };
         }
+        TraditionalLeaguesResponse::TeamInvitationSent { success: true }
+    }
 
-        // Get tournament info for prize pool calculation and price snapshots
-        let tournament = match self.state.tournaments.get(tournament_id).await {
-            Ok(Some(tournament)) => tournament,
-            _ => return vec![],
+    /// Join a team the signer has been invited to
+    async fn accept_team_invitation(&mut self, team_id: String) -> TraditionalLeaguesResponse {
+        let Some(signer) = self.runtime.authenticated_signer() else {
+            log::warn!("Rejecting acceptance of {}: no authenticated signer", team_id);
+            return TraditionalLeaguesResponse::TeamJoined { success: false };
         };
-
-        // Build price data from tournament snapshots instead of mock prices
-        let price_data = match (&tournament.start_prices, &tournament.end_prices) {
-            (Some(start_prices), Some(end_prices)) => {
-                // Calculate tournament duration in minutes
-                let duration_minutes = if let (Some(started), Some(completed)) = (tournament.started_at, tournament.completed_at) {
-                    ((completed - started) / 1_000_000) / 60 // Convert microseconds to minutes
-                } else {
-                    u64::MAX // Unknown duration, don't amplify
-                };
-                
-                // Amplify returns 100x for short tournaments (< 30 minutes) for demo purposes
-                let amplification_factor = if duration_minutes < 30 { 100 } else { 1 };
-                
-                // Create PriceData from real snapshots
-                let mut prices = Vec::new();
-                for start_snap in start_prices {
-                    if let Some(end_snap) = end_prices.iter().find(|e| e.crypto_id == start_snap.crypto_id) {
-                        // Calculate percentage change: ((end - start) / start) * 10000
-                        let percentage_change = if start_snap.price_usd > 0 {
-                            let change = (end_snap.price_usd as i64) - (start_snap.price_usd as i64);
-                            let base_change = ((change * 10000) / (start_snap.price_usd as i64)) as i32;
-                            base_change * amplification_factor
-                        } else {
-                            0
-                        };
-                        
-                        prices.push(PriceData {
-                            symbol: start_snap.crypto_id.clone(),
-                            start_price: start_snap.price_usd,
-                            end_price: end_snap.price_usd,
-                            percentage_change,
-                        });
-                    }
-                }
-                prices
-            },
-            _ => {
-                // Fallback to mock prices if snapshots not available (shouldn't happen in production)
-                log::warn!("Tournament {} missing price snapshots, using mock data", tournament_id);
-                PriceData::get_mock_prices()
-            }
+        let player_account = signer.to_string();
+        let Some((tournament, mut team)) = self.open_team(&team_id).await else {
+            return TraditionalLeaguesResponse::TeamJoined { success: false };
         };
 
-        let total_prize_pool = tournament.entry_fee_usdc * tournament.current_participants as u64;
+        if !self.state.is_participant(&tournament.id, &player_account).await.unwrap_or(false)
+            || self.state.team_of(&tournament.id, &player_account).await.unwrap_or(None).is_some()
+            || !team.accept(&player_account, tournament.team_size)
+        {
+            log::warn!("Player {} cannot join team {}", player_account, team_id);
+            return TraditionalLeaguesResponse::TeamJoined { success: false };
+        }
 
-        // Calculate leaderboard using scoring engine
-        let leaderboard = ScoringEngine::calculate_leaderboard(
-            portfolio_vec,
-            &price_data,
-            total_prize_pool,
-        );
+        let membership = (tournament.id.clone(), player_account.clone());
+        if let Err(e) = self.state.team_memberships.insert(&membership, team_id.clone()) {
+            log::error!("Failed to record membership of {} in {}: {:?}", player_account, team_id, e);
+            return TraditionalLeaguesResponse::TeamJoined { success: false };
+        }
+        if let Err(e) = self.state.teams.insert(&team_id, team) {
+            log::error!("Failed to update team {}: {:?}", team_id, e);
+            return TraditionalLeaguesResponse::TeamJoined { success: false };
+        }
+        if let Err(e) = self.advance_leaderboard_revision(&tournament.id).await {
+            log::error!("{}", e);
+            return TraditionalLeaguesResponse::TeamJoined { success: false };
+        }
 
-        // Return top 3 winners (or all players if less than 3)
-        leaderboard
-            .into_iter()
-            .take(3)
-            .map(|entry| entry.player_account)
-            .collect()
+        log::info!("Player {} joined team {}", player_account, team_id);
+        TraditionalLeaguesResponse::TeamJoined { success: true }
     }
 
-    /// Start tournament with price snapshot
-    async fn start_tournament(
+    /// Load a team whose tournament is still open for registration
+    async fn open_team(&self, team_id: &str) -> Option<(Tournament, Team)> {
+        let team = self.state.teams.get(team_id).await.ok().flatten()?;
+        match self.state.tournaments.get(&team.tournament_id).await {
+            Ok(Some(tournament)) if tournament.status == TournamentStatus::Registration => Some((tournament, team)),
+            _ => {
+                log::warn!("Team {} is not open for changes", team_id);
+                None
+            }
+        }
+    }
+
+    async fn submit_portfolio(
         &mut self,
         tournament_id: String,
-        start_prices: Vec<traditional_leagues::PriceSnapshot>,
+        portfolio: traditional_leagues::TournamentPortfolio,
     ) -> TraditionalLeaguesResponse {
-        // Get tournament
-        let mut tournament = match self.state.tournaments.get(&tournament_id).await {
-            Ok(Some(tournament)) => tournament,
-            _ => {
-                log::error!("Tournament {} not found", tournament_id);
-                return TraditionalLeaguesResponse::TournamentStarted {
-                    success: false,
-                    timestamp: 0,
-                };
-            }
+        let Some(signer) = self.runtime.authenticated_signer() else {
+            log::warn!("Rejecting portfolio for {}: no authenticated signer", tournament_id);
+            return TraditionalLeaguesResponse::PortfolioRejected { error: PortfolioError::MissingSigner };
         };
 
-        // Update tournament status
-        let timestamp = self.runtime.system_time().micros();
-        tournament.status = TournamentStatus::InProgress;
-        tournament.started_at = Some(timestamp);
-        tournament.start_prices = Some(start_prices);
+        self.store_portfolio(tournament_id, signer.to_string(), portfolio).await
+    }
+
+    async fn submit_portfolio_for_account(
+        &mut self,
+        tournament_id: String,
+        player_account: String,
+        portfolio: traditional_leagues::TournamentPortfolio,
+    ) -> TraditionalLeaguesResponse {
+        if !self.signed_by_admin() {
+            log::warn!("Rejecting portfolio for {} in {}: not signed by the application creator", player_account, tournament_id);
+            return TraditionalLeaguesResponse::PortfolioRejected { error: PortfolioError::NotAdmin };
+        }
+        self.store_portfolio(tournament_id, player_account, portfolio).await
+    }
+
+    /// Validate a submission and store it under the player's portfolio key
+    async fn store_portfolio(
+        &mut self,
+        tournament_id: String,
+        player_account: String,
+        portfolio: TournamentPortfolio,
+    ) -> TraditionalLeaguesResponse {
+        if let Err(error) = self.validate_submission(&tournament_id, &player_account, &portfolio).await {
+            log::warn!("Rejecting portfolio from {} for {}: {}", player_account, tournament_id, error);
+            return TraditionalLeaguesResponse::PortfolioRejected { error };
+        }
+
+        let portfolio_key = format!("{}-{}", tournament_id, player_account);
+        if let Err(e) = self.state.portfolios.insert(&portfolio_key, portfolio) {
+            log::error!("Failed to store portfolio {}: {:?}", portfolio_key, e);
+            return TraditionalLeaguesResponse::PortfolioRejected { error: PortfolioError::StorageFailed };
+        }
+        if let Err(e) = self.record_portfolio_change(&tournament_id, &player_account).await {
+            log::error!("{}", e);
+            return TraditionalLeaguesResponse::PortfolioRejected { error: PortfolioError::StorageFailed };
+        }
+
+        let submitted_at = self.runtime.system_time().micros();
+        self.publish(GameEvent::PortfolioSubmitted {
+            game_id: tournament_id,
+            player_account,
+            submitted_at,
+        });
+        TraditionalLeaguesResponse::PortfolioSubmitted { success: true }
+    }
+
+    /// Portfolios are accepted from registered participants while registration is open
+    async fn validate_submission(
+        &self,
+        tournament_id: &str,
+        player_account: &str,
+        portfolio: &TournamentPortfolio,
+    ) -> Result<(), PortfolioError> {
+        let tournament = match self.state.tournaments.get(tournament_id).await {
+            Ok(Some(tournament)) => tournament,
+            _ => return Err(PortfolioError::TournamentNotFound),
+        };
+        if tournament.status != TournamentStatus::Registration {
+            return Err(PortfolioError::RegistrationClosed);
+        }
+
+        if !self.state.is_participant(tournament_id, player_account).await.unwrap_or(false) {
+            return Err(PortfolioError::NotRegistered);
+        }
+
+        portfolio.validate(tournament.category, tournament.captain_picks)
+    }
+
+    /// Complete a tournament whose duration has elapsed, scoring it at `end_prices`
+    async fn complete_tournament(
+        &mut self,
+        tournament_id: String,
+        end_prices: Vec<traditional_leagues::PriceSnapshot>,
+    ) -> TraditionalLeaguesResponse {
+        let failure = || TraditionalLeaguesResponse::TournamentCompleted { success: false, winners: vec![] };
+        let Ok(Some(mut tournament)) = self.state.tournaments.get(&tournament_id).await else {
+            log::error!("Tournament {} not found", tournament_id);
+            return failure();
+        };
+
+        // Only complete if tournament is in progress and its configured duration has elapsed.
+        // Round-based tournaments complete when their final round is advanced.
+        let current_time = self.runtime.system_time().micros();
+        if tournament.status != TournamentStatus::InProgress
+            || !tournament.has_expired(current_time)
+            || tournament.tournament_type.is_round_based()
+        {
+            log::warn!("Tournament {} is not due for completion", tournament_id);
+            return failure();
+        }
+        // Settling without closing prices would score every portfolio on made-up returns
+        if end_prices.is_empty() {
+            log::warn!("Refusing to complete tournament {} without end prices", tournament_id);
+            return failure();
+        }
+
+        tournament.status = TournamentStatus::Completed;
+        tournament.completed_at = Some(current_time);
+        tournament.end_prices = Some(end_prices);
+
+        // Calculate tournament winners using scoring engine
+        let leaderboard = self.settle_leaderboard(&tournament, None).await;
+        let winners = self.leaderboard_winners(&tournament, &leaderboard).await;
+
+        if let Err(e) = self.save_tournament(tournament).await {
+            log::error!("Failed to update tournament {}: {:?}", tournament_id, e);
+            return failure();
+        }
+        if let Err(e) = self.state.results.insert(&tournament_id, winners.clone()) {
+            log::error!("Failed to store results for {}: {:?}", tournament_id, e);
+        }
+        self.publish(GameEvent::GameSettled {
+            game_id: tournament_id,
+            winners: winners.clone(),
+            settled_at: current_time,
+        });
+
+        TraditionalLeaguesResponse::TournamentCompleted { success: true, winners }
+    }
+
+    /// Reactive alternative to background timers - check for expired tournaments
+    /// Must be triggered explicitly by users/frontend or scheduled operations. Expired
+    /// tournaments are only listed: completing them takes the closing prices, which
+    /// `CompleteTournament` supplies.
+    async fn check_expired_tournaments_reactive(&mut self) -> TraditionalLeaguesResponse {
+        let mut cancelled_tournaments = Vec::new();
+        let current_time = self.runtime.system_time().micros();
+
+        // Check all tournaments for expiration using proper Linera MapView iteration
+        let mut awaiting_end_prices = Vec::new();
+        let mut tournaments_to_cancel = Vec::new();
+        
+        if let Err(_) = self.state.tournaments.for_each_index_value(|tournament_id, tournament| {
+            // Registration closed without enough entrants
+            if tournament.status == TournamentStatus::Registration
+                && tournament.registration_expired(current_time)
+                && !tournament.has_enough_entrants()
+            {
+                tournaments_to_cancel.push(tournament.into_owned());
+                return Ok(());
+            }
+
+            // Check if tournament is in progress and has expired
+            if tournament.status == TournamentStatus::InProgress
+                && !tournament.tournament_type.is_round_based()
+                && tournament.has_expired(current_time)
+            {
+                awaiting_end_prices.push(tournament_id.clone());
+            }
+            Ok(())
+        }).await {
+            return TraditionalLeaguesResponse::ExpiredTournamentsChecked {
+                awaiting_end_prices: vec![],
+                cancelled_tournaments: vec![],
+            };
+        }
+
+        // Cancel under-filled tournaments whose registration has closed
+        for tournament in tournaments_to_cancel {
+            let tournament_id = tournament.id.clone();
+            match self.cancel_tournament(tournament, "registration closed without enough entrants").await {
+                Ok(()) => cancelled_tournaments.push(tournament_id),
+                Err(e) => log::error!("Failed to cancel tournament {}: {}", tournament_id, e),
+            }
+        }
+
+        TraditionalLeaguesResponse::ExpiredTournamentsChecked { awaiting_end_prices, cancelled_tournaments }
+    }
+
+    /// Cancel a tournament, refund every entrant and tell CoinDrafts Core
+    async fn cancel_tournament(&mut self, mut tournament: Tournament, reason: &str) -> Result<(), String> {
+        let tournament_id = tournament.id.clone();
+        let refunded_players = self.state
+            .all_participants(&tournament_id, tournament.current_participants)
+            .await?;
+        for player_account in &refunded_players {
+            let refund_key = format!("{}-{}", tournament_id, player_account);
+            self.state
+                .refunds
+                .insert(&refund_key, tournament.entry_fee_usdc)
+                .map_err(|e| format!("Failed to record refund {}: {}", refund_key, e))?;
+        }
+
+        tournament.status = TournamentStatus::Cancelled;
+        let refund_usdc = tournament.entry_fee_usdc;
+        let game_id = tournament.game_id.clone();
+        self.save_tournament(tournament).await?;
+
+        log::info!("Tournament {} cancelled ({}), refunded {} entrants", tournament_id, reason, refunded_players.len());
+        if let Some(game_id) = game_id {
+            self.notify_core(TraditionalLeaguesMessage::TournamentCancelled {
+                game_id,
+                tournament_id,
+                reason: reason.to_string(),
+                refunded_players,
+                refund_usdc,
+            });
+        }
+        Ok(())
+    }
+
+    /// Score every portfolio and persist the final leaderboard as the tournament's
+    /// canonical result. Round-based tournaments pass their final `placements`,
+    /// which decide the ranks; otherwise players are ranked by return.
+    async fn settle_leaderboard(&mut self, tournament: &Tournament, placements: Option<Vec<String>>) -> Vec<LeaderboardEntry> {
+        let tournament_id = tournament.id.as_str();
+
+        // Get tournament participants to collect their portfolios
+        let participants = match self.state.all_participants(tournament_id, tournament.current_participants).await {
+            Ok(participants) if !participants.is_empty() => participants,
+            _ => return vec![], // No participants
+        };
+
+        // Collect portfolios from all participants
+        let mut portfolio_vec: Vec<(String, traditional_leagues::TournamentPortfolio)> = Vec::new();
+        
+        for participant in participants {
+            let portfolio_key = format!("{}-{}", tournament_id, participant);
+            if let Ok(Some(portfolio)) = self.state.portfolios.get(&portfolio_key).await {
+                portfolio_vec.push((participant, portfolio));
+            }
+        }
+
+        if portfolio_vec.is_empty() {
+            return vec![];
+        }
+
+        // Build price data from tournament snapshots instead of mock prices
+        let price_data = match tournament.price_data() {
+            Ok(Some(prices)) => prices,
+            Ok(None) => {
+                log::error!("Tournament {} has no start and end prices to score", tournament_id);
+                return vec![];
+            }
+            Err(e) => {
+                log::error!("Tournament {} price data could not be computed: {}", tournament_id, e);
+                return vec![];
+            }
+        };
+
+        let total_prize_pool = match tournament.entry_fee_usdc.checked_mul(tournament.current_participants as u64) {
+            Some(pool) => pool,
+            None => {
+                log::error!("Tournament {} prize pool overflowed", tournament_id);
+                return vec![];
+            }
+        };
+
+        // Calculate leaderboard using scoring engine
+        let leaderboard = match placements {
+            Some(placements) => {
+                ScoringEngine::placement_leaderboard(placements, &portfolio_vec, &price_data, total_prize_pool)
+            }
+            None => ScoringEngine::calculate_leaderboard(portfolio_vec, &price_data, total_prize_pool),
+        };
+        let mut leaderboard = match leaderboard {
+            Ok(leaderboard) => leaderboard,
+            Err(e) => {
+                log::error!("Tournament {} scoring failed: {}", tournament_id, e);
+                return vec![];
+            }
+        };
+
+        if tournament.is_team_tournament() {
+            self.settle_team_leaderboard(tournament, &mut leaderboard, total_prize_pool).await;
+        }
+
+        if let Err(e) = self.state.leaderboards.insert(tournament_id, leaderboard.clone()) {
+            log::error!("Failed to store leaderboard for {}: {:?}", tournament_id, e);
+        }
+        if let Err(e) = self.accrue_season_points(tournament, &leaderboard).await {
+            log::error!("Tournament {} season points: {}", tournament_id, e);
+        }
+        leaderboard
+    }
+
+    /// Rank the teams of a team tournament and pay each member their share of the team prize.
+    /// Individual winnings in `leaderboard` are replaced by those shares.
+    async fn settle_team_leaderboard(&mut self, tournament: &Tournament, leaderboard: &mut [LeaderboardEntry], total_prize_pool: u64) {
+        let teams = match self.state.tournament_teams(&tournament.id).await {
+            Ok(teams) => teams,
+            Err(e) => {
+                log::error!("{}", e);
+                return;
+            }
+        };
+
+        let team_entries = match team_leaderboard(&teams, leaderboard, tournament.team_scoring, total_prize_pool) {
+            Ok(team_entries) => team_entries,
+            Err(e) => {
+                log::error!("Tournament {} team scoring failed: {}", tournament.id, e);
+                return;
+            }
+        };
+
+        apply_team_payouts(leaderboard, &team_entries);
+
+        if let Err(e) = self.state.team_leaderboards.insert(&tournament.id, team_entries) {
+            log::error!("Failed to store team leaderboard for {}: {:?}", tournament.id, e);
+        }
+    }
+
+    /// Credit a settled leaderboard to every open season covering the completion time.
+    /// Seasons that already count the tournament are skipped.
+    async fn accrue_season_points(&mut self, tournament: &Tournament, leaderboard: &[LeaderboardEntry]) -> Result<(), String> {
+        let completed_at = tournament
+            .completed_at
+            .unwrap_or_else(|| self.runtime.system_time().micros());
+
+        let mut seasons = Vec::new();
+        if let Err(e) = self.state.seasons.for_each_index_value(|_season_id, season| {
+            if !season.finalized && season.contains(completed_at) && !season.tournament_ids.contains(&tournament.id) {
+                seasons.push(season.into_owned());
+            }
+            Ok(())
+        }).await {
+            return Err(format!("Failed to load seasons: {:?}", e));
+        }
+
+        for mut season in seasons {
+            for entry in leaderboard {
+                let points = season.points_for(entry.rank);
+                self.state.record_season_finish(&mut season, entry, points).await?;
+            }
+            season.tournament_ids.push(tournament.id.clone());
+            log::info!("Tournament {} counted towards season {}", tournament.id, season.id);
+            self.state
+                .seasons
+                .insert(&season.id.clone(), season)
+                .map_err(|e| format!("Failed to update season: {}", e))?;
+        }
+        Ok(())
+    }
+
+    /// Top three finishers of a settled leaderboard (or all players if less than 3).
+    /// Team tournaments are won by every member of the top team.
+    async fn leaderboard_winners(&self, tournament: &Tournament, leaderboard: &[LeaderboardEntry]) -> Vec<String> {
+        if tournament.is_team_tournament() {
+            return match self.state.team_leaderboards.get(&tournament.id).await {
+                Ok(Some(team_entries)) => team_entries
+                    .first()
+                    .map(|winner| winner.member_payouts.iter().map(|payout| payout.player_account.clone()).collect())
+                    .unwrap_or_default(),
+                _ => vec![],
+            };
+        }
+
+        leaderboard
+            .iter()
+            .take(3)
+            .map(|entry| entry.player_account.clone())
+            .collect()
+    }
+
+    /// Start tournament with price snapshot
+    async fn start_tournament(
+        &mut self,
+        tournament_id: String,
+        start_prices: Vec<traditional_leagues::PriceSnapshot>,
+    ) -> TraditionalLeaguesResponse {
+        // Get tournament
+        let mut tournament = match self.state.tournaments.get(&tournament_id).await {
+            Ok(Some(tournament)) if tournament.status == TournamentStatus::Registration => tournament,
+            _ => {
+                log::error!("Tournament {} not found or not open for registration", tournament_id);
+                return TraditionalLeaguesResponse::TournamentStarted {
+                    success: false,
+                    timestamp: 0,
+                };
+            }
+        };
+
+        // Players without a full team do not count as entrants
+        let dropouts = if tournament.is_team_tournament() {
+            match self.players_without_full_team(&tournament).await {
+                Ok(dropouts) => dropouts,
+                Err(e) => {
+                    log::error!("{}", e);
+                    return TraditionalLeaguesResponse::TournamentStarted { success: false, timestamp: 0 };
+                }
+            }
+        } else {
+            Vec::new()
+        };
+        let entrants = tournament.current_participants.saturating_sub(dropouts.len() as u32);
+        if entrants < tournament.min_participants {
+            log::warn!("Tournament {} has {} of {} required entrants",
+                      tournament_id, entrants, tournament.min_participants);
+            // Anyone may try to start a tournament, so only a closed registration cancels it
+            if tournament.registration_expired(self.runtime.system_time().micros()) {
+                if let Err(e) = self.cancel_tournament(tournament, "too few entrants to start").await {
+                    log::error!("Failed to cancel tournament {}: {}", tournament_id, e);
+                }
+            }
+            return TraditionalLeaguesResponse::TournamentStarted {
+                success: false,
+                timestamp: 0,
+            };
+        }
+
+        for player_account in dropouts {
+            match self.withdraw_participant(&mut tournament, &player_account).await {
+                Ok(refund_usdc) => {
+                    log::info!("Player {} has no full team in {} and was refunded {}", player_account, tournament_id, refund_usdc);
+                }
+                Err(e) => {
+                    log::error!("{}", e);
+                    return TraditionalLeaguesResponse::TournamentStarted { success: false, timestamp: 0 };
+                }
+            }
+        }
+
+        // Draw the bracket or schedule for round-based formats
+        if tournament.tournament_type.is_round_based() {
+            let seeds = self.seeded_players(&tournament_id).await;
+            if seeds.len() < 2 {
+                log::warn!("Tournament {} needs at least 2 players with portfolios to draw rounds", tournament_id);
+                return TraditionalLeaguesResponse::TournamentStarted {
+                    success: false,
+                    timestamp: 0,
+                };
+            }
+
+            let stored = match tournament.tournament_type {
+                TournamentType::RoundRobin => {
+                    let league = RoundRobin::new(tournament_id.clone(), seeds, start_prices.clone());
+                    self.state
+                        .leagues
+                        .insert(&tournament_id, league)
+                        .map_err(|e| format!("Failed to store schedule: {}", e))
+                }
+                TournamentType::Swiss => {
+                    let swiss = Swiss::new(tournament_id.clone(), seeds, tournament.round_count, start_prices.clone());
+                    self.state
+                        .swiss
+                        .insert(&tournament_id, swiss)
+                        .map_err(|e| format!("Failed to store Swiss pairings: {}", e))
+                }
+                _ => {
+                    let bracket = match tournament.tournament_type {
+                        TournamentType::DoubleElimination => {
+                            Bracket::double_elimination(tournament_id.clone(), seeds, start_prices.clone())
+                        }
+                        _ => Bracket::single_elimination(tournament_id.clone(), seeds, start_prices.clone()),
+                    };
+                    self.state
+                        .brackets
+                        .insert(&tournament_id, bracket)
+                        .map_err(|e| format!("Failed to store bracket: {}", e))
+                }
+            };
+            if let Err(e) = stored {
+                log::error!("Tournament {}: {}", tournament_id, e);
+                return TraditionalLeaguesResponse::TournamentStarted {
+                    success: false,
+                    timestamp: 0,
+                };
+            }
+            tournament.current_round = 1;
+        }
+
+        // Update tournament status
+        let timestamp = self.runtime.system_time().micros();
+        tournament.status = TournamentStatus::InProgress;
+        tournament.started_at = Some(timestamp);
+        tournament.start_prices = Some(start_prices);
 
         // Save tournament
-        self.state
-            .tournaments
-            .insert(&tournament_id, tournament)
-            .expect("Failed to update tournament");
+        if let Err(e) = self.save_tournament(tournament).await {
+            log::error!("Failed to update tournament {}: {}", tournament_id, e);
+            return TraditionalLeaguesResponse::TournamentStarted {
+                success: false,
+                timestamp: 0,
+            };
+        }
 
         log::info!("Tournament {} started at {}", tournament_id, timestamp);
+        self.publish(GameEvent::GameStarted { game_id: tournament_id, started_at: timestamp });
 
         TraditionalLeaguesResponse::TournamentStarted {
             success: true,
@@ -550,20 +1190,47 @@ impl TraditionalLeaguesContract {
             }
         };
 
+        if tournament.status != TournamentStatus::InProgress {
+            log::warn!("Tournament {} is not in progress", tournament_id);
+            return TraditionalLeaguesResponse::TournamentEnded {
+                success: false,
+                winners: vec![],
+            };
+        }
+        if self.state.leaderboards.contains_key(&tournament_id).await.unwrap_or(true) {
+            log::warn!("Tournament {} already has a final leaderboard", tournament_id);
+            return TraditionalLeaguesResponse::TournamentEnded {
+                success: false,
+                winners: vec![],
+            };
+        }
+
+        if tournament.tournament_type.is_round_based() {
+            log::warn!("Tournament {} is round-based; use AdvanceRound to score it", tournament_id);
+            return TraditionalLeaguesResponse::TournamentEnded {
+                success: false,
+                winners: vec![],
+            };
+        }
+
         // Update tournament status
         let timestamp = self.runtime.system_time().micros();
         tournament.status = TournamentStatus::Completed;
         tournament.completed_at = Some(timestamp);
         tournament.end_prices = Some(end_prices);
 
-        // Save tournament
-        self.state
-            .tournaments
-            .insert(&tournament_id, tournament)
-            .expect("Failed to update tournament");
-
         // Calculate winners
-        let winners = self.calculate_tournament_winners(&tournament_id).await;
+        let leaderboard = self.settle_leaderboard(&tournament, None).await;
+        let winners = self.leaderboard_winners(&tournament, &leaderboard).await;
+
+        // Save tournament
+        if let Err(e) = self.save_tournament(tournament).await {
+            log::error!("Failed to update tournament {}: {}", tournament_id, e);
+            return TraditionalLeaguesResponse::TournamentEnded {
+                success: false,
+                winners: vec![],
+            };
+        }
 
         // Store results
         if let Err(e) = self.state.results.insert(&tournament_id, winners.clone()) {
@@ -576,7 +1243,1165 @@ impl TraditionalLeaguesContract {
             timestamp,
             winners.len()
         );
+        self.publish(GameEvent::GameSettled {
+            game_id: tournament_id,
+            winners: winners.clone(),
+            settled_at: timestamp,
+        });
 
         TraditionalLeaguesResponse::TournamentEnded { success: true, winners }
     }
-}
\ No newline at end of file
+
+    /// Score the current round of a round-based tournament and draw the next one
+    async fn advance_round(
+        &mut self,
+        tournament_id: String,
+        end_prices: Vec<traditional_leagues::PriceSnapshot>,
+    ) -> TraditionalLeaguesResponse {
+        let mut tournament = match self.state.tournaments.get(&tournament_id).await {
+            Ok(Some(tournament)) if tournament.status == TournamentStatus::InProgress => tournament,
+            _ => {
+                log::warn!("Tournament {} is not in progress", tournament_id);
+                return TraditionalLeaguesResponse::RoundAdvanced { new_round: 0 };
+            }
+        };
+
+        let return_scale = tournament.return_scale as i64;
+        let outcome = match tournament.tournament_type {
+            TournamentType::RoundRobin => {
+                self.advance_league_round(&tournament_id, end_prices.clone(), return_scale).await
+            }
+            TournamentType::Swiss => {
+                self.advance_swiss_round(&tournament_id, end_prices.clone(), return_scale).await
+            }
+            _ => self.advance_bracket_round(&tournament_id, end_prices.clone(), return_scale).await,
+        };
+
+        let outcome = match outcome {
+            Ok(outcome) => outcome,
+            Err(e) => {
+                log::error!("{}", e);
+                return TraditionalLeaguesResponse::RoundAdvanced { new_round: 0 };
+            }
+        };
+        let response = match outcome {
+            RoundOutcome::Next(new_round) => {
+                tournament.current_round = new_round;
+                log::info!("Tournament {} advanced to round {}", tournament_id, new_round);
+                TraditionalLeaguesResponse::RoundAdvanced { new_round }
+            }
+            RoundOutcome::Finished(placements) => {
+                let winners: Vec<String> = placements.iter().take(3).cloned().collect();
+                let completed_at = self.runtime.system_time().micros();
+                tournament.status = TournamentStatus::Completed;
+                tournament.completed_at = Some(completed_at);
+                tournament.end_prices = Some(end_prices);
+                self.settle_leaderboard(&tournament, Some(placements)).await;
+
+                if let Err(e) = self.state.results.insert(&tournament_id, winners.clone()) {
+                    log::error!("Failed to store results for {}: {:?}", tournament_id, e);
+                }
+                log::info!("Tournament {} completed after round {}", tournament_id, tournament.current_round);
+                self.publish(GameEvent::GameSettled {
+                    game_id: tournament_id.clone(),
+                    winners: winners.clone(),
+                    settled_at: completed_at,
+                });
+                TraditionalLeaguesResponse::TournamentCompleted { success: true, winners }
+            }
+        };
+
+        if let Err(e) = self.save_tournament(tournament).await {
+            log::error!("Failed to update tournament {}: {}", tournament_id, e);
+            return TraditionalLeaguesResponse::RoundAdvanced { new_round: 0 };
+        }
+
+        response
+    }
+
+    /// Score the current bracket round and promote matchup winners
+    async fn advance_bracket_round(
+        &mut self,
+        tournament_id: &str,
+        end_prices: Vec<traditional_leagues::PriceSnapshot>,
+        return_scale: i64,
+    ) -> Result<RoundOutcome, String> {
+        let mut bracket = match self.state.brackets.get(tournament_id).await {
+            Ok(Some(bracket)) => bracket,
+            _ => return Err(format!("Tournament {} has no bracket", tournament_id)),
+        };
+
+        let portfolios = self.load_portfolios(tournament_id, bracket.active_players()).await;
+        let outcome = match bracket.advance_round(end_prices, &portfolios, return_scale) {
+            Ok(Some(new_round)) => RoundOutcome::Next(new_round),
+            Ok(None) => {
+                log::info!("Tournament {} bracket champion {:?}", tournament_id, bracket.champion);
+                RoundOutcome::Finished(bracket.placements())
+            }
+            Err(e) => return Err(format!("Tournament {} bracket scoring failed: {}", tournament_id, e)),
+        };
+
+        self.state
+            .brackets
+            .insert(tournament_id, bracket)
+            .map_err(|e| format!("Failed to update bracket of {}: {}", tournament_id, e))?;
+        Ok(outcome)
+    }
+
+    /// Score the current round-robin round and award league points
+    async fn advance_league_round(
+        &mut self,
+        tournament_id: &str,
+        end_prices: Vec<traditional_leagues::PriceSnapshot>,
+        return_scale: i64,
+    ) -> Result<RoundOutcome, String> {
+        let mut league = match self.state.leagues.get(tournament_id).await {
+            Ok(Some(league)) => league,
+            _ => return Err(format!("Tournament {} has no round-robin schedule", tournament_id)),
+        };
+
+        let portfolios = self.load_portfolios(tournament_id, league.active_players()).await;
+        let scored = league.advance_round(end_prices, &portfolios, return_scale)
+            .and_then(|next_round| match next_round {
+                Some(new_round) => Ok(RoundOutcome::Next(new_round)),
+                None => league.placements().map(RoundOutcome::Finished),
+            });
+        let outcome = scored.map_err(|e| format!("Tournament {} league scoring failed: {}", tournament_id, e))?;
+
+        self.state
+            .leagues
+            .insert(tournament_id, league)
+            .map_err(|e| format!("Failed to update schedule of {}: {}", tournament_id, e))?;
+        Ok(outcome)
+    }
+
+    /// Score the current Swiss round and pair the next one
+    async fn advance_swiss_round(
+        &mut self,
+        tournament_id: &str,
+        end_prices: Vec<traditional_leagues::PriceSnapshot>,
+        return_scale: i64,
+    ) -> Result<RoundOutcome, String> {
+        let mut swiss = match self.state.swiss.get(tournament_id).await {
+            Ok(Some(swiss)) => swiss,
+            _ => return Err(format!("Tournament {} has no Swiss pairings", tournament_id)),
+        };
+
+        let portfolios = self.load_portfolios(tournament_id, swiss.active_players()).await;
+        let scored = swiss.advance_round(end_prices, &portfolios, return_scale)
+            .and_then(|next_round| match next_round {
+                Some(new_round) => Ok(RoundOutcome::Next(new_round)),
+                None => swiss.placements().map(RoundOutcome::Finished),
+            });
+        let outcome = scored.map_err(|e| format!("Tournament {} Swiss scoring failed: {}", tournament_id, e))?;
+
+        self.state
+            .swiss
+            .insert(tournament_id, swiss)
+            .map_err(|e| format!("Failed to update Swiss pairings of {}: {}", tournament_id, e))?;
+        Ok(outcome)
+    }
+
+    /// Create a season covering tournaments completed between `starts_at` and `ends_at`
+    async fn create_season(
+        &mut self,
+        name: String,
+        starts_at: u64,
+        ends_at: u64,
+        points_table: Vec<u32>,
+        bonus_pool_usdc: u64,
+        bonus_split_percent: Vec<u32>,
+    ) -> TraditionalLeaguesResponse {
+        let split_total: u32 = bonus_split_percent.iter().sum();
+        if ends_at <= starts_at || split_total > 100 {
+            log::warn!("Rejecting season {}: it must end after it starts and split at most 100% of the bonus", name);
+            return TraditionalLeaguesResponse::SeasonCreated {
+                season_id: "error".to_string(),
+            };
+        }
+
+        let season_id = self.state.generate_season_id().await;
+        let season = Season {
+            id: season_id.clone(),
+            name,
+            starts_at,
+            ends_at,
+            points_table: if points_table.is_empty() { DEFAULT_POINTS_TABLE.to_vec() } else { points_table },
+            bonus_pool_usdc,
+            bonus_split_percent: if bonus_split_percent.is_empty() {
+                DEFAULT_BONUS_SPLIT_PERCENT.to_vec()
+            } else {
+                bonus_split_percent
+            },
+            tournament_ids: vec![],
+            player_count: 0,
+            finalized: false,
+        };
+
+        if let Err(e) = self.state.seasons.insert(&season_id, season) {
+            log::error!("Failed to store season {}: {:?}", season_id, e);
+            return TraditionalLeaguesResponse::SeasonCreated {
+                season_id: "error".to_string(),
+            };
+        }
+
+        log::info!("Season {} created", season_id);
+        TraditionalLeaguesResponse::SeasonCreated { season_id }
+    }
+
+    /// Finalize a season after its end date and pay the bonus pool from the final standings
+    async fn end_season(&mut self, season_id: String) -> TraditionalLeaguesResponse {
+        let mut season = match self.state.seasons.get(&season_id).await {
+            Ok(Some(season)) if !season.finalized => season,
+            _ => {
+                log::warn!("Season {} not found or already ended", season_id);
+                return TraditionalLeaguesResponse::SeasonEnded { success: false, bonuses: vec![] };
+            }
+        };
+
+        if self.runtime.system_time().micros() < season.ends_at {
+            log::warn!("Season {} has not reached its end date", season_id);
+            return TraditionalLeaguesResponse::SeasonEnded { success: false, bonuses: vec![] };
+        }
+
+        let records = match self.state.season_records(&season).await {
+            Ok(records) => records,
+            Err(e) => {
+                log::error!("{}", e);
+                return TraditionalLeaguesResponse::SeasonEnded { success: false, bonuses: vec![] };
+            }
+        };
+        let standings = season_standings(records);
+        let bonuses = match season.bonus_payouts(&standings) {
+            Ok(bonuses) => bonuses,
+            Err(e) => {
+                log::error!("Season {} bonus calculation failed: {}", season_id, e);
+                return TraditionalLeaguesResponse::SeasonEnded { success: false, bonuses: vec![] };
+            }
+        };
+
+        season.finalized = true;
+        let bonus_pool_usdc = season.bonus_pool_usdc;
+        if let Err(e) = self.state.seasons.insert(&season_id, season) {
+            log::error!("Failed to update season {}: {:?}", season_id, e);
+            return TraditionalLeaguesResponse::SeasonEnded { success: false, bonuses: vec![] };
+        }
+        if let Err(e) = self.state.season_bonuses.insert(&season_id, bonuses.clone()) {
+            log::error!("Failed to store bonuses of season {}: {:?}", season_id, e);
+            return TraditionalLeaguesResponse::SeasonEnded { success: false, bonuses: vec![] };
+        }
+
+        self.notify_core(TraditionalLeaguesMessage::SeasonEnded {
+            season_id: season_id.clone(),
+            standings: standings.into_iter().map(|standing| standing.player_account).collect(),
+            bonus_pool_usdc,
+        });
+
+        log::info!("Season {} ended with {} bonus payouts", season_id, bonuses.len());
+        TraditionalLeaguesResponse::SeasonEnded { success: true, bonuses }
+    }
+
+    /// Create the tournament backing a core game and answer core with its ID
+    async fn create_game_tournament(
+        &mut self,
+        game_id: String,
+        tournament_name: String,
+        entry_fee_usdc: u64,
+        max_participants: u32,
+        duration_minutes: u64,
+    ) -> TraditionalLeaguesResponse {
+        log::info!("Creating tournament: {} for game {}", tournament_name, game_id);
+
+        let response = self.create_tournament(
+            tournament_name,
+            entry_fee_usdc,
+            max_participants,
+            TournamentType::FreeForAll, // Default for now
+            TournamentOptions {
+                category: CryptoCategory::AllCategories, // Default category
+                duration_minutes,
+                ..TournamentOptions::default()
+            },
+        ).await;
+
+        let tournament_id = match response {
+            TraditionalLeaguesResponse::TournamentCreated { tournament_id } if tournament_id != "error" => tournament_id,
+            response => {
+                log::error!("Failed to create tournament for game {}", game_id);
+                return response;
+            }
+        };
+        let Ok(Some(mut tournament)) = self.state.tournaments.get(&tournament_id).await else {
+            log::error!("Created tournament {} not found", tournament_id);
+            return TraditionalLeaguesResponse::TournamentCreated { tournament_id: "error".to_string() };
+        };
+
+        // Remember the linked game so later notifications reach it
+        tournament.game_id = Some(game_id.clone());
+        let tournament_info = tournament.info();
+        self.save_tournament(tournament).await
+            .expect("Failed to link tournament to game");
+
+        // Answer core with the generated ID so it can link the game
+        self.notify_core(TraditionalLeaguesMessage::TournamentCreated {
+            game_id,
+            tournament_id: tournament_id.clone(),
+            tournament_info,
+        });
+        TraditionalLeaguesResponse::TournamentCreated { tournament_id }
+    }
+
+    /// Convert a portfolio synced from a core game and store it under its player.
+    /// Failures are reported back to core.
+    async fn sync_portfolio(&mut self, game_id: String, tournament_id: String, portfolio: Portfolio) -> TraditionalLeaguesResponse {
+        log::info!("Syncing portfolio of {} for tournament {} in game {}", portfolio.player_account, tournament_id, game_id);
+
+        let player_account = portfolio.player_account.clone();
+        let conversion = match self.state.tournaments.get(&tournament_id).await {
+            Ok(Some(tournament)) if tournament.game_id.as_ref() == Some(&game_id) && portfolio.game_id == game_id => {
+                TournamentPortfolio::from_core(&portfolio)
+            }
+            Ok(Some(_)) => Err(PortfolioError::WrongGame(portfolio.game_id.clone())),
+            _ => Err(PortfolioError::TournamentNotFound),
+        };
+
+        let response = match conversion {
+            Ok(tournament_portfolio) => {
+                self.store_portfolio(tournament_id.clone(), player_account.clone(), tournament_portfolio).await
+            }
+            Err(error) => {
+                log::warn!("Rejecting synced portfolio from {} for {}: {}", player_account, tournament_id, error);
+                TraditionalLeaguesResponse::PortfolioRejected { error }
+            }
+        };
+
+        if let TraditionalLeaguesResponse::PortfolioRejected { error } = &response {
+            self.notify_core(TraditionalLeaguesMessage::PortfolioSyncFailed {
+                game_id,
+                tournament_id,
+                player_account,
+                reason: error.to_string(),
+            });
+        }
+        response
+    }
+
+    /// Apply a request FROM CoinDrafts Core at most once and acknowledge it. A request whose
+    /// receipt was lost is acknowledged again without being reapplied.
+    async fn receive_core_request(&mut self, envelope: MessageEnvelope<CoinDraftsMessage>) {
+        let message_id = envelope.message_id.clone();
+        let request = match envelope.into_payload() {
+            Ok(request) => request,
+            Err(version) => {
+                log::warn!("Rejecting request {} with unsupported protocol version {}", message_id, version);
+                self.notify_core(TraditionalLeaguesMessage::Acknowledge {
+                    message_id,
+                    error: Some(format!("Unsupported protocol version {}", version)),
+                });
+                return;
+            }
+        };
+        if matches!(request, CoinDraftsMessage::Acknowledge { .. }) {
+            // Receipts are neither logged nor acknowledged themselves
+            let _ = self.handle_core_request(request).await;
+            return;
+        }
+
+        let now = self.runtime.system_time().micros();
+        let mut record = match self.state.inbox.get(&message_id).await {
+            Ok(Some(record)) if record.status == DeliveryStatus::Delivered => {
+                log::info!("Request {} ({}) was already applied", message_id, record.kind);
+                self.notify_core(TraditionalLeaguesMessage::Acknowledge { message_id, error: None });
+                return;
+            }
+            Ok(Some(mut record)) => {
+                record.retry(now);
+                record
+            }
+            _ => MessageRecord::pending(message_id.clone(), request.kind(), now),
+        };
+
+        let outcome = self.handle_core_request(request).await;
+        record.settle(outcome.clone(), now);
+        if let Err(e) = self.state.inbox.insert(&message_id, record) {
+            log::error!("Failed to record request {}: {}", message_id, e);
+        }
+        self.notify_core(TraditionalLeaguesMessage::Acknowledge { message_id, error: outcome.err() });
+    }
+
+    /// Handle a request FROM CoinDrafts Core, whether core called this instance directly or
+    /// its call was forwarded from another chain
+    async fn handle_core_request(&mut self, message: CoinDraftsMessage) -> Result<(), String> {
+        match message {
+            CoinDraftsMessage::CreateTournament { game_id, tournament_name, entry_fee_usdc, max_participants, duration_minutes } => {
+                let response = self.create_game_tournament(
+                    game_id,
+                    tournament_name,
+                    entry_fee_usdc,
+                    max_participants,
+                    duration_minutes,
+                ).await;
+                log::info!("Tournament creation result: {:?}", response);
+                response.failure().map_or(Ok(()), Err)
+            }
+
+            CoinDraftsMessage::RegisterPlayerForTournament { game_id, tournament_id, player_profile } => {
+                // Handle player registration request from CoinDrafts Core
+                log::info!("Registering player {} for tournament {} in game {}", 
+                          player_profile.account, tournament_id, game_id);
+                
+                // Register player using existing method
+                let response = self.register_for_tournament(
+                    tournament_id.clone(),
+                    player_profile.account,
+                ).await;
+                
+                log::info!("Player registration result: {:?}", response);
+                response.failure().map_or(Ok(()), Err)
+            }
+            
+            CoinDraftsMessage::SyncPortfolio { game_id, tournament_id, portfolio } => {
+                let response = self.sync_portfolio(game_id, tournament_id, portfolio).await;
+                log::info!("Portfolio submission result: {:?}", response);
+                response.failure().map_or(Ok(()), Err)
+            }
+
+            CoinDraftsMessage::GetTournamentStatus { tournament_id } => {
+                // Handle tournament status request
+                log::info!("Getting status for tournament {}", tournament_id);
+                
+                // Could send back status update if needed
+                // For now, just log the request
+                Ok(())
+            }
+            
+            CoinDraftsMessage::VerifyPlayer { game_id, player_account, tournament_id, verified, reason } => {
+                log::info!("Verification of {} for tournament {} in game {}: {}", player_account, tournament_id, game_id, verified);
+                let response = self.complete_registration(tournament_id, player_account, verified, reason).await;
+                log::info!("Player registration result: {:?}", response);
+                // A rejected player is a delivered verdict, not a failed delivery
+                if verified {
+                    response.failure().map_or(Ok(()), Err)
+                } else {
+                    Ok(())
+                }
+            }
+
+            CoinDraftsMessage::Acknowledge { message_id, error } => {
+                self.settle_notification(message_id, error).await;
+                Ok(())
+            }
+        }
+    }
+
+    /// Publish a lifecycle event for other applications and indexers
+    fn publish(&mut self, event: GameEvent) {
+        let stream_name = StreamName::from(event.stream_name());
+        self.runtime.emit(stream_name, &CoinDraftsEvent::Lifecycle(event));
+    }
+
+    /// Store a tournament, logging its status under the tournament's next revision when it
+    /// changed. Any change to a tournament can move its leaderboard.
+    async fn save_tournament(&mut self, tournament: Tournament) -> Result<(), String> {
+        let previous = self.state.tournaments.get(&tournament.id).await
+            .map_err(|e| format!("Failed to get tournament: {}", e))?;
+        if previous.map(|previous| previous.status) != Some(tournament.status) {
+            let revision = self.state.tournament_status_revisions.get(&tournament.id).await
+                .map_err(|e| format!("Failed to get tournament status revision: {}", e))?
+                .unwrap_or(0) + 1;
+            self.state.tournament_status_changes.insert(&(tournament.id.clone(), revision), tournament.status)
+                .map_err(|e| format!("Failed to record tournament status: {}", e))?;
+            self.state.tournament_status_revisions.insert(&tournament.id, revision)
+                .map_err(|e| format!("Failed to record tournament status: {}", e))?;
+        }
+        self.advance_leaderboard_revision(&tournament.id).await?;
+        self.state.tournaments.insert(&tournament.id.clone(), tournament)
+            .map_err(|e| format!("Failed to update tournament: {}", e))
+    }
+
+    /// Log a change to a player's portfolio under the tournament's next revision
+    async fn record_portfolio_change(&mut self, tournament_id: &str, player_account: &str) -> Result<(), String> {
+        let revision = self.state.portfolio_revisions.get(tournament_id).await
+            .map_err(|e| format!("Failed to get portfolio revision: {}", e))?
+            .unwrap_or(0) + 1;
+        self.state.portfolio_changes.insert(&(tournament_id.to_string(), revision), player_account.to_string())
+            .map_err(|e| format!("Failed to record portfolio change: {}", e))?;
+        self.state.portfolio_revisions.insert(tournament_id, revision)
+            .map_err(|e| format!("Failed to record portfolio change: {}", e))?;
+        self.advance_leaderboard_revision(tournament_id).await
+    }
+
+    /// Advance the revision a tournament's leaderboard subscribers compare against
+    async fn advance_leaderboard_revision(&mut self, tournament_id: &str) -> Result<(), String> {
+        let revision = self.state.leaderboard_revisions.get(tournament_id).await
+            .map_err(|e| format!("Failed to get leaderboard revision: {}", e))?
+            .unwrap_or(0) + 1;
+        self.state.leaderboard_revisions.insert(tournament_id, revision)
+            .map_err(|e| format!("Failed to update leaderboard revision: {}", e))
+    }
+
+    /// Publish a notification for CoinDrafts Core on the notification event stream, logging it
+    /// in the outbox until core acknowledges it. Core follows the stream and reads each
+    /// notification back through `ReadNotification`.
+    fn notify_core(&mut self, notification: TraditionalLeaguesMessage) {
+        let chain_id = self.runtime.chain_id();
+        let message_id = self.state.generate_message_id(chain_id);
+        let envelope = MessageEnvelope::new(message_id.clone(), notification);
+
+        if !matches!(envelope.payload, TraditionalLeaguesMessage::Acknowledge { .. }) {
+            let now = self.runtime.system_time().micros();
+            let record = MessageRecord::pending(message_id.clone(), envelope.payload.kind(), now);
+            if let Err(e) = self.state.outbox.insert(&message_id, record) {
+                log::error!("Failed to log notification {}: {}", message_id, e);
+            }
+            if let Err(e) = self.state.outbox_messages.insert(&message_id, envelope.clone()) {
+                log::error!("Failed to keep notification {} for retries: {}", message_id, e);
+            }
+        }
+
+        self.runtime.emit(StreamName::from(CORE_NOTIFICATION_STREAM), &CoinDraftsEvent::Notification(envelope));
+    }
+
+    /// Apply core's receipt for a notification. Returns false for unknown notifications.
+    async fn settle_notification(&mut self, message_id: String, error: Option<String>) -> bool {
+        let Ok(Some(mut record)) = self.state.outbox.get(&message_id).await else {
+            log::warn!("Receipt for unknown notification {}", message_id);
+            return false;
+        };
+        if let Some(error) = &error {
+            log::warn!("Core failed to apply {} ({}): {}", message_id, record.kind, error);
+        }
+
+        let now = self.runtime.system_time().micros();
+        record.settle(error.map_or(Ok(()), Err), now);
+        if record.status == DeliveryStatus::Delivered {
+            // Only failed notifications are ever resent
+            let _ = self.state.outbox_messages.remove(&message_id);
+        }
+        if let Err(e) = self.state.outbox.insert(&message_id, record) {
+            log::error!("Failed to update notification {}: {}", message_id, e);
+            return false;
+        }
+        true
+    }
+
+    /// Resend a failed notification under its original message ID, so core applies it at most once
+    async fn retry_notification(&mut self, message_id: String) -> TraditionalLeaguesResponse {
+        if !self.signed_by_admin() {
+            log::warn!("Only the application creator may retry notifications");
+            return TraditionalLeaguesResponse::MessageRetried { success: false };
+        }
+
+        let mut record = match self.state.outbox.get(&message_id).await {
+            Ok(Some(record)) if record.status == DeliveryStatus::Failed => record,
+            _ => {
+                log::warn!("Notification {} has not failed", message_id);
+                return TraditionalLeaguesResponse::MessageRetried { success: false };
+            }
+        };
+        let Ok(Some(envelope)) = self.state.outbox_messages.get(&message_id).await else {
+            log::error!("Notification {} is no longer stored", message_id);
+            return TraditionalLeaguesResponse::MessageRetried { success: false };
+        };
+
+        record.retry(self.runtime.system_time().micros());
+        if let Err(e) = self.state.outbox.insert(&message_id, record) {
+            log::error!("Failed to update notification {}: {}", message_id, e);
+            return TraditionalLeaguesResponse::MessageRetried { success: false };
+        }
+        self.runtime.emit(StreamName::from(CORE_NOTIFICATION_STREAM), &CoinDraftsEvent::Notification(envelope));
+        TraditionalLeaguesResponse::MessageRetried { success: true }
+    }
+
+    /// Participants with a submitted portfolio, in registration order
+    async fn seeded_players(&self, tournament_id: &str) -> Vec<String> {
+        let participant_count = self.state.tournaments.get(tournament_id).await
+            .ok()
+            .flatten()
+            .map(|tournament| tournament.current_participants)
+            .unwrap_or_default();
+        let participants = self.state.all_participants(tournament_id, participant_count).await
+            .unwrap_or_default();
+
+        let mut seeds = Vec::new();
+        for participant in participants {
+            let portfolio_key = format!("{}-{}", tournament_id, participant);
+            if let Ok(Some(_)) = self.state.portfolios.get(&portfolio_key).await {
+                seeds.push(participant);
+            }
+        }
+        seeds
+    }
+
+    /// Load the portfolios of the given players
+    async fn load_portfolios(
+        &self,
+        tournament_id: &str,
+        players: Vec<String>,
+    ) -> BTreeMap<String, TournamentPortfolio> {
+        let mut portfolios = BTreeMap::new();
+        for player in players {
+            let portfolio_key = format!("{}-{}", tournament_id, player);
+            if let Ok(Some(portfolio)) = self.state.portfolios.get(&portfolio_key).await {
+                portfolios.insert(player, portfolio);
+            }
+        }
+        portfolios
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use linera_sdk::{
+        bcs,
+        linera_base_types::{AccountOwner, ApplicationId, ChainId, CryptoHash, Timestamp},
+        util::BlockingWait,
+    };
+    use traditional_leagues::{PriceSnapshot, SeasonRecord};
+
+    use super::*;
+
+    const PICKS: [&str; 5] = ["bitcoin", "ethereum", "solana", "cardano", "polkadot"];
+
+    fn core_id() -> ApplicationId {
+        ApplicationId::new(CryptoHash::test_hash("core"))
+    }
+
+    /// Leagues administered by `admin`, taking core-only operations from `core_id()`
+    fn create_contract() -> TraditionalLeaguesContract {
+        let admin = AccountOwner::Address32(CryptoHash::test_hash("admin"));
+        let runtime = ContractRuntime::new()
+            .with_application_parameters(TraditionalLeaguesParameters { core_application_id: Some(core_id()) })
+            .with_chain_id(ChainId(CryptoHash::test_hash("leagues")))
+            .with_authenticated_caller_id(None)
+            .with_authenticated_signer(admin)
+            .with_system_time(Timestamp::from(1_000_000));
+        let state = TraditionalLeaguesState::load(runtime.root_view_storage_context())
+            .blocking_wait()
+            .expect("Failed to load state");
+        let mut contract = TraditionalLeaguesContract { state, runtime };
+        contract.instantiate(()).blocking_wait();
+        contract
+    }
+
+    /// Prices of the picks, rising by `step` per position
+    fn prices(base: u64, step: u64) -> Vec<PriceSnapshot> {
+        PICKS
+            .iter()
+            .enumerate()
+            .map(|(index, crypto_id)| PriceSnapshot {
+                crypto_id: crypto_id.to_string(),
+                price_usd: base + step * index as u64,
+                timestamp: 1_000_000,
+            })
+            .collect()
+    }
+
+    fn season_record(contract: &TraditionalLeaguesContract, season_id: &str, player: &str) -> SeasonRecord {
+        contract.state.season_records
+            .get(&(season_id.to_string(), player.to_string()))
+            .blocking_wait()
+            .unwrap()
+            .expect("Missing season record")
+    }
+
+    #[test]
+    fn test_ending_a_tournament_twice_counts_it_once() {
+        let mut contract = create_contract();
+        let season_id = match contract.execute_operation(TraditionalLeaguesOperation::CreateSeason {
+            name: "Season 1".to_string(),
+            starts_at: 0,
+            ends_at: 10_000_000,
+            points_table: vec![],
+            bonus_pool_usdc: 0,
+            bonus_split_percent: vec![],
+        }).blocking_wait() {
+            TraditionalLeaguesResponse::SeasonCreated { season_id } => season_id,
+            response => panic!("Unexpected response: {:?}", response),
+        };
+        let tournament_id = match contract.execute_operation(TraditionalLeaguesOperation::CreateTournament {
+            name: "Weekly".to_string(),
+            entry_fee_usdc: 10,
+            max_participants: 4,
+            tournament_type: TournamentType::FreeForAll,
+            options: TournamentOptions {
+                category: CryptoCategory::L1Chains,
+                duration_minutes: 60,
+                ..TournamentOptions::default()
+            },
+        }).blocking_wait() {
+            TraditionalLeaguesResponse::TournamentCreated { tournament_id } => tournament_id,
+            response => panic!("Unexpected response: {:?}", response),
+        };
+
+        let alice = AccountOwner::Address32(CryptoHash::test_hash("alice"));
+        let bob = AccountOwner::Address32(CryptoHash::test_hash("bob"));
+        for (player, picks) in [(alice, PICKS), (bob, [PICKS[4], PICKS[3], PICKS[2], PICKS[1], PICKS[0]])] {
+            contract.runtime.set_authenticated_signer(player);
+            let response = contract.execute_operation(TraditionalLeaguesOperation::RegisterForTournament {
+                tournament_id: tournament_id.clone(),
+                player_account: player.to_string(),
+            }).blocking_wait();
+            assert!(matches!(response, TraditionalLeaguesResponse::PlayerRegistered { success: true }));
+            let response = contract.execute_operation(TraditionalLeaguesOperation::SubmitPortfolio {
+                tournament_id: tournament_id.clone(),
+                portfolio: TournamentPortfolio {
+                    crypto_picks: picks.iter().map(|pick| pick.to_string()).collect(),
+                    strategy_notes: None,
+                    captain: None,
+                    vice_captain: None,
+                    short_picks: vec![],
+                },
+            }).blocking_wait();
+            assert!(matches!(response, TraditionalLeaguesResponse::PortfolioSubmitted { success: true }));
+        }
+        contract.runtime.set_authenticated_signer(AccountOwner::Address32(CryptoHash::test_hash("admin")));
+        let response = contract.execute_operation(TraditionalLeaguesOperation::StartTournament {
+            tournament_id: tournament_id.clone(),
+            start_prices: prices(1_000_000, 0),
+        }).blocking_wait();
+        assert!(matches!(response, TraditionalLeaguesResponse::TournamentStarted { success: true, .. }));
+
+        let end = || TraditionalLeaguesOperation::EndTournament {
+            tournament_id: tournament_id.clone(),
+            end_prices: prices(2_000_000, 100_000),
+        };
+        let response = contract.execute_operation(end()).blocking_wait();
+        assert!(matches!(response, TraditionalLeaguesResponse::TournamentEnded { success: true, .. }));
+        let first = season_record(&contract, &season_id, &alice.to_string());
+        assert_eq!(first.tournaments_played, 1);
+
+        // Ending again is refused, and settling the same leaderboard again is not counted
+        let response = contract.execute_operation(end()).blocking_wait();
+        assert!(matches!(response, TraditionalLeaguesResponse::TournamentEnded { success: false, .. }));
+        let statuses = contract.state.tournament_status_revisions.get(&tournament_id).blocking_wait().unwrap();
+        assert_eq!(statuses, Some(3));
+        let completed = contract.state.tournament_status_changes.get(&(tournament_id.clone(), 3)).blocking_wait().unwrap();
+        assert_eq!(completed, Some(TournamentStatus::Completed));
+        let portfolios = contract.state.portfolio_revisions.get(&tournament_id).blocking_wait().unwrap();
+        assert_eq!(portfolios, Some(2));
+        let tournament = contract.state.tournaments.get(&tournament_id).blocking_wait().unwrap().unwrap();
+        let leaderboard = contract.state.leaderboards.get(&tournament_id).blocking_wait().unwrap().unwrap();
+        contract.accrue_season_points(&tournament, &leaderboard).blocking_wait().unwrap();
+
+        assert_eq!(season_record(&contract, &season_id, &alice.to_string()), first);
+        let season = contract.state.seasons.get(&season_id).blocking_wait().unwrap().unwrap();
+        assert_eq!(season.tournament_ids, vec![tournament_id]);
+        assert_eq!(season.player_count, 2);
+    }
+
+    #[test]
+    fn test_only_core_verifies_players() {
+        let mut contract = create_contract();
+        let tournament_id = match contract.execute_operation(TraditionalLeaguesOperation::CreateTournamentForGame {
+            game_id: "game_1".to_string(),
+            name: "Weekly".to_string(),
+            entry_fee_usdc: 10,
+            max_participants: 4,
+            duration_minutes: 60,
+        }).blocking_wait() {
+            TraditionalLeaguesResponse::TournamentCreated { tournament_id } => tournament_id,
+            response => panic!("Unexpected response: {:?}", response),
+        };
+        contract.runtime.set_authenticated_caller_id(core_id());
+        let response = contract.execute_operation(TraditionalLeaguesOperation::RegisterForTournament {
+            tournament_id: tournament_id.clone(),
+            player_account: "alice".to_string(),
+        }).blocking_wait();
+        assert!(matches!(response, TraditionalLeaguesResponse::RegistrationPending { success: true }));
+
+        let verify = || TraditionalLeaguesOperation::VerifyPlayer {
+            tournament_id: tournament_id.clone(),
+            player_account: "alice".to_string(),
+            verified: true,
+            reason: None,
+        };
+        let forwarded = || TraditionalLeaguesOperation::ReceiveCoreRequest {
+            envelope: MessageEnvelope::new(
+                "core-1".to_string(),
+                CoinDraftsMessage::VerifyPlayer {
+                    game_id: "game_1".to_string(),
+                    player_account: "alice".to_string(),
+                    tournament_id: tournament_id.clone(),
+                    verified: true,
+                    reason: None,
+                },
+            ),
+        };
+        for caller in [None, Some(ApplicationId::new(CryptoHash::test_hash("impostor")))] {
+            contract.runtime.set_authenticated_caller_id(caller);
+            let response = contract.execute_operation(verify()).blocking_wait();
+            assert!(matches!(response, TraditionalLeaguesResponse::RegistrationRejected { .. }));
+            let response = contract.execute_operation(forwarded()).blocking_wait();
+            assert!(matches!(response, TraditionalLeaguesResponse::CoreRequestReceived { success: false }));
+        }
+        assert!(!contract.state.is_participant(&tournament_id, "alice").blocking_wait().unwrap());
+
+        contract.runtime.set_authenticated_caller_id(core_id());
+        let response = contract.execute_operation(verify()).blocking_wait();
+        assert!(matches!(response, TraditionalLeaguesResponse::PlayerRegistered { success: true }));
+        assert!(contract.state.is_participant(&tournament_id, "alice").blocking_wait().unwrap());
+    }
+
+    #[test]
+    fn test_core_reads_notifications_through_leagues() {
+        let mut contract = create_contract();
+        let leagues_chain = ChainId(CryptoHash::test_hash("leagues"));
+        let envelope = MessageEnvelope::new(
+            "leagues-1".to_string(),
+            TraditionalLeaguesMessage::PlayerUnregistered {
+                game_id: "game_1".to_string(),
+                tournament_id: "tournament_1".to_string(),
+                player_account: "alice".to_string(),
+                refund_usdc: 10,
+            },
+        );
+        let event = bcs::to_bytes(&CoinDraftsEvent::Notification(envelope)).unwrap();
+        contract.runtime.add_event(leagues_chain, StreamName::from(CORE_NOTIFICATION_STREAM), 0, &event);
+
+        let read = || TraditionalLeaguesOperation::ReadNotification { chain_id: leagues_chain, index: 0 };
+        let response = contract.execute_operation(read()).blocking_wait();
+        assert!(matches!(response, TraditionalLeaguesResponse::Notification { envelope: None }));
+
+        contract.runtime.set_authenticated_caller_id(core_id());
+        match contract.execute_operation(read()).blocking_wait() {
+            TraditionalLeaguesResponse::Notification { envelope: Some(envelope) } => {
+                assert_eq!(envelope.message_id, "leagues-1");
+                assert!(matches!(envelope.payload, TraditionalLeaguesMessage::PlayerUnregistered { .. }));
+            }
+            response => panic!("Unexpected response: {:?}", response),
+        }
+    }
+
+    #[test]
+    fn test_only_core_acknowledges_notifications() {
+        let mut contract = create_contract();
+        contract.notify_core(TraditionalLeaguesMessage::PlayerUnregistered {
+            game_id: "game_1".to_string(),
+            tournament_id: "tournament_1".to_string(),
+            player_account: "alice".to_string(),
+            refund_usdc: 10,
+        });
+        let message_id = format!("{}-1", ChainId(CryptoHash::test_hash("leagues")));
+        let status = |contract: &TraditionalLeaguesContract| {
+            contract.state.outbox.get(&message_id).blocking_wait().unwrap().expect("Missing outbox record").status
+        };
+        let acknowledge = || TraditionalLeaguesOperation::Acknowledge { message_id: message_id.clone(), error: None };
+
+        for caller in [None, Some(ApplicationId::new(CryptoHash::test_hash("impostor")))] {
+            contract.runtime.set_authenticated_caller_id(caller);
+            let response = contract.execute_operation(acknowledge()).blocking_wait();
+            assert!(matches!(response, TraditionalLeaguesResponse::Acknowledged { success: false }));
+            assert_eq!(status(&contract), DeliveryStatus::Pending);
+        }
+
+        contract.runtime.set_authenticated_caller_id(core_id());
+        let response = contract.execute_operation(acknowledge()).blocking_wait();
+        assert!(matches!(response, TraditionalLeaguesResponse::Acknowledged { success: true }));
+        assert_eq!(status(&contract), DeliveryStatus::Delivered);
+    }
+
+    #[test]
+    fn test_withdrawal_notification_reaches_core() {
+        let mut contract = create_contract();
+        let leagues_chain = ChainId(CryptoHash::test_hash("leagues"));
+        let player = AccountOwner::Address32(CryptoHash::test_hash("admin")).to_string();
+        let tournament_id = match contract.execute_operation(TraditionalLeaguesOperation::CreateTournamentForGame {
+            game_id: "game_1".to_string(),
+            name: "Weekly".to_string(),
+            entry_fee_usdc: 10,
+            max_participants: 4,
+            duration_minutes: 60,
+        }).blocking_wait() {
+            TraditionalLeaguesResponse::TournamentCreated { tournament_id } => tournament_id,
+            response => panic!("Unexpected response: {:?}", response),
+        };
+        contract.execute_operation(TraditionalLeaguesOperation::RegisterForTournament {
+            tournament_id: tournament_id.clone(),
+            player_account: player.clone(),
+        }).blocking_wait();
+        contract.runtime.set_authenticated_caller_id(core_id());
+        contract.execute_operation(TraditionalLeaguesOperation::VerifyPlayer {
+            tournament_id: tournament_id.clone(),
+            player_account: player.clone(),
+            verified: true,
+            reason: None,
+        }).blocking_wait();
+
+        contract.runtime.set_authenticated_caller_id(None);
+        let response = contract.execute_operation(TraditionalLeaguesOperation::UnregisterFromTournament {
+            tournament_id: tournament_id.clone(),
+        }).blocking_wait();
+        assert!(matches!(response, TraditionalLeaguesResponse::PlayerUnregistered { success: true, refund_usdc: 10 }));
+
+        // The withdrawal is the latest notification published
+        let message_id = format!("{}-{}", leagues_chain, contract.state.message_counter.get());
+        let published = contract.state.outbox_messages.get(&message_id).blocking_wait().unwrap().expect("Missing notification");
+        let event = bcs::to_bytes(&CoinDraftsEvent::Notification(published)).unwrap();
+        contract.runtime.add_event(leagues_chain, StreamName::from(CORE_NOTIFICATION_STREAM), 1, &event);
+
+        contract.runtime.set_authenticated_caller_id(core_id());
+        let response = contract.execute_operation(TraditionalLeaguesOperation::ReadNotification {
+            chain_id: leagues_chain,
+            index: 1,
+        }).blocking_wait();
+        match response {
+            TraditionalLeaguesResponse::Notification { envelope: Some(envelope) } => {
+                assert_eq!(envelope.message_id, message_id);
+                match envelope.payload {
+                    TraditionalLeaguesMessage::PlayerUnregistered { game_id, tournament_id: withdrawn_from, player_account, refund_usdc } => {
+                        assert_eq!(game_id, "game_1");
+                        assert_eq!(withdrawn_from, tournament_id);
+                        assert_eq!(player_account, player);
+                        assert_eq!(refund_usdc, 10);
+                    }
+                    payload => panic!("Unexpected notification: {:?}", payload),
+                }
+            }
+            response => panic!("Unexpected response: {:?}", response),
+        }
+    }
+
+    #[test]
+    fn test_players_act_only_for_themselves() {
+        let mut contract = create_contract();
+        let tournament_id = match contract.execute_operation(TraditionalLeaguesOperation::CreateTournament {
+            name: "Weekly".to_string(),
+            entry_fee_usdc: 10,
+            max_participants: 4,
+            tournament_type: TournamentType::FreeForAll,
+            options: TournamentOptions {
+                category: CryptoCategory::L1Chains,
+                duration_minutes: 60,
+                ..TournamentOptions::default()
+            },
+        }).blocking_wait() {
+            TraditionalLeaguesResponse::TournamentCreated { tournament_id } => tournament_id,
+            response => panic!("Unexpected response: {:?}", response),
+        };
+        let alice = AccountOwner::Address32(CryptoHash::test_hash("alice"));
+        let register = || TraditionalLeaguesOperation::RegisterForTournament {
+            tournament_id: tournament_id.clone(),
+            player_account: alice.to_string(),
+        };
+        let submit = || TraditionalLeaguesOperation::SubmitPortfolioForAccount {
+            tournament_id: tournament_id.clone(),
+            player_account: alice.to_string(),
+            portfolio: TournamentPortfolio {
+                crypto_picks: PICKS.iter().map(|pick| pick.to_string()).collect(),
+                strategy_notes: None,
+                captain: None,
+                vice_captain: None,
+                short_picks: vec![],
+            },
+        };
+
+        // Neither someone else nor an unsigned call can register alice
+        let response = contract.execute_operation(register()).blocking_wait();
+        assert!(matches!(response, TraditionalLeaguesResponse::RegistrationRejected { .. }));
+        contract.runtime.set_authenticated_signer(None);
+        let response = contract.execute_operation(register()).blocking_wait();
+        assert!(matches!(response, TraditionalLeaguesResponse::RegistrationRejected { .. }));
+        assert!(!contract.state.is_participant(&tournament_id, &alice.to_string()).blocking_wait().unwrap());
+
+        contract.runtime.set_authenticated_signer(alice);
+        let response = contract.execute_operation(register()).blocking_wait();
+        assert!(matches!(response, TraditionalLeaguesResponse::PlayerRegistered { success: true }));
+
+        // Only the application creator submits on a player's behalf
+        let response = contract.execute_operation(submit()).blocking_wait();
+        assert!(matches!(response, TraditionalLeaguesResponse::PortfolioRejected { error: PortfolioError::NotAdmin }));
+        contract.runtime.set_authenticated_signer(AccountOwner::Address32(CryptoHash::test_hash("admin")));
+        let response = contract.execute_operation(submit()).blocking_wait();
+        assert!(matches!(response, TraditionalLeaguesResponse::PortfolioSubmitted { success: true }));
+    }
+
+    #[test]
+    fn test_only_full_teams_start() {
+        let mut contract = create_contract();
+        let tournament_id = match contract.execute_operation(TraditionalLeaguesOperation::CreateTournament {
+            name: "Pairs".to_string(),
+            entry_fee_usdc: 10,
+            max_participants: 8,
+            tournament_type: TournamentType::FreeForAll,
+            options: TournamentOptions {
+                category: CryptoCategory::L1Chains,
+                duration_minutes: 60,
+                team_size: 2,
+                ..TournamentOptions::default()
+            },
+        }).blocking_wait() {
+            TraditionalLeaguesResponse::TournamentCreated { tournament_id } => tournament_id,
+            response => panic!("Unexpected response: {:?}", response),
+        };
+        let [alice, bob, carol, dave] =
+            ["alice", "bob", "carol", "dave"].map(|name| AccountOwner::Address32(CryptoHash::test_hash(name)));
+        for player in [alice, bob, carol, dave] {
+            contract.runtime.set_authenticated_signer(player);
+            contract.execute_operation(TraditionalLeaguesOperation::RegisterForTournament {
+                tournament_id: tournament_id.clone(),
+                player_account: player.to_string(),
+            }).blocking_wait();
+        }
+
+        let create_team = |contract: &mut TraditionalLeaguesContract, captain: AccountOwner| {
+            contract.runtime.set_authenticated_signer(captain);
+            match contract.execute_operation(TraditionalLeaguesOperation::CreateTeam {
+                tournament_id: tournament_id.clone(),
+                name: "Team".to_string(),
+            }).blocking_wait() {
+                TraditionalLeaguesResponse::TeamCreated { team_id } => team_id,
+                response => panic!("Unexpected response: {:?}", response),
+            }
+        };
+        let pair = create_team(&mut contract, alice);
+        create_team(&mut contract, dave);
+
+        // Only the captain invites, and only the invited player can accept
+        let invite = || TraditionalLeaguesOperation::InviteToTeam { team_id: pair.clone(), player_account: bob.to_string() };
+        contract.runtime.set_authenticated_signer(carol);
+        let response = contract.execute_operation(invite()).blocking_wait();
+        assert!(matches!(response, TraditionalLeaguesResponse::TeamInvitationSent { success: false }));
+        contract.runtime.set_authenticated_signer(alice);
+        let response = contract.execute_operation(invite()).blocking_wait();
+        assert!(matches!(response, TraditionalLeaguesResponse::TeamInvitationSent { success: true }));
+        let accept = || TraditionalLeaguesOperation::AcceptTeamInvitation { team_id: pair.clone() };
+        contract.runtime.set_authenticated_signer(carol);
+        let response = contract.execute_operation(accept()).blocking_wait();
+        assert!(matches!(response, TraditionalLeaguesResponse::TeamJoined { success: false }));
+        contract.runtime.set_authenticated_signer(bob);
+        let response = contract.execute_operation(accept()).blocking_wait();
+        assert!(matches!(response, TraditionalLeaguesResponse::TeamJoined { success: true }));
+
+        // Carol has no team and Dave's is one short: both are refunded when the tournament starts
+        let response = contract.execute_operation(TraditionalLeaguesOperation::StartTournament {
+            tournament_id: tournament_id.clone(),
+            start_prices: prices(1_000_000, 0),
+        }).blocking_wait();
+        assert!(matches!(response, TraditionalLeaguesResponse::TournamentStarted { success: true, .. }));
+        let tournament = contract.state.tournaments.get(&tournament_id).blocking_wait().unwrap().unwrap();
+        assert_eq!(tournament.current_participants, 2);
+        for (player, refunded) in [(alice, None), (bob, None), (carol, Some(10)), (dave, Some(10))] {
+            let refund = contract.state.refunds.get(&format!("{}-{}", tournament_id, player)).blocking_wait().unwrap();
+            assert_eq!(refund, refunded);
+            let registered = contract.state.is_participant(&tournament_id, &player.to_string()).blocking_wait().unwrap();
+            assert_eq!(registered, refunded.is_none());
+        }
+        let teams = contract.state.tournament_teams(&tournament_id).blocking_wait().unwrap();
+        assert_eq!(teams.iter().map(|team| team.id.clone()).collect::<Vec<_>>(), vec![pair]);
+    }
+
+    #[test]
+    fn test_completion_needs_end_prices() {
+        let mut contract = create_contract();
+        let tournament_id = match contract.execute_operation(TraditionalLeaguesOperation::CreateTournament {
+            name: "Weekly".to_string(),
+            entry_fee_usdc: 10,
+            max_participants: 4,
+            tournament_type: TournamentType::FreeForAll,
+            options: TournamentOptions {
+                category: CryptoCategory::L1Chains,
+                duration_minutes: 60,
+                ..TournamentOptions::default()
+            },
+        }).blocking_wait() {
+            TraditionalLeaguesResponse::TournamentCreated { tournament_id } => tournament_id,
+            response => panic!("Unexpected response: {:?}", response),
+        };
+        for name in ["alice", "bob"] {
+            let player = AccountOwner::Address32(CryptoHash::test_hash(name));
+            contract.runtime.set_authenticated_signer(player);
+            contract.execute_operation(TraditionalLeaguesOperation::RegisterForTournament {
+                tournament_id: tournament_id.clone(),
+                player_account: player.to_string(),
+            }).blocking_wait();
+            contract.execute_operation(TraditionalLeaguesOperation::SubmitPortfolio {
+                tournament_id: tournament_id.clone(),
+                portfolio: TournamentPortfolio {
+                    crypto_picks: PICKS.iter().map(|pick| pick.to_string()).collect(),
+                    strategy_notes: None,
+                    captain: None,
+                    vice_captain: None,
+                    short_picks: vec![],
+                },
+            }).blocking_wait();
+        }
+        let response = contract.execute_operation(TraditionalLeaguesOperation::StartTournament {
+            tournament_id: tournament_id.clone(),
+            start_prices: prices(1_000_000, 0),
+        }).blocking_wait();
+        assert!(matches!(response, TraditionalLeaguesResponse::TournamentStarted { success: true, .. }));
+        contract.runtime.set_system_time(Timestamp::from(1_000_000 + 61 * MINUTE_MICROS));
+
+        let response = contract.execute_operation(TraditionalLeaguesOperation::CheckExpiredTournaments).blocking_wait();
+        match response {
+            TraditionalLeaguesResponse::ExpiredTournamentsChecked { awaiting_end_prices, cancelled_tournaments } => {
+                assert_eq!(awaiting_end_prices, vec![tournament_id.clone()]);
+                assert!(cancelled_tournaments.is_empty());
+            }
+            response => panic!("Unexpected response: {:?}", response),
+        }
+        let complete = |end_prices| TraditionalLeaguesOperation::CompleteTournament {
+            tournament_id: tournament_id.clone(),
+            end_prices,
+        };
+        let response = contract.execute_operation(complete(vec![])).blocking_wait();
+        assert!(matches!(response, TraditionalLeaguesResponse::TournamentCompleted { success: false, .. }));
+        let tournament = contract.state.tournaments.get(&tournament_id).blocking_wait().unwrap().unwrap();
+        assert_eq!(tournament.status, TournamentStatus::InProgress);
+        assert!(tournament.end_prices.is_none());
+
+        let response = contract.execute_operation(complete(prices(2_000_000, 100_000))).blocking_wait();
+        assert!(matches!(response, TraditionalLeaguesResponse::TournamentCompleted { success: true, .. }));
+        let tournament = contract.state.tournaments.get(&tournament_id).blocking_wait().unwrap().unwrap();
+        assert_eq!(tournament.status, TournamentStatus::Completed);
+        assert_eq!(tournament.end_prices.map(|end_prices| end_prices.len()), Some(PICKS.len()));
+    }
+
+    #[test]
+    fn test_early_start_does_not_cancel() {
+        let mut contract = create_contract();
+        let tournament_id = match contract.execute_operation(TraditionalLeaguesOperation::CreateTournament {
+            name: "Weekly".to_string(),
+            entry_fee_usdc: 10,
+            max_participants: 4,
+            tournament_type: TournamentType::FreeForAll,
+            options: TournamentOptions {
+                category: CryptoCategory::L1Chains,
+                duration_minutes: 60,
+                registration_minutes: 30,
+                ..TournamentOptions::default()
+            },
+        }).blocking_wait() {
+            TraditionalLeaguesResponse::TournamentCreated { tournament_id } => tournament_id,
+            response => panic!("Unexpected response: {:?}", response),
+        };
+        let alice = AccountOwner::Address32(CryptoHash::test_hash("alice"));
+        contract.runtime.set_authenticated_signer(alice);
+        contract.execute_operation(TraditionalLeaguesOperation::RegisterForTournament {
+            tournament_id: tournament_id.clone(),
+            player_account: alice.to_string(),
+        }).blocking_wait();
+
+        let start = || TraditionalLeaguesOperation::StartTournament {
+            tournament_id: tournament_id.clone(),
+            start_prices: prices(1_000_000, 0),
+        };
+        let status = |contract: &TraditionalLeaguesContract| {
+            contract.state.tournaments.get(&tournament_id).blocking_wait().unwrap().unwrap().status
+        };
+
+        // Too few entrants while registration is open: the start fails and registration goes on
+        let response = contract.execute_operation(start()).blocking_wait();
+        assert!(matches!(response, TraditionalLeaguesResponse::TournamentStarted { success: false, .. }));
+        assert_eq!(status(&contract), TournamentStatus::Registration);
+
+        // Once registration has closed the tournament is cancelled and refunded
+        contract.runtime.set_system_time(Timestamp::from(1_000_000 + 31 * MINUTE_MICROS));
+        let response = contract.execute_operation(start()).blocking_wait();
+        assert!(matches!(response, TraditionalLeaguesResponse::TournamentStarted { success: false, .. }));
+        assert_eq!(status(&contract), TournamentStatus::Cancelled);
+        let refund = contract.state.refunds.get(&format!("{}-{}", tournament_id, alice)).blocking_wait().unwrap();
+        assert_eq!(refund, Some(10));
+    }
+}
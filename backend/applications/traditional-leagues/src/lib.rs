@@ -6,11 +6,30 @@ Handles multi-round tournaments, bracket management, and advanced scoring.
 */
 
 use async_graphql::{Request, Response, SimpleObject, InputObject, Enum};
-use linera_sdk::{
-    graphql::GraphQLMutationRoot,
-    linera_base_types::{ContractAbi, ServiceAbi},
-};
+use linera_sdk::linera_base_types::{ApplicationId, ChainId, ContractAbi, ServiceAbi};
 use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+pub mod bracket;
+pub mod category;
+pub mod round_robin;
+pub mod season;
+pub mod state;
+pub mod swiss;
+pub mod team;
+
+pub use bracket::{Bracket, BracketRound, BracketSide, Matchup, PlayerLives};
+pub use category::CryptoCategory;
+pub use round_robin::{LeagueMatch, LeagueRound, MatchResult, RoundRobin, StandingsEntry};
+pub use season::{season_standings, Season, SeasonBonus, SeasonRecord, SeasonStanding, DEFAULT_BONUS_SPLIT_PERCENT, DEFAULT_POINTS_TABLE};
+pub use swiss::{Swiss, SwissStandingsEntry};
+pub use team::{apply_team_payouts, team_leaderboard, MemberPayout, Team, TeamLeaderboardEntry, TeamScoring};
+
+// Cross-application types and message protocol shared with CoinDrafts Core
+pub use coindrafts_common::{
+    ArithmeticError, CoinDraftsEvent, CoinDraftsMessage, CryptoHolding, DeliveryStatus, execute, MAX_SHORT_PERCENT, CORE_NOTIFICATION_STREAM, Fixed, FixedResult, GameEvent, GAME_EVENT_STREAM, MessageEnvelope, MessageRecord, page_slots, Portfolio, Rounding, PriceSnapshot, TournamentInfo, TournamentStatus,
+    TournamentType, TraditionalLeaguesMessage,
+};
 
 pub struct TraditionalLeaguesAbi;
 
@@ -24,8 +43,16 @@ impl ServiceAbi for TraditionalLeaguesAbi {
     type QueryResponse = Response;
 }
 
+/// Application parameters
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct TraditionalLeaguesParameters {
+    /// CoinDrafts Core application, the only caller allowed to vouch for players, sync portfolios
+    /// and acknowledge notifications
+    pub core_application_id: Option<ApplicationId>,
+}
+
 /// Operations supported by the Traditional Leagues application
-#[derive(Debug, Serialize, Deserialize, GraphQLMutationRoot)]
+#[derive(Debug, Serialize, Deserialize)]
 pub enum TraditionalLeaguesOperation {
     /// Create a new traditional league tournament
     CreateTournament {
@@ -33,19 +60,45 @@ pub enum TraditionalLeaguesOperation {
         entry_fee_usdc: u64,
         max_participants: u32,
         tournament_type: TournamentType,
-        category: String,
+        options: TournamentOptions,
     },
-    /// Register for a tournament
+    /// Register the signer for a tournament; CoinDrafts Core registers the players of its games
     RegisterForTournament {
         tournament_id: String,
         player_account: String,
     },
+    /// Complete a registration held for CoinDrafts Core verification (called by core on the same chain)
+    VerifyPlayer {
+        tournament_id: String,
+        player_account: String,
+        verified: bool,
+        /// Why the player failed verification
+        reason: Option<String>,
+    },
+    /// Withdraw the signer from a tournament while registration is open, refunding the entry fee
+    UnregisterFromTournament {
+        tournament_id: String,
+    },
+    /// Create a team in a team tournament, captained by the signer
+    CreateTeam {
+        tournament_id: String,
+        name: String,
+    },
+    /// The signing captain invites a registered player to their team
+    InviteToTeam {
+        team_id: String,
+        player_account: String,
+    },
+    /// Accept the signer's pending team invitation
+    AcceptTeamInvitation {
+        team_id: String,
+    },
     /// Submit portfolio for tournament
     SubmitPortfolio {
         tournament_id: String,
         portfolio: TournamentPortfolio,
     },
-    /// Submit portfolio for a specific player account (for seeding/testing)
+    /// Submit portfolio for a specific player account (application creator only, for seeding/testing)
     SubmitPortfolioForAccount {
         tournament_id: String,
         player_account: String,
@@ -61,12 +114,84 @@ pub enum TraditionalLeaguesOperation {
         tournament_id: String,
         end_prices: Vec<PriceSnapshot>,
     },
-    /// Complete tournament and distribute rewards
+    /// Complete a tournament whose duration has elapsed at its final prices and distribute rewards
     CompleteTournament {
         tournament_id: String,
+        end_prices: Vec<PriceSnapshot>,
     },
-    /// Check for expired tournaments and complete them (reactive alternative to timers)
+    /// Cancel under-filled tournaments whose registration closed and list expired tournaments
+    /// awaiting their final prices (reactive alternative to timers)
     CheckExpiredTournaments,
+    /// Score the current round of a round-based tournament and promote the winners
+    AdvanceRound {
+        tournament_id: String,
+        end_prices: Vec<PriceSnapshot>,
+    },
+    /// Create a season; tournaments completed between `starts_at` and `ends_at` earn season points
+    CreateSeason {
+        name: String,
+        starts_at: u64,
+        ends_at: u64,
+        /// Points per finishing position, first place first (empty = default table)
+        points_table: Vec<u32>,
+        bonus_pool_usdc: u64,
+        /// Bonus pool share per season position in percent (empty = default split)
+        bonus_split_percent: Vec<u32>,
+    },
+    /// Close a season after its end date and pay the bonus pool to the top finishers
+    EndSeason {
+        season_id: String,
+    },
+    /// Create the tournament backing a CoinDrafts Core game (called by core on the same chain)
+    CreateTournamentForGame {
+        game_id: String,
+        name: String,
+        entry_fee_usdc: u64,
+        max_participants: u32,
+        duration_minutes: u64,
+    },
+    /// Sync a portfolio submitted to a CoinDrafts Core game (called by core on the same chain)
+    SyncPortfolio {
+        game_id: String,
+        tournament_id: String,
+        portfolio: Portfolio,
+    },
+    /// Delivery receipt for a core notification (called by core on the same chain)
+    Acknowledge {
+        message_id: String,
+        error: Option<String>,
+    },
+    /// Deliver a request from CoinDrafts Core (called by core on any chain). Called away from
+    /// the leagues chain, the request is forwarded there.
+    ReceiveCoreRequest {
+        envelope: MessageEnvelope<CoinDraftsMessage>,
+    },
+    /// Read the notification this application published for core at `index` of its
+    /// notification stream on `chain_id` (called by core, which cannot read the stream itself)
+    ReadNotification {
+        chain_id: ChainId,
+        index: u32,
+    },
+    /// Resend a notification core failed to apply (application creator only)
+    RetryMessage {
+        message_id: String,
+    },
+}
+
+impl TraditionalLeaguesOperation {
+    /// Whether only the configured CoinDrafts Core application may execute the operation.
+    /// Core's requests from other chains all arrive through `ReceiveCoreRequest`, so this
+    /// one check covers them too.
+    pub fn requires_core(&self) -> bool {
+        matches!(
+            self,
+            Self::VerifyPlayer { .. }
+                | Self::SyncPortfolio { .. }
+                | Self::Acknowledge { .. }
+                | Self::ReceiveCoreRequest { .. }
+                | Self::ReadNotification { .. }
+        )
+    }
 }
 
 /// Response types from Traditional Leagues operations
@@ -74,37 +199,57 @@ pub enum TraditionalLeaguesOperation {
 pub enum TraditionalLeaguesResponse {
     TournamentCreated { tournament_id: String },
     PlayerRegistered { success: bool },
+    RegistrationPending { success: bool },
+    RegistrationRejected { reason: String },
+    PlayerUnregistered { success: bool, refund_usdc: u64 },
     PortfolioSubmitted { success: bool },
+    PortfolioRejected { error: PortfolioError },
     TournamentStarted { success: bool, timestamp: u64 },
     TournamentEnded { success: bool, winners: Vec<String> },
     RoundAdvanced { new_round: u32 },
-    TournamentCompleted { winners: Vec<String> },
-    ExpiredTournamentsChecked { completed_tournaments: Vec<String> },
+    TournamentCompleted { success: bool, winners: Vec<String> },
+    ExpiredTournamentsChecked { awaiting_end_prices: Vec<String>, cancelled_tournaments: Vec<String> },
+    TeamCreated { team_id: String },
+    TeamInvitationSent { success: bool },
+    TeamJoined { success: bool },
+    SeasonCreated { season_id: String },
+    SeasonEnded { success: bool, bonuses: Vec<SeasonBonus> },
+    Acknowledged { success: bool },
+    CoreRequestReceived { success: bool },
+    Notification { envelope: Option<MessageEnvelope<TraditionalLeaguesMessage>> },
+    MessageRetried { success: bool },
 }
 
-
-
-/// Tournament types supported
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Enum)]
-pub enum TournamentType {
-    /// Single elimination bracket
-    SingleElimination,
-    /// Double elimination bracket
-    DoubleElimination,
-    /// Round robin format
-    RoundRobin,
-    /// Swiss system tournament
-    Swiss,
+impl TraditionalLeaguesResponse {
+    /// Why the operation failed, if it did
+    pub fn failure(&self) -> Option<String> {
+        match self {
+            Self::TournamentCreated { tournament_id } if tournament_id == "error" => {
+                Some("Tournament creation failed".to_string())
+            }
+            Self::RoundAdvanced { new_round: 0 } => Some("Round could not be advanced".to_string()),
+            Self::RegistrationRejected { reason } => Some(reason.clone()),
+            Self::PortfolioRejected { error } => Some(error.to_string()),
+            Self::PlayerRegistered { success: false }
+            | Self::RegistrationPending { success: false }
+            | Self::PlayerUnregistered { success: false, .. }
+            | Self::PortfolioSubmitted { success: false }
+            | Self::TournamentStarted { success: false, .. }
+            | Self::TournamentEnded { success: false, .. }
+            | Self::TournamentCompleted { success: false, .. }
+            | Self::TeamInvitationSent { success: false }
+            | Self::TeamJoined { success: false }
+            | Self::SeasonEnded { success: false, .. }
+            | Self::Acknowledged { success: false }
+            | Self::CoreRequestReceived { success: false }
+            | Self::Notification { envelope: None }
+            | Self::MessageRetried { success: false } => Some(format!("Operation failed: {:?}", self)),
+            _ => None,
+        }
+    }
 }
 
-/// Tournament status
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Enum)]
-pub enum TournamentStatus {
-    Registration,
-    InProgress,
-    Completed,
-    Cancelled,
-}
+
 
 /// Portfolio submission for tournament
 /// Position-based ranking system: cryptos are ordered by confidence
@@ -116,10 +261,220 @@ pub struct TournamentPortfolio {
     /// First crypto has highest weight (5x), last has lowest (1x)
     pub crypto_picks: Vec<String>,
     pub strategy_notes: Option<String>,
+    /// Pick whose contribution is doubled (tournaments with captain picks only)
+    pub captain: Option<String>,
+    /// Pick that is doubled instead when the captain has no price data
+    pub vice_captain: Option<String>,
+    /// Picks held short, scoring the inverse of their price return
+    pub short_picks: Vec<String>,
 }
 
+impl TournamentPortfolio {
+    /// Check the picks: exactly `REQUIRED_PICKS` of them, no duplicates, all within `category`.
+    /// Captain and vice-captain must be distinct picks and are only allowed with `captain_picks`.
+    pub fn validate(&self, category: CryptoCategory, captain_picks: bool) -> Result<(), PortfolioError> {
+        if self.crypto_picks.len() != REQUIRED_PICKS {
+            return Err(PortfolioError::WrongPickCount {
+                expected: REQUIRED_PICKS as u32,
+                actual: self.crypto_picks.len() as u32,
+            });
+        }
+
+        for (index, pick) in self.crypto_picks.iter().enumerate() {
+            if self.crypto_picks[..index].contains(pick) {
+                return Err(PortfolioError::DuplicatePick(pick.clone()));
+            }
+            if !category.contains(pick) {
+                return Err(PortfolioError::PickOutsideCategory(pick.clone()));
+            }
+        }
+
+        for short_pick in &self.short_picks {
+            if !self.crypto_picks.contains(short_pick) {
+                return Err(PortfolioError::ShortNotPicked(short_pick.clone()));
+            }
+        }
+        let short_percent = self.short_percent();
+        if short_percent > MAX_SHORT_PERCENT {
+            return Err(PortfolioError::TooMuchShort {
+                max_percent: MAX_SHORT_PERCENT,
+                actual_percent: short_percent,
+            });
+        }
+
+        match (&self.captain, &self.vice_captain) {
+            (None, None) => {}
+            _ if !captain_picks => return Err(PortfolioError::CaptainsNotEnabled),
+            (None, Some(_)) => return Err(PortfolioError::ViceCaptainWithoutCaptain),
+            (Some(captain), vice_captain) => {
+                for designated in std::iter::once(captain).chain(vice_captain) {
+                    if !self.crypto_picks.contains(designated) {
+                        return Err(PortfolioError::CaptainNotPicked(designated.clone()));
+                    }
+                }
+                if vice_captain.as_ref() == Some(captain) {
+                    return Err(PortfolioError::ViceCaptainIsCaptain);
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Share of the position weights held short, in percent (rounded down)
+    pub fn short_percent(&self) -> u32 {
+        let short_weight: i64 = self.crypto_picks
+            .iter()
+            .zip(POSITION_WEIGHTS)
+            .filter(|(pick, _)| self.short_picks.contains(pick))
+            .map(|(_, weight)| weight)
+            .sum();
+        (short_weight * 100 / POSITION_WEIGHTS.iter().sum::<i64>()) as u32
+    }
+
+    /// The pick scored as captain: the captain, or the vice-captain when the captain
+    /// has no price data
+    pub fn acting_captain(&self, price_data: &[PriceData]) -> Option<&str> {
+        let has_price = |pick: &&String| price_data.iter().any(|price| price.symbol == **pick);
+        self.captain
+            .iter()
+            .find(has_price)
+            .or_else(|| self.vice_captain.iter().find(has_price))
+            .map(String::as_str)
+    }
+
+    /// Convert a portfolio submitted to a CoinDrafts Core game. Holdings become picks ranked
+    /// by allocation, largest first and in submission order on ties; short holdings become
+    /// short picks. The result still has to pass `validate`.
+    pub fn from_core(portfolio: &Portfolio) -> Result<Self, PortfolioError> {
+        if let Some(holding) = portfolio.holdings.iter().find(|holding| holding.allocation_percent == 0) {
+            return Err(PortfolioError::EmptyAllocation(holding.symbol.clone()));
+        }
+
+        let mut holdings: Vec<&CryptoHolding> = portfolio.holdings.iter().collect();
+        holdings.sort_by_key(|holding| std::cmp::Reverse(holding.allocation_percent));
+
+        Ok(Self {
+            crypto_picks: holdings.iter().map(|holding| holding.symbol.clone()).collect(),
+            strategy_notes: Some(format!("Portfolio synced from game {}", portfolio.game_id)),
+            captain: None,
+            vice_captain: None,
+            short_picks: holdings
+                .iter()
+                .filter(|holding| holding.short)
+                .map(|holding| holding.symbol.clone())
+                .collect(),
+        })
+    }
+}
+
+/// Reasons a tournament portfolio submission is rejected
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Error)]
+pub enum PortfolioError {
+    #[error("tournament not found")]
+    TournamentNotFound,
+    #[error("tournament is no longer accepting portfolios")]
+    RegistrationClosed,
+    #[error("player is not registered for this tournament")]
+    NotRegistered,
+    #[error("operation has no authenticated signer")]
+    MissingSigner,
+    #[error("expected {expected} picks, got {actual}")]
+    WrongPickCount { expected: u32, actual: u32 },
+    #[error("{0} is picked more than once")]
+    DuplicatePick(String),
+    #[error("{0} is not in the tournament category")]
+    PickOutsideCategory(String),
+    #[error("this tournament does not use captain picks")]
+    CaptainsNotEnabled,
+    #[error("{0} must be one of the picks to be captain or vice-captain")]
+    CaptainNotPicked(String),
+    #[error("a vice-captain needs a captain")]
+    ViceCaptainWithoutCaptain,
+    #[error("captain and vice-captain must be different picks")]
+    ViceCaptainIsCaptain,
+    #[error("{0} must be one of the picks to be held short")]
+    ShortNotPicked(String),
+    #[error("at most {max_percent}% of the portfolio can be short, got {actual_percent}%")]
+    TooMuchShort { max_percent: u32, actual_percent: u32 },
+    #[error("failed to store portfolio")]
+    StorageFailed,
+    #[error("{0} has no allocation")]
+    EmptyAllocation(String),
+    #[error("portfolio belongs to game {0}, not the tournament's game")]
+    WrongGame(String),
+    #[error("portfolio sync must come from CoinDrafts Core")]
+    UntrustedSync,
+    #[error("only the application creator may submit for another account")]
+    NotAdmin,
+}
+
+/// Multiplier applied to the acting captain's contribution
+pub const CAPTAIN_MULTIPLIER: i64 = 2;
+
+/// Number of ranked picks every tournament portfolio must contain
+pub const REQUIRED_PICKS: usize = POSITION_WEIGHTS.len();
+
+/// Default tournament length (7 days)
+pub const DEFAULT_TOURNAMENT_DURATION_MINUTES: u64 = 7 * 24 * 60;
+
+/// Fewest entrants any tournament can start with
+pub const DEFAULT_MIN_PARTICIPANTS: u32 = 2;
+
+/// Default return scale: returns are scored unamplified
+pub const DEFAULT_RETURN_SCALE: u32 = 1;
+
+/// Category, scoring and limit options of a new tournament; omitted fields take their defaults
+#[derive(Debug, Clone, Serialize, Deserialize, InputObject)]
+pub struct TournamentOptions {
+    #[graphql(default)]
+    pub category: CryptoCategory,
+    /// Tournament length in minutes, counted from the start
+    #[graphql(default_with = "DEFAULT_TOURNAMENT_DURATION_MINUTES")]
+    pub duration_minutes: u64,
+    /// Multiplier applied to every price return when scoring (1 = unscaled)
+    #[graphql(default_with = "DEFAULT_RETURN_SCALE")]
+    pub return_scale: u32,
+    /// Number of Swiss rounds (0 = enough rounds to separate the field)
+    #[graphql(default)]
+    pub round_count: u32,
+    /// Members per team (0 = individual tournament)
+    #[graphql(default)]
+    pub team_size: u32,
+    #[graphql(default)]
+    pub team_scoring: TeamScoring,
+    /// Let portfolios name a double-scoring captain and a vice-captain
+    #[graphql(default)]
+    pub captain_picks: bool,
+    /// Entrants needed to start (raised to at least `DEFAULT_MIN_PARTICIPANTS`)
+    #[graphql(default_with = "DEFAULT_MIN_PARTICIPANTS")]
+    pub min_participants: u32,
+    /// Registration window in minutes after which an under-filled tournament is cancelled (0 = none)
+    #[graphql(default)]
+    pub registration_minutes: u64,
+}
+
+impl Default for TournamentOptions {
+    fn default() -> Self {
+        Self {
+            category: CryptoCategory::default(),
+            duration_minutes: DEFAULT_TOURNAMENT_DURATION_MINUTES,
+            return_scale: DEFAULT_RETURN_SCALE,
+            round_count: 0,
+            team_size: 0,
+            team_scoring: TeamScoring::default(),
+            captain_picks: false,
+            min_participants: DEFAULT_MIN_PARTICIPANTS,
+            registration_minutes: 0,
+        }
+    }
+}
+
+/// One minute in microseconds
+pub const MINUTE_MICROS: u64 = 60 * 1_000_000;
+
 /// Position weights for scoring (Position 1 = 5x, Position 2 = 4x, etc.)
-const POSITION_WEIGHTS: [f32; 5] = [5.0, 4.0, 3.0, 2.0, 1.0];
+const POSITION_WEIGHTS: [i64; 5] = [5, 4, 3, 2, 1];
 
 /// Cryptocurrency allocation in tournament portfolio (legacy, kept for compatibility)
 #[derive(Debug, Clone, Serialize, Deserialize, SimpleObject, InputObject)]
@@ -152,22 +507,84 @@ pub struct Tournament {
     pub created_at: u64,
     pub started_at: Option<u64>,
     pub completed_at: Option<u64>,
-    /// Crypto category for this tournament (L1_CHAINS, L2_CHAINS, MEME_COINS, DEFI_TOKENS, ALL_CATEGORIES)
-    pub category: String,
+    /// Crypto category this tournament's picks are drawn from
+    pub category: CryptoCategory,
     /// Price snapshot when tournament started (crypto_id -> price in micro-units)
     pub start_prices: Option<Vec<PriceSnapshot>>,
     /// Price snapshot when tournament ended (crypto_id -> price in micro-units)
     pub end_prices: Option<Vec<PriceSnapshot>>,
+    /// Configured tournament length in minutes, counted from `started_at`
+    pub duration_minutes: u64,
+    /// Multiplier applied to every price return when scoring (1 = unscaled)
+    pub return_scale: u32,
+    /// Round currently being played (0 until a round-based tournament starts)
+    pub current_round: u32,
+    /// Configured number of Swiss rounds (0 = enough rounds to separate the field)
+    pub round_count: u32,
+    /// CoinDrafts Core game this tournament was created for, if any
+    pub game_id: Option<String>,
+    /// Members per team (0 = individual tournament)
+    pub team_size: u32,
+    /// How member returns combine into a team score
+    pub team_scoring: TeamScoring,
+    /// Portfolios may name a captain scored at `CAPTAIN_MULTIPLIER` and a vice-captain
+    pub captain_picks: bool,
+    /// Entrants needed to start; fewer cancels the tournament
+    pub min_participants: u32,
+    /// End of registration (microseconds), if the tournament has a registration window
+    pub registration_deadline: Option<u64>,
 }
 
-/// Price snapshot at a specific timestamp
-#[derive(Debug, Clone, Serialize, Deserialize, SimpleObject, InputObject)]
-#[graphql(input_name = "PriceSnapshotInput")]
-pub struct PriceSnapshot {
-    pub crypto_id: String,
-    /// Price in micro-units (price * 1_000_000 for 6 decimal precision)
-    pub price_usd: u64,
-    pub timestamp: u64,
+impl Tournament {
+    /// Check whether enough players have registered to start
+    pub fn has_enough_entrants(&self) -> bool {
+        self.current_participants >= self.min_participants
+    }
+
+    /// Check whether the registration window has closed
+    pub fn registration_expired(&self, current_time: u64) -> bool {
+        self.registration_deadline.is_some_and(|deadline| current_time >= deadline)
+    }
+
+    /// Check whether players compete in teams
+    pub fn is_team_tournament(&self) -> bool {
+        self.team_size > 0
+    }
+
+    /// Timestamp at which the tournament is due to end, once started
+    pub fn ends_at(&self) -> Option<u64> {
+        self.started_at
+            .map(|start| start.saturating_add(self.duration_minutes.saturating_mul(MINUTE_MICROS)))
+    }
+
+    /// Check whether the configured duration has elapsed
+    pub fn has_expired(&self, current_time: u64) -> bool {
+        self.ends_at().is_some_and(|end| current_time >= end)
+    }
+
+    /// Price data for scoring, with returns multiplied by the configured return scale
+    pub fn price_data(&self) -> FixedResult<Option<Vec<PriceData>>> {
+        match (&self.start_prices, &self.end_prices) {
+            (Some(start_prices), Some(end_prices)) => {
+                PriceData::from_snapshots(start_prices, end_prices, self.return_scale as i64).map(Some)
+            }
+            _ => Ok(None),
+        }
+    }
+
+    /// Summary of this tournament for other applications
+    pub fn info(&self) -> TournamentInfo {
+        TournamentInfo {
+            tournament_id: self.id.clone(),
+            name: self.name.clone(),
+            tournament_type: self.tournament_type,
+            status: self.status,
+            entry_fee_usdc: self.entry_fee_usdc,
+            max_participants: self.max_participants,
+            current_participants: self.current_participants,
+            duration_minutes: self.duration_minutes,
+        }
+    }
 }
 
 /// Price data for cryptocurrency scoring (using integer representations)
@@ -178,30 +595,86 @@ pub struct PriceData {
     pub start_price: u64,
     /// End price in micro-units (price * 1_000_000 for 6 decimal precision)
     pub end_price: u64,
-    /// Percentage change scaled by 10000 (e.g., 5.25% = 52500)
-    pub percentage_change: i32,
+    /// Relative price change in fixed point (e.g., 5.25% = 525)
+    pub percentage_change: Fixed,
 }
 
 /// Portfolio performance result
 #[derive(Debug, Clone, Serialize, Deserialize, SimpleObject)]
 pub struct PortfolioPerformance {
     pub player_account: String,
-    /// Total return percentage scaled by 10000 (e.g., 12.50% = 125000)
-    pub total_return: i32,
+    /// Total weighted return in fixed point (e.g., 12.50% = 1250)
+    pub total_return: Fixed,
     pub rank: u32,
     pub portfolio: TournamentPortfolio,
 }
 
+/// Where a player's registration for a tournament stands
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Enum)]
+pub enum RegistrationStatus {
+    #[graphql(name = "NOT_REGISTERED")]
+    NotRegistered,
+    /// Waiting for CoinDrafts Core to verify the player's profile and entry payment
+    #[graphql(name = "PENDING")]
+    Pending,
+    #[graphql(name = "REGISTERED")]
+    Registered,
+    #[graphql(name = "REJECTED")]
+    Rejected,
+}
+
+/// A player's registration for a tournament
+#[derive(Debug, Clone, Serialize, Deserialize, SimpleObject)]
+pub struct Registration {
+    pub tournament_id: String,
+    pub player_account: String,
+    pub status: RegistrationStatus,
+    /// Why the last registration attempt was rejected
+    pub rejection_reason: Option<String>,
+}
+
 /// Tournament leaderboard entry
 #[derive(Debug, Clone, Serialize, Deserialize, SimpleObject)]
 pub struct LeaderboardEntry {
     pub rank: u32,
     pub player_account: String,
-    /// Total return percentage scaled by 10000 (e.g., 12.50% = 125000)
-    pub total_return: i32,
+    /// Total weighted return in fixed point (e.g., 12.50% = 1250)
+    pub total_return: Fixed,
     pub winning_amount: u64, // USDC winnings
 }
 
+/// Prize split for the top three finishers (60% winner, 30% second, 10% third)
+pub(crate) const PRIZE_SPLIT_PERCENT: [u32; 3] = [60, 30, 10];
+
+impl PriceData {
+    /// Build price data from start and end snapshots, multiplying each return by `amplification`
+    pub fn from_snapshots(
+        start_prices: &[PriceSnapshot],
+        end_prices: &[PriceSnapshot],
+        amplification: i64,
+    ) -> FixedResult<Vec<PriceData>> {
+        let mut prices = Vec::new();
+        for start_snap in start_prices {
+            if let Some(end_snap) = end_prices.iter().find(|e| e.crypto_id == start_snap.crypto_id) {
+                let percentage_change = if start_snap.price_usd > 0 {
+                    Fixed::percent_change(start_snap.price_usd, end_snap.price_usd, Rounding::HalfEven)?
+                        .checked_mul_int(amplification)?
+                } else {
+                    Fixed::ZERO
+                };
+
+                prices.push(PriceData {
+                    symbol: start_snap.crypto_id.clone(),
+                    start_price: start_snap.price_usd,
+                    end_price: end_snap.price_usd,
+                    percentage_change,
+                });
+            }
+        }
+        Ok(prices)
+    }
+}
+
 /// Mock price data for testing (10-minute tournaments)
 impl PriceData {
     pub fn get_mock_prices() -> Vec<PriceData> {
@@ -210,31 +683,31 @@ impl PriceData {
                 symbol: "BTC".to_string(),
                 start_price: 45_000_000_000, // $45,000.00 * 1_000_000
                 end_price: 46_800_000_000,   // $46,800.00 * 1_000_000 (+4% return)
-                percentage_change: 40000,     // 4.0% * 10000
+                percentage_change: Fixed::from_raw(400),
             },
             PriceData {
                 symbol: "ETH".to_string(),
                 start_price: 3_200_000_000,  // $3,200.00 * 1_000_000
                 end_price: 3_360_000_000,    // $3,360.00 * 1_000_000 (+5% return)
-                percentage_change: 50000,     // 5.0% * 10000
+                percentage_change: Fixed::from_raw(500),
             },
             PriceData {
                 symbol: "ADA".to_string(),
                 start_price: 450_000,        // $0.45 * 1_000_000
                 end_price: 470_000,          // $0.47 * 1_000_000 (+4.4% return)
-                percentage_change: 44000,     // 4.4% * 10000
+                percentage_change: Fixed::from_raw(444),
             },
             PriceData {
                 symbol: "SOL".to_string(),
                 start_price: 180_000_000,    // $180.00 * 1_000_000
                 end_price: 171_000_000,      // $171.00 * 1_000_000 (-5% return)
-                percentage_change: -50000,    // -5.0% * 10000
+                percentage_change: Fixed::from_raw(-500),
             },
             PriceData {
                 symbol: "DOT".to_string(),
                 start_price: 8_500_000,      // $8.50 * 1_000_000
                 end_price: 9_000_000,        // $9.00 * 1_000_000 (+5.9% return)
-                percentage_change: 59000,     // 5.9% * 10000
+                percentage_change: Fixed::from_raw(588),
             },
         ]
     }
@@ -246,12 +719,14 @@ pub struct ScoringEngine;
 impl ScoringEngine {
     /// Calculate portfolio performance using position-based weights
     /// Position 1 = 5x, Position 2 = 4x, Position 3 = 3x, Position 4 = 2x, Position 5 = 1x
-    /// Score = sum of (crypto_percentage_change * position_weight)
+    /// Score = sum of (crypto_percentage_change * position_weight), with short picks'
+    /// changes inverted and the acting captain's term multiplied by `CAPTAIN_MULTIPLIER`
     pub fn calculate_portfolio_performance(
         portfolio: &TournamentPortfolio,
         price_data: &[PriceData],
-    ) -> i32 {
-        let mut total_score = 0i32;
+    ) -> FixedResult<Fixed> {
+        let mut total_score = Fixed::ZERO;
+        let captain = portfolio.acting_captain(price_data);
         
         // Iterate through picks with their position index
         for (position_index, crypto_symbol) in portfolio.crypto_picks.iter().enumerate() {
@@ -261,20 +736,20 @@ impl ScoringEngine {
             
             // Find price data for this crypto
             if let Some(price_info) = price_data.iter().find(|p| p.symbol == *crypto_symbol) {
-                let position_weight = POSITION_WEIGHTS[position_index];
-                
-                // percentage_change is scaled by 10000 (e.g., 5.25% = 52500)
-                // Convert weight to integer scaled by 10000 for calculation
-                let weight_scaled = (position_weight * 10000.0) as i32;
-                
-                // Calculate contribution: (percentage_change * weight) / 10000
-                // This keeps the result scaled by 10000 for precision
-                let contribution = (price_info.percentage_change * weight_scaled) / 10000;
-                total_score += contribution;
+                let mut contribution = price_info
+                    .percentage_change
+                    .checked_mul_int(POSITION_WEIGHTS[position_index])?;
+                if portfolio.short_picks.contains(crypto_symbol) {
+                    contribution = contribution.checked_mul_int(-1)?;
+                }
+                if captain == Some(crypto_symbol.as_str()) {
+                    contribution = contribution.checked_mul_int(CAPTAIN_MULTIPLIER)?;
+                }
+                total_score = total_score.checked_add(contribution)?;
             }
         }
         
-        total_score
+        Ok(total_score)
     }
     
     /// Calculate portfolio performance (legacy method with allocation percentages)
@@ -283,19 +758,19 @@ impl ScoringEngine {
     pub fn calculate_portfolio_performance_legacy(
         portfolio: &TournamentPortfolio,
         price_data: &[PriceData],
-    ) -> i32 {
+    ) -> FixedResult<Fixed> {
         // Legacy implementation - use crypto_picks with equal weights
-        let mut total_return = 0i32;
-        let equal_weight = 100 / portfolio.crypto_picks.len().max(1) as i32;
+        let mut total_return = Fixed::ZERO;
+        let equal_weight = Fixed::from_ratio(1, portfolio.crypto_picks.len().max(1) as i128, Rounding::Down)?;
         
         for crypto_symbol in &portfolio.crypto_picks {
             if let Some(price_info) = price_data.iter().find(|p| p.symbol == *crypto_symbol) {
-                let contribution = (price_info.percentage_change * equal_weight) / 100;
-                total_return += contribution;
+                let contribution = price_info.percentage_change.checked_mul(equal_weight, Rounding::HalfEven)?;
+                total_return = total_return.checked_add(contribution)?;
             }
         }
         
-        total_return
+        Ok(total_return)
     }
     
     /// Calculate tournament winners and rankings
@@ -303,99 +778,214 @@ impl ScoringEngine {
         portfolios: Vec<(String, TournamentPortfolio)>,
         price_data: &[PriceData],
         total_prize_pool: u64,
-    ) -> Vec<LeaderboardEntry> {
-        let mut performance: Vec<(String, i32)> = portfolios
-            .iter()
-            .map(|(player, portfolio)| {
-                let score = Self::calculate_portfolio_performance(portfolio, price_data);
-                (player.clone(), score)
-            })
-            .collect();
+    ) -> FixedResult<Vec<LeaderboardEntry>> {
+        let mut performance: Vec<(String, Fixed)> = Vec::new();
+        for (player, portfolio) in &portfolios {
+            let score = Self::calculate_portfolio_performance(portfolio, price_data)?;
+            performance.push((player.clone(), score));
+        }
         
         // Sort by performance (highest first)
         performance.sort_by(|a, b| b.1.cmp(&a.1));
         
-        // Calculate prize distribution (60% winner, 30% second, 10% third)
-        performance
-            .into_iter()
-            .enumerate()
-            .map(|(index, (player, score))| {
-                let rank = (index + 1) as u32;
-                let winning_amount = match rank {
-                    1 => (total_prize_pool * 60) / 100,  // 60%
-                    2 => (total_prize_pool * 30) / 100,  // 30%
-                    3 => (total_prize_pool * 10) / 100,  // 10%
-                    _ => 0,
-                };
-                
-                LeaderboardEntry {
-                    rank,
-                    player_account: player,
-                    total_return: score,
-                    winning_amount,
-                }
-            })
-            .collect()
+        Self::rank_leaderboard(performance, total_prize_pool)
     }
-}
-
-/// Message types for cross-chain communication
 
-/// Messages sent TO CoinDrafts Core
-#[derive(Debug, Serialize, Deserialize)]
-pub enum TraditionalLeaguesMessage {
-    /// Notify CoinDrafts Core of tournament creation
-    TournamentCreated {
-        game_id: String,
-        tournament_id: String,
-        tournament_info: Tournament,
-    },
-    /// Notify CoinDrafts Core of tournament completion
-    TournamentCompleted {
-        game_id: String,
-        tournament_id: String,
-        winners: Vec<String>,
+    /// Build the leaderboard of a round-based tournament. Ranks follow the final
+    /// `placements`; each entry reports the player's return over the whole tournament.
+    pub fn placement_leaderboard(
+        placements: Vec<String>,
+        portfolios: &[(String, TournamentPortfolio)],
+        price_data: &[PriceData],
         total_prize_pool: u64,
-    },
-    /// Request player verification from CoinDrafts Core
-    VerifyPlayer {
-        game_id: String,
-        player_account: String,
-        tournament_id: String,
-    },
+    ) -> FixedResult<Vec<LeaderboardEntry>> {
+        let mut ordered = Vec::new();
+        for player in placements {
+            let score = match portfolios.iter().find(|(account, _)| *account == player) {
+                Some((_, portfolio)) => Self::calculate_portfolio_performance(portfolio, price_data)?,
+                None => Fixed::ZERO,
+            };
+            ordered.push((player, score));
+        }
+
+        Self::rank_leaderboard(ordered, total_prize_pool)
+    }
+
+    /// Assign ranks and prizes to players already in finishing order
+    fn rank_leaderboard(ordered: Vec<(String, Fixed)>, total_prize_pool: u64) -> FixedResult<Vec<LeaderboardEntry>> {
+        // Calculate prize distribution from PRIZE_SPLIT_PERCENT
+        let mut leaderboard = Vec::new();
+        for (index, (player, score)) in ordered.into_iter().enumerate() {
+            let winning_amount = match PRIZE_SPLIT_PERCENT.get(index) {
+                Some(percent) => Fixed::from_percent(*percent).apply_to_amount(total_prize_pool, Rounding::Down)?,
+                None => 0,
+            };
+            
+            leaderboard.push(LeaderboardEntry {
+                rank: (index + 1) as u32,
+                player_account: player,
+                total_return: score,
+                winning_amount,
+            });
+        }
+        
+        Ok(leaderboard)
+    }
 }
 
-/// Messages received FROM CoinDrafts Core
-#[derive(Debug, Serialize, Deserialize)]
-pub enum CoinDraftsMessage {
-    /// Request to create a tournament for a Traditional League game
-    CreateTournament {
-        game_id: String,
-        tournament_name: String,
-        entry_fee_usdc: u64,
-        max_participants: u32,
-    },
-    /// Register a player for a specific tournament
-    RegisterPlayerForTournament {
-        game_id: String,
-        tournament_id: String,
-        player_profile: String, // JSON serialized PlayerProfile
-    },
-    /// Sync portfolio submission to tournament
-    SyncPortfolio {
-        game_id: String,
-        tournament_id: String,
-        portfolio: String, // JSON serialized Portfolio
-    },
-    /// Request tournament status update
-    GetTournamentStatus {
-        tournament_id: String,
-    },
-    /// Response to player verification request
-    VerifyPlayer {
-        game_id: String,
-        player_account: String,
-        tournament_id: String,
-        verified: bool,
-    },
-}
\ No newline at end of file
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use coindrafts_common::PortfolioStatus;
+
+    fn portfolio(picks: &[&str]) -> TournamentPortfolio {
+        TournamentPortfolio {
+            crypto_picks: picks.iter().map(|pick| pick.to_string()).collect(),
+            strategy_notes: None,
+            captain: None,
+            vice_captain: None,
+            short_picks: vec![],
+        }
+    }
+
+    #[test]
+    fn test_portfolio_validation() {
+        let valid = portfolio(&["bitcoin", "ethereum", "solana", "cardano", "polkadot"]);
+        assert_eq!(valid.validate(CryptoCategory::L1Chains, false), Ok(()));
+        assert_eq!(valid.validate(CryptoCategory::AllCategories, false), Ok(()));
+        assert_eq!(
+            valid.validate(CryptoCategory::MemeCoins, false),
+            Err(PortfolioError::PickOutsideCategory("bitcoin".to_string()))
+        );
+
+        let short = portfolio(&["bitcoin", "ethereum"]);
+        assert_eq!(
+            short.validate(CryptoCategory::L1Chains, false),
+            Err(PortfolioError::WrongPickCount { expected: 5, actual: 2 })
+        );
+
+        let duplicate = portfolio(&["bitcoin", "ethereum", "solana", "bitcoin", "polkadot"]);
+        assert_eq!(
+            duplicate.validate(CryptoCategory::L1Chains, false),
+            Err(PortfolioError::DuplicatePick("bitcoin".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_placement_leaderboard_follows_placements() {
+        let prices = vec![PriceData {
+            symbol: "bitcoin".to_string(),
+            start_price: 1_000,
+            end_price: 1_100,
+            percentage_change: Fixed::from_raw(1_000),
+        }];
+        let portfolios = vec![
+            ("a".to_string(), portfolio(&["bitcoin", "ethereum", "solana", "cardano", "polkadot"])),
+            ("b".to_string(), portfolio(&["ethereum", "solana", "cardano", "polkadot", "bitcoin"])),
+        ];
+
+        // "b" won the bracket despite the lower overall return
+        let placements = vec!["b".to_string(), "a".to_string(), "c".to_string()];
+        let leaderboard = ScoringEngine::placement_leaderboard(placements, &portfolios, &prices, 1_000).unwrap();
+
+        let summary: Vec<(u32, &str, i64, u64)> = leaderboard
+            .iter()
+            .map(|entry| (entry.rank, entry.player_account.as_str(), entry.total_return.raw(), entry.winning_amount))
+            .collect();
+        assert_eq!(summary, vec![(1, "b", 1_000, 600), (2, "a", 5_000, 300), (3, "c", 0, 100)]);
+    }
+
+    #[test]
+    fn test_captain_picks() {
+        let mut captained = portfolio(&["bitcoin", "ethereum", "solana", "cardano", "polkadot"]);
+        captained.captain = Some("solana".to_string());
+        captained.vice_captain = Some("cardano".to_string());
+        assert_eq!(captained.validate(CryptoCategory::L1Chains, true), Ok(()));
+        assert_eq!(captained.validate(CryptoCategory::L1Chains, false), Err(PortfolioError::CaptainsNotEnabled));
+
+        let mut outsider = captained.clone();
+        outsider.vice_captain = Some("dogecoin".to_string());
+        assert_eq!(
+            outsider.validate(CryptoCategory::L1Chains, true),
+            Err(PortfolioError::CaptainNotPicked("dogecoin".to_string()))
+        );
+
+        let price = |symbol: &str, change: i64| PriceData {
+            symbol: symbol.to_string(),
+            start_price: 1_000,
+            end_price: 1_000,
+            percentage_change: Fixed::from_raw(change),
+        };
+
+        // Solana (3x) is doubled to 6x: 6 * 100 + 2 * 50
+        let prices = vec![price("solana", 100), price("cardano", 50)];
+        assert_eq!(ScoringEngine::calculate_portfolio_performance(&captained, &prices).unwrap(), Fixed::from_raw(700));
+
+        // Without a solana feed the vice-captain cardano (2x) is doubled instead
+        let prices = vec![price("cardano", 50)];
+        assert_eq!(ScoringEngine::calculate_portfolio_performance(&captained, &prices).unwrap(), Fixed::from_raw(200));
+    }
+
+    #[test]
+    fn test_short_picks() {
+        let mut bearish = portfolio(&["bitcoin", "ethereum", "solana", "cardano", "polkadot"]);
+        bearish.short_picks = vec!["bitcoin".to_string(), "ethereum".to_string()];
+        assert_eq!(
+            bearish.validate(CryptoCategory::L1Chains, false),
+            Err(PortfolioError::TooMuchShort { max_percent: 50, actual_percent: 60 })
+        );
+
+        bearish.short_picks = vec!["bitcoin".to_string(), "dogecoin".to_string()];
+        assert_eq!(
+            bearish.validate(CryptoCategory::L1Chains, false),
+            Err(PortfolioError::ShortNotPicked("dogecoin".to_string()))
+        );
+
+        // Bitcoin (5x) short against a 2% drop scores +10%; ethereum (4x) long scores -8%
+        bearish.short_picks = vec!["bitcoin".to_string()];
+        assert_eq!(bearish.validate(CryptoCategory::L1Chains, false), Ok(()));
+        let prices: Vec<PriceData> = ["bitcoin", "ethereum"]
+            .iter()
+            .map(|symbol| PriceData {
+                symbol: symbol.to_string(),
+                start_price: 1_000,
+                end_price: 980,
+                percentage_change: Fixed::from_raw(-200),
+            })
+            .collect();
+        assert_eq!(ScoringEngine::calculate_portfolio_performance(&bearish, &prices).unwrap(), Fixed::from_raw(200));
+    }
+
+    #[test]
+    fn test_core_portfolio_conversion() {
+        let holding = |symbol: &str, allocation_percent: u8, short: bool| CryptoHolding {
+            symbol: symbol.to_string(),
+            allocation_percent,
+            short,
+        };
+        let mut core = Portfolio {
+            game_id: "game_1".to_string(),
+            player_account: "a".to_string(),
+            holdings: vec![
+                holding("cardano", 10, false),
+                holding("bitcoin", 30, false),
+                holding("ethereum", 20, true),
+                holding("solana", 20, false),
+                holding("polkadot", 20, false),
+            ],
+            submitted_at: 0,
+            status: PortfolioStatus::Valid,
+        };
+
+        let converted = TournamentPortfolio::from_core(&core).unwrap();
+        assert_eq!(converted.crypto_picks, vec!["bitcoin", "ethereum", "solana", "polkadot", "cardano"]);
+        assert_eq!(converted.short_picks, vec!["ethereum"]);
+        assert_eq!(converted.validate(CryptoCategory::L1Chains, false), Ok(()));
+
+        core.holdings[0].allocation_percent = 0;
+        assert_eq!(
+            TournamentPortfolio::from_core(&core).unwrap_err(),
+            PortfolioError::EmptyAllocation("cardano".to_string())
+        );
+    }
+}
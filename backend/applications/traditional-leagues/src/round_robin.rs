@@ -0,0 +1,459 @@
+/*!
+# Round-Robin Leagues
+
+Full pairwise schedules for round-robin tournaments. The schedule is drawn up front with
+the circle method, every round is scored head-to-head over its own price window, and
+players collect win/draw/loss points into a standings table.
+*/
+
+use std::collections::BTreeMap;
+
+use async_graphql::{Enum, SimpleObject};
+use serde::{Deserialize, Serialize};
+
+use crate::{Fixed, FixedResult, PriceData, PriceSnapshot, ScoringEngine, TournamentPortfolio};
+
+/// League points for a win
+pub const WIN_POINTS: u32 = 3;
+/// League points for a draw
+pub const DRAW_POINTS: u32 = 1;
+/// League points for a loss
+pub const LOSS_POINTS: u32 = 0;
+
+/// Outcome of a league match
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Enum)]
+pub enum MatchResult {
+    HomeWin,
+    AwayWin,
+    Draw,
+}
+
+/// Pairing of two players within a round; a missing away player is a bye
+#[derive(Debug, Clone, Serialize, Deserialize, SimpleObject)]
+pub struct LeagueMatch {
+    pub round: u32,
+    pub home: String,
+    pub away: Option<String>,
+    /// Weighted return of the home player over the round's price window
+    pub home_score: Option<Fixed>,
+    /// Weighted return of the away player over the round's price window
+    pub away_score: Option<Fixed>,
+    pub result: Option<MatchResult>,
+}
+
+/// One round of league matches with its own price window
+#[derive(Debug, Clone, Serialize, Deserialize, SimpleObject)]
+pub struct LeagueRound {
+    pub round: u32,
+    pub matches: Vec<LeagueMatch>,
+    /// Prices when the round started; set once the previous round is scored
+    pub start_prices: Option<Vec<PriceSnapshot>>,
+    /// Prices when the round was scored
+    pub end_prices: Option<Vec<PriceSnapshot>>,
+}
+
+/// Round-robin schedule for a tournament
+#[derive(Debug, Clone, Serialize, Deserialize, SimpleObject)]
+pub struct RoundRobin {
+    pub tournament_id: String,
+    /// Players in registration order
+    pub players: Vec<String>,
+    pub rounds: Vec<LeagueRound>,
+    /// Round currently being played (1-based); past the last round once finished
+    pub current_round: u32,
+}
+
+/// Row of a league standings table
+#[derive(Debug, Clone, Serialize, Deserialize, SimpleObject)]
+pub struct StandingsEntry {
+    pub rank: u32,
+    pub player_account: String,
+    pub played: u32,
+    pub wins: u32,
+    pub draws: u32,
+    pub losses: u32,
+    pub points: u32,
+    /// Sum of the player's own round returns
+    pub return_for: Fixed,
+    /// Sum of the opponents' round returns
+    pub return_against: Fixed,
+    pub return_differential: Fixed,
+}
+
+impl LeagueMatch {
+    pub(crate) fn new(round: u32, home: String, away: Option<String>) -> Self {
+        Self {
+            round,
+            home,
+            away,
+            home_score: None,
+            away_score: None,
+            result: None,
+        }
+    }
+
+    pub fn is_bye(&self) -> bool {
+        self.away.is_none()
+    }
+
+    /// Whether this match was played between `a` and `b`, in either order
+    pub fn is_between(&self, a: &str, b: &str) -> bool {
+        let away = self.away.as_deref();
+        (self.home == a && away == Some(b)) || (self.home == b && away == Some(a))
+    }
+
+    /// League points earned by `player` in this match, if it was decided
+    pub fn points_for(&self, player: &str) -> Option<u32> {
+        let home = self.home == player;
+        if !home && self.away.as_deref() != Some(player) {
+            return None;
+        }
+        Some(match (self.result?, home) {
+            (MatchResult::Draw, _) => DRAW_POINTS,
+            (MatchResult::HomeWin, true) | (MatchResult::AwayWin, false) => WIN_POINTS,
+            _ => LOSS_POINTS,
+        })
+    }
+
+    /// Opponent of `player` in this match
+    pub fn opponent_of(&self, player: &str) -> Option<&String> {
+        if self.home == player {
+            self.away.as_ref()
+        } else if self.away.as_deref() == Some(player) {
+            Some(&self.home)
+        } else {
+            None
+        }
+    }
+
+    /// Score both players over the round window; equal returns are a draw
+    pub(crate) fn decide(
+        &mut self,
+        portfolios: &BTreeMap<String, TournamentPortfolio>,
+        price_data: &[PriceData],
+    ) -> FixedResult<()> {
+        let Some(away) = &self.away else {
+            return Ok(());
+        };
+        let score = |player: &String| -> FixedResult<Fixed> {
+            match portfolios.get(player) {
+                Some(portfolio) => ScoringEngine::calculate_portfolio_performance(portfolio, price_data),
+                None => Ok(Fixed::ZERO),
+            }
+        };
+        let home_score = score(&self.home)?;
+        let away_score = score(away)?;
+
+        self.result = Some(match home_score.cmp(&away_score) {
+            std::cmp::Ordering::Greater => MatchResult::HomeWin,
+            std::cmp::Ordering::Less => MatchResult::AwayWin,
+            std::cmp::Ordering::Equal => MatchResult::Draw,
+        });
+        self.home_score = Some(home_score);
+        self.away_score = Some(away_score);
+        Ok(())
+    }
+}
+
+/// Running totals for one player while building a standings table
+#[derive(Default)]
+pub(crate) struct Tally {
+    pub played: u32,
+    pub wins: u32,
+    pub draws: u32,
+    pub losses: u32,
+    pub points: u32,
+    pub return_for: Fixed,
+    pub return_against: Fixed,
+}
+
+impl Tally {
+    /// Tally every decided match across `rounds`
+    pub(crate) fn collect<'a>(
+        players: &[String],
+        rounds: impl Iterator<Item = &'a LeagueRound>,
+    ) -> FixedResult<BTreeMap<String, Tally>> {
+        let mut tallies: BTreeMap<String, Tally> = players.iter().map(|player| (player.clone(), Tally::default())).collect();
+        for league_match in rounds.flat_map(|round| round.matches.iter()) {
+            let (Some(away), Some(result), Some(home_score), Some(away_score)) =
+                (&league_match.away, league_match.result, league_match.home_score, league_match.away_score)
+            else {
+                continue;
+            };
+            for (player, own, other, won) in [
+                (&league_match.home, home_score, away_score, result == MatchResult::HomeWin),
+                (away, away_score, home_score, result == MatchResult::AwayWin),
+            ] {
+                let tally = tallies.entry(player.clone()).or_default();
+                tally.played += 1;
+                match (result, won) {
+                    (MatchResult::Draw, _) => tally.draws += 1,
+                    (_, true) => tally.wins += 1,
+                    _ => tally.losses += 1,
+                }
+                tally.points += league_match.points_for(player).unwrap_or(LOSS_POINTS);
+                tally.return_for = tally.return_for.checked_add(own)?;
+                tally.return_against = tally.return_against.checked_add(other)?;
+            }
+        }
+        Ok(tallies)
+    }
+
+    pub(crate) fn into_entry(self, rank: u32, player_account: String) -> FixedResult<StandingsEntry> {
+        Ok(StandingsEntry {
+            rank,
+            player_account,
+            played: self.played,
+            wins: self.wins,
+            draws: self.draws,
+            losses: self.losses,
+            points: self.points,
+            return_differential: self.return_for.checked_sub(self.return_against)?,
+            return_for: self.return_for,
+            return_against: self.return_against,
+        })
+    }
+}
+
+/// Circle-method schedule: one player stays fixed while the rest rotate, so every
+/// pair meets exactly once. Odd fields add a bye slot.
+pub fn circle_schedule(players: &[String]) -> Vec<Vec<(String, Option<String>)>> {
+    let mut slots: Vec<Option<String>> = players.iter().cloned().map(Some).collect();
+    if slots.len() % 2 == 1 {
+        slots.push(None);
+    }
+    let count = slots.len();
+    if count < 2 {
+        return Vec::new();
+    }
+
+    let mut rounds = Vec::with_capacity(count - 1);
+    for round in 0..count - 1 {
+        let mut pairs = Vec::with_capacity(count / 2);
+        for index in 0..count / 2 {
+            let (first, second) = (&slots[index], &slots[count - 1 - index]);
+            // Alternate home side for the fixed player between rounds
+            let (home, away) = if index == 0 && round % 2 == 1 { (second, first) } else { (first, second) };
+            match (home, away) {
+                (Some(home), away) => pairs.push((home.clone(), away.clone())),
+                (None, Some(away)) => pairs.push((away.clone(), None)),
+                (None, None) => {}
+            }
+        }
+        rounds.push(pairs);
+        slots[1..].rotate_right(1);
+    }
+    rounds
+}
+
+impl RoundRobin {
+    /// Draw the full schedule; round 1 starts at `start_prices`
+    pub fn new(tournament_id: String, players: Vec<String>, start_prices: Vec<PriceSnapshot>) -> Self {
+        let rounds = circle_schedule(&players)
+            .into_iter()
+            .enumerate()
+            .map(|(index, pairs)| {
+                let round = index as u32 + 1;
+                LeagueRound {
+                    round,
+                    matches: pairs.into_iter().map(|(home, away)| LeagueMatch::new(round, home, away)).collect(),
+                    start_prices: if round == 1 { Some(start_prices.clone()) } else { None },
+                    end_prices: None,
+                }
+            })
+            .collect();
+
+        Self {
+            tournament_id,
+            players,
+            rounds,
+            current_round: 1,
+        }
+    }
+
+    pub fn total_rounds(&self) -> u32 {
+        self.rounds.len() as u32
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.current_round > self.total_rounds()
+    }
+
+    /// Players with a match to score in the current round
+    pub fn active_players(&self) -> Vec<String> {
+        self.rounds.get(self.current_round as usize - 1)
+            .map(|round| {
+                round.matches.iter()
+                    .filter(|league_match| !league_match.is_bye())
+                    .flat_map(|league_match| std::iter::once(league_match.home.clone()).chain(league_match.away.clone()))
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    /// Score the current round against `end_prices`; the next round starts at those prices.
+    /// Returns the new round number, or `None` once every round has been played.
+    pub fn advance_round(
+        &mut self,
+        end_prices: Vec<PriceSnapshot>,
+        portfolios: &BTreeMap<String, TournamentPortfolio>,
+        return_scale: i64,
+    ) -> FixedResult<Option<u32>> {
+        let index = self.current_round as usize - 1;
+        let Some(round) = self.rounds.get_mut(index) else {
+            return Ok(None);
+        };
+
+        let start_prices = round.start_prices.clone().unwrap_or_default();
+        let price_data = PriceData::from_snapshots(&start_prices, &end_prices, return_scale)?;
+        for league_match in round.matches.iter_mut() {
+            league_match.decide(portfolios, &price_data)?;
+        }
+        round.end_prices = Some(end_prices.clone());
+
+        self.current_round += 1;
+        match self.rounds.get_mut(index + 1) {
+            Some(next) => {
+                next.start_prices = Some(end_prices);
+                Ok(Some(self.current_round))
+            }
+            None => Ok(None),
+        }
+    }
+
+    /// Standings ordered by points, then head-to-head points among tied players,
+    /// then return differential, then registration order
+    pub fn standings(&self) -> FixedResult<Vec<StandingsEntry>> {
+        let mut tallies = Tally::collect(&self.players, self.rounds.iter())?;
+
+        let head_to_head = |player: &String, rivals: &[&String]| -> u32 {
+            self.rounds.iter()
+                .flat_map(|round| round.matches.iter())
+                .filter(|league_match| rivals.iter().any(|rival| league_match.is_between(player, rival)))
+                .filter_map(|league_match| league_match.points_for(player))
+                .sum()
+        };
+
+        let mut order: Vec<(u32, u32, Fixed, usize, &String)> = Vec::with_capacity(self.players.len());
+        for (index, player) in self.players.iter().enumerate() {
+            let tally = &tallies[player];
+            let rivals: Vec<&String> = self.players.iter()
+                .filter(|other| *other != player && tallies[*other].points == tally.points)
+                .collect();
+            let differential = tally.return_for.checked_sub(tally.return_against)?;
+            order.push((tally.points, head_to_head(player, &rivals), differential, index, player));
+        }
+        order.sort_by(|a, b| b.0.cmp(&a.0).then(b.1.cmp(&a.1)).then(b.2.cmp(&a.2)).then(a.3.cmp(&b.3)));
+
+        order.into_iter()
+            .enumerate()
+            .map(|(rank, (_, _, _, _, player))| {
+                let tally = tallies.remove(player).unwrap_or_default();
+                tally.into_entry(rank as u32 + 1, player.clone())
+            })
+            .collect()
+    }
+
+    /// Final placements in standings order
+    pub fn placements(&self) -> FixedResult<Vec<String>> {
+        Ok(self.standings()?.into_iter().map(|entry| entry.player_account).collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn players(names: &[&str]) -> Vec<String> {
+        names.iter().map(|name| name.to_string()).collect()
+    }
+
+    fn snapshot(crypto_id: &str, price_usd: u64) -> PriceSnapshot {
+        PriceSnapshot { crypto_id: crypto_id.to_string(), price_usd, timestamp: 0 }
+    }
+
+    fn portfolio(pick: &str) -> TournamentPortfolio {
+        TournamentPortfolio { crypto_picks: vec![pick.to_string()], strategy_notes: None, captain: None, vice_captain: None, short_picks: vec![] }
+    }
+
+    #[test]
+    fn test_every_pair_meets_once() {
+        for size in 2..=7 {
+            let field = players(&["a", "b", "c", "d", "e", "f", "g"][..size]);
+            let schedule = circle_schedule(&field);
+            assert_eq!(schedule.len(), if size % 2 == 0 { size - 1 } else { size });
+
+            let mut pairs: Vec<(String, String)> = schedule.iter()
+                .flatten()
+                .filter_map(|(home, away)| away.as_ref().map(|away| {
+                    if home < away { (home.clone(), away.clone()) } else { (away.clone(), home.clone()) }
+                }))
+                .collect();
+            pairs.sort();
+            pairs.dedup();
+            assert_eq!(pairs.len(), size * (size - 1) / 2);
+        }
+    }
+
+    #[test]
+    fn test_standings_points_and_differential() {
+        let portfolios: BTreeMap<String, TournamentPortfolio> = [
+            ("a".to_string(), portfolio("BTC")),
+            ("b".to_string(), portfolio("ETH")),
+            ("c".to_string(), portfolio("SOL")),
+        ].into_iter().collect();
+        let start = vec![snapshot("BTC", 1000), snapshot("ETH", 100), snapshot("SOL", 100)];
+        let mut league = RoundRobin::new("t".to_string(), players(&["a", "b", "c"]), start);
+        assert_eq!(league.total_rounds(), 3);
+
+        // BTC rises 10% every round, ETH and SOL stay flat: "a" wins both its matches, "b" and "c" draw
+        let mut btc = 1000;
+        let mut rounds_played = 0;
+        while !league.is_finished() {
+            btc = btc * 11 / 10;
+            let end = vec![snapshot("BTC", btc), snapshot("ETH", 100), snapshot("SOL", 100)];
+            league.advance_round(end, &portfolios, 1).unwrap();
+            rounds_played += 1;
+        }
+        assert_eq!(rounds_played, 3);
+
+        let standings = league.standings().unwrap();
+        let table: Vec<(&str, u32, u32, u32, u32)> = standings.iter()
+            .map(|entry| (entry.player_account.as_str(), entry.points, entry.wins, entry.draws, entry.losses))
+            .collect();
+        assert_eq!(table, vec![("a", 6, 2, 0, 0), ("b", 1, 0, 1, 1), ("c", 1, 0, 1, 1)]);
+        assert!(standings[0].return_differential > Fixed::ZERO);
+        assert_eq!(standings[1].return_differential, standings[2].return_differential);
+    }
+
+    #[test]
+    fn test_head_to_head_breaks_tie() {
+        fn set_result(league: &mut RoundRobin, x: &str, y: &str, x_score: i64, y_score: i64) {
+            let league_match = league.rounds.iter_mut()
+                .flat_map(|round| round.matches.iter_mut())
+                .find(|league_match| league_match.is_between(x, y))
+                .unwrap();
+            let (home, away) = if league_match.home == x { (x_score, y_score) } else { (y_score, x_score) };
+            league_match.home_score = Some(Fixed::from_raw(home));
+            league_match.away_score = Some(Fixed::from_raw(away));
+            league_match.result = Some(match home.cmp(&away) {
+                std::cmp::Ordering::Greater => MatchResult::HomeWin,
+                std::cmp::Ordering::Less => MatchResult::AwayWin,
+                std::cmp::Ordering::Equal => MatchResult::Draw,
+            });
+        }
+
+        let mut league = RoundRobin::new("t".to_string(), players(&["a", "b", "c", "d"]), vec![]);
+        // "a" and "b" both finish on 6 points; "a" has the better differential but lost to "b"
+        set_result(&mut league, "a", "b", 0, 1);
+        set_result(&mut league, "a", "c", 50, 0);
+        set_result(&mut league, "a", "d", 50, 0);
+        set_result(&mut league, "b", "c", 0, 1);
+        set_result(&mut league, "b", "d", 1, 0);
+        set_result(&mut league, "c", "d", 0, 0);
+
+        let standings = league.standings().unwrap();
+        assert!(standings[1].return_differential > standings[0].return_differential);
+        assert_eq!(league.placements().unwrap(), vec!["b", "a", "c", "d"]);
+    }
+}
@@ -0,0 +1,206 @@
+/*!
+# Seasons
+
+A season groups every tournament completed within its date range. Each finish earns the
+points listed for its position in the season's points table, and at the end of the season
+a bonus pool is paid out to the top of the cumulative standings.
+*/
+
+use async_graphql::SimpleObject;
+use serde::{Deserialize, Serialize};
+
+use crate::{Fixed, FixedResult, LeaderboardEntry, Rounding};
+
+/// Points awarded for first through tenth place when a season does not set its own table
+pub const DEFAULT_POINTS_TABLE: [u32; 10] = [25, 18, 15, 12, 10, 8, 6, 4, 2, 1];
+
+/// Bonus pool split for the top three of the season when none is given
+pub const DEFAULT_BONUS_SPLIT_PERCENT: [u32; 3] = [50, 30, 20];
+
+/// A season of tournaments
+#[derive(Debug, Clone, Serialize, Deserialize, SimpleObject)]
+pub struct Season {
+    pub id: String,
+    pub name: String,
+    /// First moment (microseconds) a tournament completion counts towards the season
+    pub starts_at: u64,
+    /// End of the season (microseconds, exclusive)
+    pub ends_at: u64,
+    /// Points per finishing position, first place first; positions past the end earn nothing
+    pub points_table: Vec<u32>,
+    /// USDC paid out to the top finishers when the season ends
+    pub bonus_pool_usdc: u64,
+    /// Share of the bonus pool per season position, in percent
+    pub bonus_split_percent: Vec<u32>,
+    /// Tournaments that have counted towards the season, in completion order
+    pub tournament_ids: Vec<String>,
+    /// Number of players holding season points
+    pub player_count: u32,
+    /// Set once the season has ended and its bonus pool has been paid
+    pub finalized: bool,
+}
+
+/// A player's accumulated season results
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize, SimpleObject)]
+pub struct SeasonRecord {
+    pub player_account: String,
+    pub points: u32,
+    pub tournaments_played: u32,
+    pub wins: u32,
+}
+
+/// One row of the season standings
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, SimpleObject)]
+pub struct SeasonStanding {
+    pub rank: u32,
+    pub player_account: String,
+    pub points: u32,
+    pub tournaments_played: u32,
+    pub wins: u32,
+}
+
+/// End-of-season bonus paid to one player
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, SimpleObject)]
+pub struct SeasonBonus {
+    pub rank: u32,
+    pub player_account: String,
+    pub bonus_usdc: u64,
+}
+
+impl Season {
+    /// Check whether a tournament completed at `timestamp` counts towards this season
+    pub fn contains(&self, timestamp: u64) -> bool {
+        self.starts_at <= timestamp && timestamp < self.ends_at
+    }
+
+    /// Points for finishing at `rank` (1-based)
+    pub fn points_for(&self, rank: u32) -> u32 {
+        rank.checked_sub(1)
+            .and_then(|index| self.points_table.get(index as usize))
+            .copied()
+            .unwrap_or(0)
+    }
+
+    /// Bonus payouts for the final `standings`
+    pub fn bonus_payouts(&self, standings: &[SeasonStanding]) -> FixedResult<Vec<SeasonBonus>> {
+        let mut payouts = Vec::new();
+        for (standing, percent) in standings.iter().zip(&self.bonus_split_percent) {
+            payouts.push(SeasonBonus {
+                rank: standing.rank,
+                player_account: standing.player_account.clone(),
+                bonus_usdc: Fixed::from_percent(*percent).apply_to_amount(self.bonus_pool_usdc, Rounding::Down)?,
+            });
+        }
+        Ok(payouts)
+    }
+}
+
+impl SeasonRecord {
+    /// Start a record for a player's first season result
+    pub fn new(player_account: String) -> Self {
+        Self {
+            player_account,
+            ..Self::default()
+        }
+    }
+
+    /// Add one tournament finish to the record
+    pub fn add_finish(&mut self, entry: &LeaderboardEntry, points: u32) {
+        self.points = self.points.saturating_add(points);
+        self.tournaments_played += 1;
+        if entry.rank == 1 {
+            self.wins += 1;
+        }
+    }
+}
+
+/// Rank season records: points, then wins, then fewer tournaments played, then account
+pub fn season_standings(mut records: Vec<SeasonRecord>) -> Vec<SeasonStanding> {
+    records.sort_by(|a, b| {
+        b.points
+            .cmp(&a.points)
+            .then(b.wins.cmp(&a.wins))
+            .then(a.tournaments_played.cmp(&b.tournaments_played))
+            .then(a.player_account.cmp(&b.player_account))
+    });
+
+    records
+        .into_iter()
+        .enumerate()
+        .map(|(index, record)| SeasonStanding {
+            rank: index as u32 + 1,
+            player_account: record.player_account,
+            points: record.points,
+            tournaments_played: record.tournaments_played,
+            wins: record.wins,
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn season() -> Season {
+        Season {
+            id: "season-1".to_string(),
+            name: "Test season".to_string(),
+            starts_at: 100,
+            ends_at: 200,
+            points_table: vec![10, 6, 3],
+            bonus_pool_usdc: 1_000,
+            bonus_split_percent: DEFAULT_BONUS_SPLIT_PERCENT.to_vec(),
+            tournament_ids: vec![],
+            player_count: 0,
+            finalized: false,
+        }
+    }
+
+    fn finish(rank: u32, player: &str) -> LeaderboardEntry {
+        LeaderboardEntry {
+            rank,
+            player_account: player.to_string(),
+            total_return: Fixed::ZERO,
+            winning_amount: 0,
+        }
+    }
+
+    #[test]
+    fn test_season_accrual_and_bonus() {
+        let season = season();
+        assert!(season.contains(100));
+        assert!(!season.contains(200));
+        assert_eq!(season.points_for(1), 10);
+        assert_eq!(season.points_for(4), 0);
+        assert_eq!(season.points_for(0), 0);
+
+        // Two tournaments: a, b, c then b, a, d
+        let mut records = vec![
+            SeasonRecord::new("a".to_string()),
+            SeasonRecord::new("b".to_string()),
+            SeasonRecord::new("c".to_string()),
+            SeasonRecord::new("d".to_string()),
+        ];
+        for (index, player) in ["a", "b", "c"].iter().enumerate() {
+            let entry = finish(index as u32 + 1, player);
+            let record = records.iter_mut().find(|record| record.player_account == *player).unwrap();
+            record.add_finish(&entry, season.points_for(entry.rank));
+        }
+        for (index, player) in ["b", "a", "d"].iter().enumerate() {
+            let entry = finish(index as u32 + 1, player);
+            let record = records.iter_mut().find(|record| record.player_account == *player).unwrap();
+            record.add_finish(&entry, season.points_for(entry.rank));
+        }
+
+        // a and b tie on 16 points and one win each; c (one event) edges d on account order
+        let standings = season_standings(records);
+        let summary: Vec<(&str, u32)> = standings
+            .iter()
+            .map(|standing| (standing.player_account.as_str(), standing.points))
+            .collect();
+        assert_eq!(summary, vec![("a", 16), ("b", 16), ("c", 3), ("d", 3)]);
+
+        let payouts: Vec<u64> = season.bonus_payouts(&standings).unwrap().iter().map(|bonus| bonus.bonus_usdc).collect();
+        assert_eq!(payouts, vec![500, 300, 200]);
+    }
+}
@@ -1,26 +1,31 @@
 /*!
 # Traditional Leagues Service
 
-Provides GraphQL interface for traditional league tournament management.
+Provides GraphQL interface for traditional league tournament management, with
+subscriptions that report tournament status changes, portfolio submissions and
+leaderboard changes.
 */
 
 #![cfg_attr(target_arch = "wasm32", no_main)]
 
-mod state;
-
 use std::sync::Arc;
 
-use async_graphql::{Context, EmptySubscription, Object, Request, Response, Schema};
+use async_graphql::futures_util::stream::{self, Stream};
+use async_graphql::{Context, Object, Request, Response, Schema, SimpleObject, Subscription};
 use traditional_leagues::{
-    TraditionalLeaguesAbi, Tournament, TournamentStatus, TournamentType,
-    TournamentPortfolio, LeaderboardEntry, ScoringEngine, PriceData,
+    TraditionalLeaguesAbi, TraditionalLeaguesParameters, Bracket, CryptoCategory, LeagueMatch, PlayerLives, RoundRobin, StandingsEntry, SwissStandingsEntry, Tournament, TournamentOptions, TournamentStatus, TournamentType,
+    TournamentPortfolio, LeaderboardEntry, Registration, RegistrationStatus, ScoringEngine, PriceData,
+    page_slots,
+    Season, SeasonBonus, SeasonStanding, season_standings,
+    Team, TeamLeaderboardEntry, apply_team_payouts, team_leaderboard,
+    DeliveryStatus, MessageRecord, execute,
 };
 use linera_sdk::{
     linera_base_types::WithServiceAbi,
     views::View,
     Service, ServiceRuntime,
 };
-use state::TraditionalLeaguesState;
+use traditional_leagues::state::TraditionalLeaguesState;
 
 pub struct TraditionalLeaguesService {
     state: Arc<TraditionalLeaguesState>,
@@ -34,7 +39,7 @@ impl WithServiceAbi for TraditionalLeaguesService {
 }
 
 impl Service for TraditionalLeaguesService {
-    type Parameters = ();
+    type Parameters = TraditionalLeaguesParameters;
 
     async fn new(runtime: ServiceRuntime<Self>) -> Self {
         let state = TraditionalLeaguesState::load(runtime.root_view_storage_context())
@@ -54,10 +59,14 @@ impl Service for TraditionalLeaguesService {
             MutationRoot {
                 runtime: self.runtime.clone(),
             },
-            EmptySubscription,
+            SubscriptionRoot {
+                queries: QueryRoot {
+                    state: self.state.clone(),
+                },
+            },
         )
         .finish();
-        schema.execute(request).await
+        execute(&schema, request).await
     }
 }
 
@@ -143,11 +152,15 @@ impl QueryRoot {
         tournaments
     }
 
-    /// Get tournament participants
-    async fn tournament_participants(&self, tournament_id: String) -> Vec<String> {
-        match self.state.participants.get(&tournament_id).await {
-            Ok(Some(participants)) => participants,
-            Ok(None) => vec![],
+    /// Get a page of tournament participants in registration slot order
+    async fn tournament_participants(&self, tournament_id: String, offset: Option<u32>, limit: Option<u32>) -> Vec<String> {
+        let count = match self.state.tournaments.get(&tournament_id).await {
+            Ok(Some(tournament)) => tournament.current_participants,
+            _ => return vec![],
+        };
+
+        match self.state.participant_page(&tournament_id, page_slots(offset, limit, count)).await {
+            Ok(participants) => participants,
             Err(e) => {
                 log::error!("Failed to get participants for {}: {}", tournament_id, e);
                 vec![]
@@ -155,6 +168,28 @@ impl QueryRoot {
         }
     }
 
+    /// Get where a player's registration for a tournament stands
+    async fn registration(&self, tournament_id: String, player_account: String) -> Registration {
+        let key = (tournament_id.clone(), player_account.clone());
+        let rejection_reason = self.state.registration_rejections.get(&key).await.ok().flatten();
+        let status = if self.state.participants.contains_key(&key).await.unwrap_or(false) {
+            RegistrationStatus::Registered
+        } else if self.state.pending_registrations.contains_key(&key).await.unwrap_or(false) {
+            RegistrationStatus::Pending
+        } else if rejection_reason.is_some() {
+            RegistrationStatus::Rejected
+        } else {
+            RegistrationStatus::NotRegistered
+        };
+
+        Registration {
+            tournament_id,
+            player_account,
+            status,
+            rejection_reason,
+        }
+    }
+
     /// Get tournament results/winners
     async fn tournament_results(&self, tournament_id: String) -> Vec<String> {
         match self.state.results.get(&tournament_id).await {
@@ -167,6 +202,125 @@ impl QueryRoot {
         }
     }
 
+    /// Get the elimination bracket for a tournament, round by round
+    async fn tournament_bracket(&self, tournament_id: String) -> Option<Bracket> {
+        match self.state.brackets.get(&tournament_id).await {
+            Ok(bracket) => bracket,
+            Err(e) => {
+                log::error!("Failed to get bracket for {}: {}", tournament_id, e);
+                None
+            }
+        }
+    }
+
+    /// Get remaining lives of every player in an elimination bracket
+    async fn tournament_lives(&self, tournament_id: String) -> Vec<PlayerLives> {
+        match self.state.brackets.get(&tournament_id).await {
+            Ok(Some(bracket)) => bracket.lives(),
+            Ok(None) => vec![],
+            Err(e) => {
+                log::error!("Failed to get bracket for {}: {}", tournament_id, e);
+                vec![]
+            }
+        }
+    }
+
+    /// Get the round-robin schedule for a tournament, round by round
+    async fn tournament_schedule(&self, tournament_id: String) -> Option<RoundRobin> {
+        match self.state.leagues.get(&tournament_id).await {
+            Ok(league) => league,
+            Err(e) => {
+                log::error!("Failed to get schedule for {}: {}", tournament_id, e);
+                None
+            }
+        }
+    }
+
+    /// Get the round-robin standings table: points, then head-to-head, then return differential
+    async fn tournament_standings(&self, tournament_id: String) -> Vec<StandingsEntry> {
+        let league = match self.state.leagues.get(&tournament_id).await {
+            Ok(Some(league)) => league,
+            Ok(None) => return vec![],
+            Err(e) => {
+                log::error!("Failed to get schedule for {}: {}", tournament_id, e);
+                return vec![];
+            }
+        };
+        match league.standings() {
+            Ok(standings) => standings,
+            Err(e) => {
+                log::error!("Failed to calculate standings for {}: {}", tournament_id, e);
+                vec![]
+            }
+        }
+    }
+
+    /// Get the Swiss pairings for a round (the current round if omitted).
+    /// The next round is paired as soon as the previous one is scored.
+    async fn tournament_pairings(&self, tournament_id: String, round: Option<u32>) -> Vec<LeagueMatch> {
+        match self.state.swiss.get(&tournament_id).await {
+            Ok(Some(swiss)) => {
+                let round = round
+                    .or_else(|| swiss.current_round().map(|current| current.round))
+                    .unwrap_or(1);
+                swiss.pairings(round)
+            }
+            Ok(None) => vec![],
+            Err(e) => {
+                log::error!("Failed to get Swiss pairings for {}: {}", tournament_id, e);
+                vec![]
+            }
+        }
+    }
+
+    /// Get the Swiss standings: points, then Buchholz, then Sonneborn-Berger
+    async fn tournament_swiss_standings(&self, tournament_id: String) -> Vec<SwissStandingsEntry> {
+        let swiss = match self.state.swiss.get(&tournament_id).await {
+            Ok(Some(swiss)) => swiss,
+            Ok(None) => return vec![],
+            Err(e) => {
+                log::error!("Failed to get Swiss pairings for {}: {}", tournament_id, e);
+                return vec![];
+            }
+        };
+        match swiss.standings() {
+            Ok(standings) => standings,
+            Err(e) => {
+                log::error!("Failed to calculate Swiss standings for {}: {}", tournament_id, e);
+                vec![]
+            }
+        }
+    }
+
+    /// List the asset IDs players may pick in a tournament
+    async fn eligible_assets(&self, tournament_id: String) -> Vec<String> {
+        match self.state.tournaments.get(&tournament_id).await {
+            Ok(Some(tournament)) => tournament.category.assets().into_iter().map(String::from).collect(),
+            Ok(None) => vec![],
+            Err(e) => {
+                log::error!("Failed to get tournament {}: {}", tournament_id, e);
+                vec![]
+            }
+        }
+    }
+
+    /// List the asset IDs in a crypto category
+    async fn category_assets(&self, category: CryptoCategory) -> Vec<String> {
+        category.assets().into_iter().map(String::from).collect()
+    }
+
+    /// Get the entry fee refunded to a player who withdrew from a tournament
+    async fn entry_fee_refund(&self, tournament_id: String, player_account: String) -> u64 {
+        let refund_key = format!("{}-{}", tournament_id, player_account);
+        match self.state.refunds.get(&refund_key).await {
+            Ok(refund) => refund.unwrap_or(0),
+            Err(e) => {
+                log::error!("Failed to get refund {}: {}", refund_key, e);
+                0
+            }
+        }
+    }
+
     /// Get player's portfolio for a tournament
     async fn player_portfolio(&self, tournament_id: String, player_account: String) -> Option<TournamentPortfolio> {
         let portfolio_key = format!("{}-{}", tournament_id, player_account);
@@ -179,83 +333,316 @@ impl QueryRoot {
         }
     }
 
-    /// Get tournament leaderboard (calculates live rankings)
+    /// Get tournament leaderboard. Completed tournaments return the leaderboard stored
+    /// at settlement; others calculate live rankings.
     async fn tournament_leaderboard(&self, tournament_id: String) -> Vec<LeaderboardEntry> {
-        // Get tournament
+        self.leaderboard(&tournament_id).await
+    }
+
+    /// Get a team tournament's team leaderboard. Completed tournaments return the one
+    /// stored at settlement; others calculate live rankings.
+    async fn team_leaderboard(&self, tournament_id: String) -> Vec<TeamLeaderboardEntry> {
         let tournament = match self.state.tournaments.get(&tournament_id).await {
+            Ok(Some(t)) if t.is_team_tournament() => t,
+            _ => return vec![],
+        };
+
+        if tournament.status == TournamentStatus::Completed {
+            return self.state.team_leaderboards.get(&tournament_id)
+                .await
+                .unwrap_or_default()
+                .unwrap_or_default();
+        }
+
+        let Some((leaderboard, total_prize_pool)) = self.live_leaderboard(&tournament).await else {
+            return vec![];
+        };
+        self.live_team_leaderboard(&tournament, &leaderboard, total_prize_pool)
+            .await
+            .unwrap_or_default()
+    }
+
+    /// Get the teams of a tournament in creation order
+    async fn tournament_teams(&self, tournament_id: String) -> Vec<Team> {
+        match self.state.tournament_teams(&tournament_id).await {
+            Ok(teams) => teams,
+            Err(e) => {
+                log::error!("{}", e);
+                vec![]
+            }
+        }
+    }
+
+    /// Get a team by ID
+    async fn team(&self, team_id: String) -> Option<Team> {
+        self.state.teams.get(&team_id).await.unwrap_or_default()
+    }
+
+    /// Get the teams in a tournament that have invited a player
+    async fn team_invitations(&self, tournament_id: String, player_account: String) -> Vec<Team> {
+        self.state.tournament_teams(&tournament_id)
+            .await
+            .unwrap_or_default()
+            .into_iter()
+            .filter(|team| team.invitations.contains(&player_account))
+            .collect()
+    }
+
+    /// Get all seasons
+    async fn seasons(&self) -> Vec<Season> {
+        let mut seasons = Vec::new();
+        if let Err(e) = self.state.seasons.for_each_index_value(|_season_id, season| {
+            seasons.push(season.into_owned());
+            Ok(())
+        }).await {
+            log::error!("Failed to get seasons: {}", e);
+        }
+        seasons
+    }
+
+    /// Get season by ID
+    async fn season(&self, season_id: String) -> Option<Season> {
+        match self.state.seasons.get(&season_id).await {
+            Ok(season) => season,
+            Err(e) => {
+                log::error!("Failed to get season {}: {}", season_id, e);
+                None
+            }
+        }
+    }
+
+    /// Get a page of cumulative season standings, best first
+    async fn season_standings(&self, season_id: String, offset: Option<u32>, limit: Option<u32>) -> Vec<SeasonStanding> {
+        let season = match self.state.seasons.get(&season_id).await {
+            Ok(Some(season)) => season,
+            _ => return vec![],
+        };
+
+        let records = match self.state.season_records(&season).await {
+            Ok(records) => records,
+            Err(e) => {
+                log::error!("{}", e);
+                return vec![];
+            }
+        };
+
+        let standings = season_standings(records);
+        let slots = page_slots(offset, limit, standings.len() as u32);
+        standings
+            .into_iter()
+            .filter(|standing| slots.contains(&standing.rank))
+            .collect()
+    }
+
+    /// Get the end-of-season bonus payouts (empty until the season ends)
+    async fn season_bonuses(&self, season_id: String) -> Vec<SeasonBonus> {
+        self.state.season_bonuses.get(&season_id)
+            .await
+            .unwrap_or_default()
+            .unwrap_or_default()
+    }
+
+    /// Get the delivery log of notifications sent to CoinDrafts Core, optionally by status
+    async fn outbox(&self, status: Option<DeliveryStatus>) -> Vec<MessageRecord> {
+        let mut records = Vec::new();
+        self.state.outbox.for_each_index_value(|_message_id, record| {
+            if status.is_none_or(|status| record.status == status) {
+                records.push(record.into_owned());
+            }
+            Ok(())
+        }).await.unwrap_or_default();
+        records
+    }
+
+    /// Get the log of requests received from CoinDrafts Core, optionally by status
+    async fn inbox(&self, status: Option<DeliveryStatus>) -> Vec<MessageRecord> {
+        let mut records = Vec::new();
+        self.state.inbox.for_each_index_value(|_message_id, record| {
+            if status.is_none_or(|status| record.status == status) {
+                records.push(record.into_owned());
+            }
+            Ok(())
+        }).await.unwrap_or_default();
+        records
+    }
+}
+
+impl QueryRoot {
+    /// Leaderboard stored at settlement, or live rankings while the tournament runs
+    async fn leaderboard(&self, tournament_id: &str) -> Vec<LeaderboardEntry> {
+        // Get tournament
+        let tournament = match self.state.tournaments.get(tournament_id).await {
             Ok(Some(t)) => t,
             _ => return vec![],
         };
 
+        if tournament.status == TournamentStatus::Completed {
+            match self.state.leaderboards.get(tournament_id).await {
+                Ok(Some(leaderboard)) => return leaderboard,
+                Ok(None) => log::warn!("Tournament {} completed without a stored leaderboard", tournament_id),
+                Err(e) => {
+                    log::error!("Failed to get leaderboard for {}: {}", tournament_id, e);
+                    return vec![];
+                }
+            }
+        }
+
+        let Some((mut leaderboard, total_prize_pool)) = self.live_leaderboard(&tournament).await else {
+            return vec![];
+        };
+        if tournament.is_team_tournament() {
+            if let Some(team_entries) = self.live_team_leaderboard(&tournament, &leaderboard, total_prize_pool).await {
+                apply_team_payouts(&mut leaderboard, &team_entries);
+            }
+        }
+        leaderboard
+    }
+
+    /// Live individual leaderboard and prize pool of a tournament
+    async fn live_leaderboard(&self, tournament: &Tournament) -> Option<(Vec<LeaderboardEntry>, u64)> {
         // Get participants
-        let participants = match self.state.participants.get(&tournament_id).await {
-            Ok(Some(p)) => p,
-            _ => return vec![],
+        let participants = match self.state.all_participants(&tournament.id, tournament.current_participants).await {
+            Ok(p) => p,
+            _ => return None,
         };
 
         // Collect portfolios
         let mut portfolios = Vec::new();
         for participant in participants {
-            let portfolio_key = format!("{}-{}", tournament_id, participant);
+            let portfolio_key = format!("{}-{}", tournament.id, participant);
             if let Ok(Some(portfolio)) = self.state.portfolios.get(&portfolio_key).await {
                 portfolios.push((participant, portfolio));
             }
         }
 
         if portfolios.is_empty() {
-            return vec![];
+            return None;
         }
 
         // Get price data
-        let price_data = match (&tournament.start_prices, &tournament.end_prices) {
-            (Some(start), Some(end)) => {
-                // Calculate tournament duration in minutes
-                let duration_minutes = if let (Some(started), Some(completed)) = (tournament.started_at, tournament.completed_at) {
-                    ((completed - started) / 1_000_000) / 60 // Convert microseconds to minutes
-                } else {
-                    u64::MAX // Unknown duration, don't amplify
-                };
-                
-                // Amplify returns 100x for short tournaments (< 30 minutes) for demo purposes
-                let amplification_factor = if duration_minutes < 30 { 100 } else { 1 };
-                
-                let mut prices = Vec::new();
-                for start_snap in start {
-                    if let Some(end_snap) = end.iter().find(|e| e.crypto_id == start_snap.crypto_id) {
-                        let percentage_change = if start_snap.price_usd > 0 {
-                            let change = (end_snap.price_usd as i64) - (start_snap.price_usd as i64);
-                            let base_change = ((change * 10000) / (start_snap.price_usd as i64)) as i32;
-                            base_change * amplification_factor
-                        } else {
-                            0
-                        };
-                        
-                        prices.push(PriceData {
-                            symbol: start_snap.crypto_id.clone(),
-                            start_price: start_snap.price_usd,
-                            end_price: end_snap.price_usd,
-                            percentage_change,
-                        });
-                    }
-                }
-                prices
-            },
-            _ => PriceData::get_mock_prices(),
+        let price_data = match tournament.price_data() {
+            Ok(Some(prices)) => prices,
+            Ok(None) => PriceData::get_mock_prices(),
+            Err(e) => {
+                log::error!("Tournament {} price data could not be computed: {}", tournament.id, e);
+                return None;
+            }
         };
 
-        let total_prize_pool = tournament.entry_fee_usdc * tournament.current_participants as u64;
+        let total_prize_pool = match tournament.entry_fee_usdc.checked_mul(tournament.current_participants as u64) {
+            Some(pool) => pool,
+            None => {
+                log::error!("Tournament {} prize pool overflowed", tournament.id);
+                return None;
+            }
+        };
         
-        ScoringEngine::calculate_leaderboard(portfolios, &price_data, total_prize_pool)
+        match ScoringEngine::calculate_leaderboard(portfolios, &price_data, total_prize_pool) {
+            Ok(leaderboard) => Some((leaderboard, total_prize_pool)),
+            Err(e) => {
+                log::error!("Tournament {} scoring failed: {}", tournament.id, e);
+                None
+            }
+        }
+    }
+
+    /// Live team leaderboard computed from the individual `leaderboard`
+    async fn live_team_leaderboard(
+        &self,
+        tournament: &Tournament,
+        leaderboard: &[LeaderboardEntry],
+        total_prize_pool: u64,
+    ) -> Option<Vec<TeamLeaderboardEntry>> {
+        let teams = self.state.tournament_teams(&tournament.id).await.ok()?;
+        match team_leaderboard(&teams, leaderboard, tournament.team_scoring, total_prize_pool) {
+            Ok(team_entries) => Some(team_entries),
+            Err(e) => {
+                log::error!("Tournament {} team scoring failed: {}", tournament.id, e);
+                None
+            }
+        }
     }
 }
 
+struct SubscriptionRoot {
+    queries: QueryRoot,
+}
+
+#[Subscription]
+impl SubscriptionRoot {
+    /// Reports each status the tournament moved to after `known_revision`, oldest first
+    async fn tournament_status_changed(&self, tournament_id: String, known_revision: Option<u32>) -> impl Stream<Item = TournamentStatusChange> {
+        let state = &self.queries.state;
+        let revision = state.tournament_status_revisions.get(&tournament_id).await.ok().flatten().unwrap_or(0);
+        let mut changes = Vec::new();
+        for revision in known_revision.unwrap_or(0) + 1..=revision {
+            if let Ok(Some(status)) = state.tournament_status_changes.get(&(tournament_id.clone(), revision)).await {
+                changes.push(TournamentStatusChange { revision, status });
+            }
+        }
+        stream::iter(changes)
+    }
+
+    /// Reports each portfolio submitted, replaced or withdrawn after `known_revision`,
+    /// oldest first. `portfolio` is the player's current portfolio, null once withdrawn.
+    async fn portfolio_submitted(&self, tournament_id: String, known_revision: Option<u32>) -> impl Stream<Item = PortfolioChange> {
+        let state = &self.queries.state;
+        let revision = state.portfolio_revisions.get(&tournament_id).await.ok().flatten().unwrap_or(0);
+        let mut changes = Vec::new();
+        for revision in known_revision.unwrap_or(0) + 1..=revision {
+            let Ok(Some(player_account)) = state.portfolio_changes.get(&(tournament_id.clone(), revision)).await else {
+                continue;
+            };
+            let portfolio_key = format!("{}-{}", tournament_id, player_account);
+            let portfolio = state.portfolios.get(&portfolio_key).await.ok().flatten();
+            changes.push(PortfolioChange { revision, player_account, portfolio });
+        }
+        stream::iter(changes)
+    }
+
+    /// Reports the tournament leaderboard when the tournament, its portfolios or its teams
+    /// changed after `known_revision`
+    async fn tournament_leaderboard_changed(&self, tournament_id: String, known_revision: Option<u32>) -> impl Stream<Item = TournamentLeaderboardUpdate> {
+        let revision = self.queries.state.leaderboard_revisions.get(&tournament_id).await.ok().flatten().unwrap_or(0);
+        let update = if known_revision.is_none_or(|known_revision| known_revision < revision) {
+            let entries = self.queries.leaderboard(&tournament_id).await;
+            Some(TournamentLeaderboardUpdate { revision, entries })
+        } else {
+            None
+        };
+        stream::iter(update)
+    }
+}
+
+/// Tournament status change with the revision to pass back as `knownRevision`
+#[derive(SimpleObject)]
+struct TournamentStatusChange {
+    revision: u32,
+    status: TournamentStatus,
+}
+
+/// Portfolio change with the revision to pass back as `knownRevision`
+#[derive(SimpleObject)]
+struct PortfolioChange {
+    revision: u32,
+    player_account: String,
+    portfolio: Option<TournamentPortfolio>,
+}
+
+/// Tournament leaderboard with the revision to pass back as `knownRevision`
+#[derive(SimpleObject)]
+struct TournamentLeaderboardUpdate {
+    revision: u32,
+    entries: Vec<LeaderboardEntry>,
+}
+
 struct MutationRoot {
     runtime: Arc<ServiceRuntime<TraditionalLeaguesService>>,
 }
 
 #[Object]
 impl MutationRoot {
-    /// Create a new tournament (requires authentication)
+    /// Create a new tournament (requires authentication); omitted options take their defaults
     async fn create_tournament(
         &self,
         _context: &Context<'_>,
@@ -263,7 +650,7 @@ impl MutationRoot {
         entry_fee_usdc: String, // String to handle large numbers in GraphQL
         max_participants: i32,
         tournament_type: TournamentType,
-        category: Option<String>,
+        options: Option<TournamentOptions>,
     ) -> String {
         // Parse entry fee
         let entry_fee = match entry_fee_usdc.parse::<u64>() {
@@ -276,14 +663,14 @@ impl MutationRoot {
             entry_fee_usdc: entry_fee,
             max_participants: max_participants as u32,
             tournament_type,
-            category: category.unwrap_or_else(|| "ALL_CATEGORIES".to_string()),
+            options: options.unwrap_or_default(),
         };
 
         self.runtime.schedule_operation(&operation);
         // For now, return a success message since schedule_operation returns ()
         format!("Tournament creation scheduled")
     }
-
+    /// Register the signer for a tournament; `player_account` must be the signer's account
     /// Register for a tournament
     async fn register_for_tournament(
         &self,
@@ -300,6 +687,112 @@ impl MutationRoot {
         format!("Registration scheduled for tournament {}", tournament_id)
     }
 
+    /// Withdraw the signer from a tournament while registration is open
+    async fn unregister_from_tournament(
+        &self,
+        _context: &Context<'_>,
+        tournament_id: String,
+    ) -> String {
+        let operation = traditional_leagues::TraditionalLeaguesOperation::UnregisterFromTournament {
+            tournament_id: tournament_id.clone(),
+        };
+
+        self.runtime.schedule_operation(&operation);
+        format!("Withdrawal scheduled for tournament {}", tournament_id)
+    }
+
+    /// Create a team in a team tournament, captained by the signer
+    async fn create_team(
+        &self,
+        _context: &Context<'_>,
+        tournament_id: String,
+        name: String,
+    ) -> String {
+        let operation = traditional_leagues::TraditionalLeaguesOperation::CreateTeam {
+            tournament_id: tournament_id.clone(),
+            name,
+        };
+
+        self.runtime.schedule_operation(&operation);
+        format!("Team creation scheduled for tournament {}", tournament_id)
+    }
+
+    /// Invite a player to a team (captain only)
+    async fn invite_to_team(
+        &self,
+        _context: &Context<'_>,
+        team_id: String,
+        player_account: String,
+    ) -> String {
+        let operation = traditional_leagues::TraditionalLeaguesOperation::InviteToTeam {
+            team_id: team_id.clone(),
+            player_account,
+        };
+
+        self.runtime.schedule_operation(&operation);
+        format!("Invitation scheduled for team {}", team_id)
+    }
+
+    /// Accept the signer's invitation to join a team
+    async fn accept_team_invitation(&self, _context: &Context<'_>, team_id: String) -> String {
+        let operation = traditional_leagues::TraditionalLeaguesOperation::AcceptTeamInvitation {
+            team_id: team_id.clone(),
+        };
+
+        self.runtime.schedule_operation(&operation);
+        format!("Joining team {} scheduled", team_id)
+    }
+
+    /// Create a season (timestamps in microseconds)
+    async fn create_season(
+        &self,
+        _context: &Context<'_>,
+        name: String,
+        starts_at: String, // String to handle large numbers in GraphQL
+        ends_at: String,
+        points_table: Option<Vec<u32>>,
+        bonus_pool_usdc: String,
+        bonus_split_percent: Option<Vec<u32>>,
+    ) -> String {
+        let (Ok(starts_at), Ok(ends_at), Ok(bonus_pool_usdc)) =
+            (starts_at.parse::<u64>(), ends_at.parse::<u64>(), bonus_pool_usdc.parse::<u64>())
+        else {
+            return "Invalid season timestamp or bonus pool format".to_string();
+        };
+
+        let operation = traditional_leagues::TraditionalLeaguesOperation::CreateSeason {
+            name,
+            starts_at,
+            ends_at,
+            points_table: points_table.unwrap_or_default(),
+            bonus_pool_usdc,
+            bonus_split_percent: bonus_split_percent.unwrap_or_default(),
+        };
+
+        self.runtime.schedule_operation(&operation);
+        "Season creation scheduled".to_string()
+    }
+
+    /// End a season and pay its bonus pool
+    async fn end_season(&self, _context: &Context<'_>, season_id: String) -> String {
+        let operation = traditional_leagues::TraditionalLeaguesOperation::EndSeason {
+            season_id: season_id.clone(),
+        };
+
+        self.runtime.schedule_operation(&operation);
+        format!("End of season {} scheduled", season_id)
+    }
+
+    /// Resend a notification CoinDrafts Core failed to apply (application creator only)
+    async fn retry_message(&self, _context: &Context<'_>, message_id: String) -> String {
+        let operation = traditional_leagues::TraditionalLeaguesOperation::RetryMessage {
+            message_id: message_id.clone(),
+        };
+
+        self.runtime.schedule_operation(&operation);
+        format!("Retry of message {} scheduled", message_id)
+    }
+
     /// Submit portfolio for a tournament
     async fn submit_portfolio(
         &self,
@@ -307,10 +800,16 @@ impl MutationRoot {
         tournament_id: String,
         crypto_picks: Vec<String>,
         strategy_notes: Option<String>,
+        captain: Option<String>,
+        vice_captain: Option<String>,
+        short_picks: Option<Vec<String>>,
     ) -> String {
         let portfolio = traditional_leagues::TournamentPortfolio {
             crypto_picks,
             strategy_notes,
+            captain,
+            vice_captain,
+            short_picks: short_picks.unwrap_or_default(),
         };
 
         let operation = traditional_leagues::TraditionalLeaguesOperation::SubmitPortfolio {
@@ -322,7 +821,7 @@ impl MutationRoot {
         format!("Portfolio submission scheduled for tournament {}", tournament_id)
     }
 
-    /// Submit portfolio for a specific player account (for seeding/testing)
+    /// Submit portfolio for a specific player account (application creator only, for seeding/testing)
     async fn submit_portfolio_for_account(
         &self,
         _context: &Context<'_>,
@@ -330,10 +829,16 @@ impl MutationRoot {
         player_account: String,
         crypto_picks: Vec<String>,
         strategy_notes: Option<String>,
+        captain: Option<String>,
+        vice_captain: Option<String>,
+        short_picks: Option<Vec<String>>,
     ) -> String {
         let portfolio = traditional_leagues::TournamentPortfolio {
             crypto_picks,
             strategy_notes,
+            captain,
+            vice_captain,
+            short_picks: short_picks.unwrap_or_default(),
         };
 
         let operation = traditional_leagues::TraditionalLeaguesOperation::SubmitPortfolioForAccount {
@@ -354,21 +859,7 @@ impl MutationRoot {
         start_prices: Vec<String>, // JSON array of {crypto_id, price_usd}
     ) -> String {
         // Parse price snapshots from string array
-        let mut snapshots = Vec::new();
-        for price_str in start_prices {
-            if let Ok(parts) = serde_json::from_str::<serde_json::Value>(&price_str) {
-                if let (Some(crypto_id), Some(price)) = (
-                    parts.get("crypto_id").and_then(|v| v.as_str()),
-                    parts.get("price_usd").and_then(|v| v.as_u64()),
-                ) {
-                    snapshots.push(traditional_leagues::PriceSnapshot {
-                        crypto_id: crypto_id.to_string(),
-                        price_usd: price,
-                        timestamp: 0, // Will be set by contract
-                    });
-                }
-            }
-        }
+        let snapshots = parse_price_snapshots(start_prices);
 
         let operation = traditional_leagues::TraditionalLeaguesOperation::StartTournament {
             tournament_id: tournament_id.clone(),
@@ -387,21 +878,7 @@ impl MutationRoot {
         end_prices: Vec<String>, // JSON array of {crypto_id, price_usd}
     ) -> String {
         // Parse price snapshots
-        let mut snapshots = Vec::new();
-        for price_str in end_prices {
-            if let Ok(parts) = serde_json::from_str::<serde_json::Value>(&price_str) {
-                if let (Some(crypto_id), Some(price)) = (
-                    parts.get("crypto_id").and_then(|v| v.as_str()),
-                    parts.get("price_usd").and_then(|v| v.as_u64()),
-                ) {
-                    snapshots.push(traditional_leagues::PriceSnapshot {
-                        crypto_id: crypto_id.to_string(),
-                        price_usd: price,
-                        timestamp: 0,
-                    });
-                }
-            }
-        }
+        let snapshots = parse_price_snapshots(end_prices);
 
         let operation = traditional_leagues::TraditionalLeaguesOperation::EndTournament {
             tournament_id: tournament_id.clone(),
@@ -412,17 +889,55 @@ impl MutationRoot {
         format!("Tournament {} end scheduled", tournament_id)
     }
 
-    /// Complete tournament and calculate winners
+    /// Complete an expired tournament at its final prices and calculate winners
     async fn complete_tournament(
         &self,
         _context: &Context<'_>,
         tournament_id: String,
+        end_prices: Vec<String>, // JSON array of {crypto_id, price_usd}
     ) -> String {
         let operation = traditional_leagues::TraditionalLeaguesOperation::CompleteTournament {
             tournament_id: tournament_id.clone(),
+            end_prices: parse_price_snapshots(end_prices),
         };
 
         self.runtime.schedule_operation(&operation);
         format!("Tournament {} completion scheduled", tournament_id)
     }
-}
\ No newline at end of file
+
+    /// Score the current round of a bracket or round-robin tournament with its closing prices
+    async fn advance_round(
+        &self,
+        _context: &Context<'_>,
+        tournament_id: String,
+        end_prices: Vec<String>, // JSON array of {crypto_id, price_usd}
+    ) -> String {
+        let operation = traditional_leagues::TraditionalLeaguesOperation::AdvanceRound {
+            tournament_id: tournament_id.clone(),
+            end_prices: parse_price_snapshots(end_prices),
+        };
+
+        self.runtime.schedule_operation(&operation);
+        format!("Round advance scheduled for tournament {}", tournament_id)
+    }
+}
+
+/// Parse price snapshots from JSON strings of the form {crypto_id, price_usd}
+fn parse_price_snapshots(prices: Vec<String>) -> Vec<traditional_leagues::PriceSnapshot> {
+    let mut snapshots = Vec::new();
+    for price_str in prices {
+        if let Ok(parts) = serde_json::from_str::<serde_json::Value>(&price_str) {
+            if let (Some(crypto_id), Some(price)) = (
+                parts.get("crypto_id").and_then(|v| v.as_str()),
+                parts.get("price_usd").and_then(|v| v.as_u64()),
+            ) {
+                snapshots.push(traditional_leagues::PriceSnapshot {
+                    crypto_id: crypto_id.to_string(),
+                    price_usd: price,
+                    timestamp: 0, // Will be set by contract
+                });
+            }
+        }
+    }
+    snapshots
+}
@@ -5,7 +5,13 @@ Manages the persistent state for tournament operations.
 Uses Linera views for efficient blockchain-native state management.
 */
 
-use traditional_leagues::{Tournament, TournamentPortfolio};
+use std::ops::RangeInclusive;
+
+use crate::{
+    Bracket, LeaderboardEntry, RoundRobin, Season, SeasonBonus, SeasonRecord, Swiss, Team, TeamLeaderboardEntry, Tournament,
+    TournamentPortfolio, TournamentStatus, MessageEnvelope, MessageRecord, TraditionalLeaguesMessage,
+};
+use linera_sdk::linera_base_types::{AccountOwner, ChainId};
 use linera_sdk::views::{linera_views, MapView, RegisterView, RootView, ViewStorageContext};
 
 /// The application state for Traditional Leagues
@@ -14,19 +20,83 @@ use linera_sdk::views::{linera_views, MapView, RegisterView, RootView, ViewStora
 pub struct TraditionalLeaguesState {
     /// All tournaments indexed by tournament_id
     pub tournaments: MapView<String, Tournament>,
-    /// Tournament participants indexed by tournament_id
-    pub participants: MapView<String, Vec<String>>,
-    /// Portfolio submissions indexed by (tournament_id, round, player_account)
+    /// Statuses each tournament moved to per (tournament_id, revision), revisions numbered from 1
+    pub tournament_status_changes: MapView<(String, u32), TournamentStatus>,
+    /// Number of status changes per tournament_id
+    pub tournament_status_revisions: MapView<String, u32>,
+    /// Registration slot of each participant indexed by (tournament_id, player_account).
+    /// The participant count is `Tournament::current_participants`.
+    pub participants: MapView<(String, String), u32>,
+    /// Participants indexed by (tournament_id, slot), with slots numbered from 1
+    pub participant_slots: MapView<(String, u32), String>,
+    /// Portfolio submissions keyed by "tournament_id-player_account"
     pub portfolios: MapView<String, TournamentPortfolio>,
+    /// Player whose portfolio was submitted, replaced or withdrawn per (tournament_id, revision),
+    /// revisions numbered from 1
+    pub portfolio_changes: MapView<(String, u32), String>,
+    /// Number of portfolio changes per tournament_id
+    pub portfolio_revisions: MapView<String, u32>,
+    /// Advanced whenever a tournament, its portfolios or its teams change, indexed by tournament_id
+    pub leaderboard_revisions: MapView<String, u32>,
     /// Tournament results indexed by tournament_id
     pub results: MapView<String, Vec<String>>, // Winners list
+    /// Final leaderboards written once at settlement, indexed by tournament_id
+    pub leaderboards: MapView<String, Vec<LeaderboardEntry>>,
+    /// Elimination brackets indexed by tournament_id
+    pub brackets: MapView<String, Bracket>,
+    /// Round-robin schedules indexed by tournament_id
+    pub leagues: MapView<String, RoundRobin>,
+    /// Swiss pairings and results indexed by tournament_id
+    pub swiss: MapView<String, Swiss>,
+    /// Registrations awaiting core verification indexed by (tournament_id, player_account),
+    /// holding the request time
+    pub pending_registrations: MapView<(String, String), u64>,
+    /// Reason the last registration was rejected indexed by (tournament_id, player_account)
+    pub registration_rejections: MapView<(String, String), String>,
+    /// Entry fees refunded on withdrawal keyed by "tournament_id-player_account"
+    pub refunds: MapView<String, u64>,
     /// Global tournament counter for generating unique IDs
     pub tournament_counter: RegisterView<u64>,
+    /// Teams indexed by team_id
+    pub teams: MapView<String, Team>,
+    /// Team IDs of each tournament in creation order, indexed by tournament_id
+    pub tournament_team_ids: MapView<String, Vec<String>>,
+    /// Team of each team-tournament member indexed by (tournament_id, player_account)
+    pub team_memberships: MapView<(String, String), String>,
+    /// Final team leaderboards written at settlement, indexed by tournament_id
+    pub team_leaderboards: MapView<String, Vec<TeamLeaderboardEntry>>,
+    /// Seasons indexed by season_id
+    pub seasons: MapView<String, Season>,
+    /// Accumulated season results indexed by (season_id, player_account)
+    pub season_records: MapView<(String, String), SeasonRecord>,
+    /// Players holding season points indexed by (season_id, slot), with slots numbered from 1.
+    /// The player count is `Season::player_count`.
+    pub season_players: MapView<(String, u32), String>,
+    /// End-of-season bonus payouts indexed by season_id
+    pub season_bonuses: MapView<String, Vec<SeasonBonus>>,
+    /// Season counter for generating unique IDs
+    pub season_counter: RegisterView<u64>,
+    /// Account that instantiated the application, allowed to retry failed notifications
+    pub admin: RegisterView<Option<AccountOwner>>,
+    /// Counter for generating notification message IDs
+    pub message_counter: RegisterView<u64>,
+    /// Delivery log of notifications sent to core indexed by message_id
+    pub outbox: MapView<String, MessageRecord>,
+    /// Sent notifications kept for retries, indexed by message_id
+    pub outbox_messages: MapView<String, MessageEnvelope<TraditionalLeaguesMessage>>,
+    /// Core requests handled so far indexed by message_id, used to drop duplicates
+    pub inbox: MapView<String, MessageRecord>,
 }
 
 impl TraditionalLeaguesState {
+    /// Generate a message ID unique across chains
+    pub fn generate_message_id(&mut self, chain_id: ChainId) -> String {
+        let counter = *self.message_counter.get() + 1;
+        self.message_counter.set(counter);
+        format!("{}-{}", chain_id, counter)
+    }
+
     /// Generate a new tournament ID
-    #[allow(dead_code)]
     pub async fn generate_tournament_id(&mut self) -> String {
         let counter = self.tournament_counter.get();
         let tournament_id = format!("trad-league-{}", counter);
@@ -34,33 +104,182 @@ impl TraditionalLeaguesState {
         tournament_id
     }
 
-    /// Add participant to tournament
-    #[allow(dead_code)]
-    pub async fn add_participant(&mut self, tournament_id: &str, player_account: String) -> Result<(), String> {
-        let mut participants = self.participants.get(tournament_id).await
-            .map_err(|e| format!("Failed to get participants: {}", e))?
+    /// Check if a player is registered for a tournament
+    pub async fn is_participant(&self, tournament_id: &str, player_account: &str) -> Result<bool, String> {
+        let key = (tournament_id.to_string(), player_account.to_string());
+        self.participants.contains_key(&key).await
+            .map_err(|e| format!("Failed to get participant: {}", e))
+    }
+
+    /// Add participant to tournament in the slot after the current `count`.
+    /// Returns false if the player was already registered.
+    pub async fn add_participant(&mut self, tournament_id: &str, player_account: &str, count: u32) -> Result<bool, String> {
+        if self.is_participant(tournament_id, player_account).await? {
+            return Ok(false);
+        }
+
+        let slot = count + 1;
+        self.participants.insert(&(tournament_id.to_string(), player_account.to_string()), slot)
+            .map_err(|e| format!("Failed to update participants: {}", e))?;
+        self.participant_slots.insert(&(tournament_id.to_string(), slot), player_account.to_string())
+            .map_err(|e| format!("Failed to update participants: {}", e))?;
+        Ok(true)
+    }
+
+    /// Remove participant from a tournament holding `count` participants. The last
+    /// participant moves into the freed slot so slots stay contiguous.
+    /// Returns false if the player was not registered.
+    pub async fn remove_participant(&mut self, tournament_id: &str, player_account: &str, count: u32) -> Result<bool, String> {
+        let key = (tournament_id.to_string(), player_account.to_string());
+        let Some(slot) = self.participants.get(&key).await
+            .map_err(|e| format!("Failed to get participant: {}", e))?
+        else {
+            return Ok(false);
+        };
+
+        let last_key = (tournament_id.to_string(), count);
+        if slot != count {
+            if let Some(last_player) = self.participant_slots.get(&last_key).await
+                .map_err(|e| format!("Failed to get participants: {}", e))?
+            {
+                self.participant_slots.insert(&(tournament_id.to_string(), slot), last_player.clone())
+                    .map_err(|e| format!("Failed to update participants: {}", e))?;
+                self.participants.insert(&(tournament_id.to_string(), last_player), slot)
+                    .map_err(|e| format!("Failed to update participants: {}", e))?;
+            }
+        }
+        self.participant_slots.remove(&last_key)
+            .map_err(|e| format!("Failed to update participants: {}", e))?;
+        self.participants.remove(&key)
+            .map_err(|e| format!("Failed to update participants: {}", e))?;
+        Ok(true)
+    }
+
+    /// Participants in the given slot range, in slot order
+    pub async fn participant_page(&self, tournament_id: &str, slots: RangeInclusive<u32>) -> Result<Vec<String>, String> {
+        let mut participants = Vec::new();
+        for slot in slots {
+            if let Some(player) = self.participant_slots.get(&(tournament_id.to_string(), slot)).await
+                .map_err(|e| format!("Failed to get participants: {}", e))?
+            {
+                participants.push(player);
+            }
+        }
+        Ok(participants)
+    }
+
+    /// All participants of a tournament holding `count` participants, in slot order
+    pub async fn all_participants(&self, tournament_id: &str, count: u32) -> Result<Vec<String>, String> {
+        self.participant_page(tournament_id, 1..=count).await
+    }
+
+    /// Generate unique season ID
+    pub async fn generate_season_id(&mut self) -> String {
+        let counter = self.season_counter.get() + 1;
+        self.season_counter.set(counter);
+        format!("season-{}", counter)
+    }
+
+    /// Add one tournament finish to a player's season record
+    pub async fn record_season_finish(&mut self, season: &mut Season, entry: &LeaderboardEntry, points: u32) -> Result<(), String> {
+        let key = (season.id.clone(), entry.player_account.clone());
+        let mut record = match self.season_records.get(&key).await
+            .map_err(|e| format!("Failed to get season record: {}", e))?
+        {
+            Some(record) => record,
+            None => {
+                season.player_count += 1;
+                self.season_players.insert(&(season.id.clone(), season.player_count), entry.player_account.clone())
+                    .map_err(|e| format!("Failed to update season players: {}", e))?;
+                SeasonRecord::new(entry.player_account.clone())
+            }
+        };
+
+        record.add_finish(entry, points);
+        self.season_records.insert(&key, record)
+            .map_err(|e| format!("Failed to store season record: {}", e))
+    }
+
+    /// Every player's record for a season
+    pub async fn season_records(&self, season: &Season) -> Result<Vec<SeasonRecord>, String> {
+        let mut records = Vec::new();
+        for slot in 1..=season.player_count {
+            let Some(player) = self.season_players.get(&(season.id.clone(), slot)).await
+                .map_err(|e| format!("Failed to get season players: {}", e))?
+            else {
+                continue;
+            };
+            if let Some(record) = self.season_records.get(&(season.id.clone(), player)).await
+                .map_err(|e| format!("Failed to get season record: {}", e))?
+            {
+                records.push(record);
+            }
+        }
+        Ok(records)
+    }
+
+    /// Team a player belongs to within a tournament
+    pub async fn team_of(&self, tournament_id: &str, player_account: &str) -> Result<Option<String>, String> {
+        self.team_memberships.get(&(tournament_id.to_string(), player_account.to_string())).await
+            .map_err(|e| format!("Failed to get team membership: {}", e))
+    }
+
+    /// Create a team with the captain as its first member, returning the new team ID
+    pub async fn add_team(&mut self, tournament_id: &str, name: String, captain: String) -> Result<String, String> {
+        let mut team_ids = self.tournament_team_ids.get(tournament_id).await
+            .map_err(|e| format!("Failed to get teams: {}", e))?
             .unwrap_or_default();
-        
-        if !participants.contains(&player_account) {
-            participants.push(player_account);
-            self.participants.insert(tournament_id, participants)
-                .map_err(|e| format!("Failed to update participants: {}", e))?;
+        let team_id = format!("{}-team-{}", tournament_id, team_ids.len() + 1);
+
+        self.team_memberships.insert(&(tournament_id.to_string(), captain.clone()), team_id.clone())
+            .map_err(|e| format!("Failed to record team membership: {}", e))?;
+        self.teams.insert(&team_id, Team::new(team_id.clone(), tournament_id.to_string(), name, captain))
+            .map_err(|e| format!("Failed to store team: {}", e))?;
+        team_ids.push(team_id.clone());
+        self.tournament_team_ids.insert(tournament_id, team_ids)
+            .map_err(|e| format!("Failed to update teams: {}", e))?;
+        Ok(team_id)
+    }
+
+    /// Remove a player from their team, if any. Empty teams are disbanded.
+    pub async fn leave_team(&mut self, tournament_id: &str, player_account: &str) -> Result<(), String> {
+        let Some(team_id) = self.team_of(tournament_id, player_account).await? else {
+            return Ok(());
+        };
+        self.team_memberships.remove(&(tournament_id.to_string(), player_account.to_string()))
+            .map_err(|e| format!("Failed to update team membership: {}", e))?;
+
+        let Some(mut team) = self.teams.get(&team_id).await
+            .map_err(|e| format!("Failed to get team: {}", e))?
+        else {
+            return Ok(());
+        };
+
+        if team.remove_member(player_account) {
+            self.teams.insert(&team_id, team)
+                .map_err(|e| format!("Failed to update team: {}", e))?;
+        } else {
+            // Team IDs are numbered by position, so a disbanded team keeps its place in the list
+            self.teams.remove(&team_id)
+                .map_err(|e| format!("Failed to remove team: {}", e))?;
         }
-        
         Ok(())
     }
 
-    /// Check if tournament is full
-    #[allow(dead_code)]
-    pub async fn is_tournament_full(&self, tournament_id: &str) -> Result<bool, String> {
-        let tournament = self.tournaments.get(tournament_id).await
-            .map_err(|e| format!("Failed to get tournament: {}", e))?
-            .ok_or("Tournament not found")?;
-        
-        let participants = self.participants.get(tournament_id).await
-            .map_err(|e| format!("Failed to get participants: {}", e))?
+    /// Teams of a tournament in creation order
+    pub async fn tournament_teams(&self, tournament_id: &str) -> Result<Vec<Team>, String> {
+        let team_ids = self.tournament_team_ids.get(tournament_id).await
+            .map_err(|e| format!("Failed to get teams: {}", e))?
             .unwrap_or_default();
-        
-        Ok(participants.len() >= tournament.max_participants as usize)
+
+        let mut teams = Vec::new();
+        for team_id in team_ids {
+            if let Some(team) = self.teams.get(&team_id).await
+                .map_err(|e| format!("Failed to get team: {}", e))?
+            {
+                teams.push(team);
+            }
+        }
+        Ok(teams)
     }
-}
\ No newline at end of file
+}
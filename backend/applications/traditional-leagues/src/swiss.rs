@@ -0,0 +1,412 @@
+/*!
+# Swiss-System Tournaments
+
+Swiss pairing for large fields. Every round pairs players on similar points while avoiding
+rematches, each round is scored head-to-head over its own price window, and the next round's
+pairings are drawn as soon as the previous round is scored so they can be inspected in advance.
+Final standings are ordered by points, then Buchholz, then Sonneborn-Berger.
+*/
+
+use std::collections::BTreeMap;
+
+use async_graphql::SimpleObject;
+use serde::{Deserialize, Serialize};
+
+use crate::round_robin::{LeagueMatch, LeagueRound, Tally, WIN_POINTS};
+use crate::{Fixed, FixedResult, PriceData, PriceSnapshot, Rounding, TournamentPortfolio};
+
+/// Upper bound on pairing attempts per round before rematches are allowed
+const PAIRING_SEARCH_BUDGET: u32 = 10_000;
+
+/// Swiss tournament state
+#[derive(Debug, Clone, Serialize, Deserialize, SimpleObject)]
+pub struct Swiss {
+    pub tournament_id: String,
+    /// Players in registration order; earlier registration ranks higher on equal standing
+    pub players: Vec<String>,
+    /// Number of rounds to play
+    pub total_rounds: u32,
+    /// Rounds paired so far; the last one is being played until `finished`
+    pub rounds: Vec<LeagueRound>,
+    pub finished: bool,
+}
+
+/// Row of a Swiss standings table
+#[derive(Debug, Clone, Serialize, Deserialize, SimpleObject)]
+pub struct SwissStandingsEntry {
+    pub rank: u32,
+    pub player_account: String,
+    pub played: u32,
+    pub wins: u32,
+    pub draws: u32,
+    pub losses: u32,
+    /// Rounds sat out; each counts as a win
+    pub byes: u32,
+    pub points: u32,
+    /// Sum of the points of every opponent faced
+    pub buchholz: u32,
+    /// Points of opponents beaten plus half the points of opponents drawn
+    pub sonneborn_berger: Fixed,
+    pub return_differential: Fixed,
+}
+
+/// Rounds needed to separate a single undefeated player: ceil(log2(players))
+pub fn default_swiss_rounds(players: usize) -> u32 {
+    (players.max(2) as u32).next_power_of_two().trailing_zeros()
+}
+
+impl Swiss {
+    /// Start a Swiss tournament and pair round 1. A `round_count` of 0 plays the default
+    /// number of rounds; the count is capped so nobody is forced into a rematch.
+    pub fn new(tournament_id: String, players: Vec<String>, round_count: u32, start_prices: Vec<PriceSnapshot>) -> Self {
+        let max_rounds = (players.len().saturating_sub(1) as u32).max(1);
+        let total_rounds = match round_count {
+            0 => default_swiss_rounds(players.len()),
+            count => count,
+        }
+        .min(max_rounds);
+
+        let mut swiss = Self {
+            tournament_id,
+            players,
+            total_rounds,
+            rounds: Vec::new(),
+            finished: false,
+        };
+        let matches = swiss.pair_round(1, &BTreeMap::new());
+        swiss.rounds.push(LeagueRound {
+            round: 1,
+            matches,
+            start_prices: Some(start_prices),
+            end_prices: None,
+        });
+        swiss
+    }
+
+    /// Round currently being played (or the last one, once finished)
+    pub fn current_round(&self) -> Option<&LeagueRound> {
+        self.rounds.last()
+    }
+
+    /// Pairings of a given round, if it has been drawn
+    pub fn pairings(&self, round: u32) -> Vec<LeagueMatch> {
+        self.rounds.iter()
+            .find(|league_round| league_round.round == round)
+            .map(|league_round| league_round.matches.clone())
+            .unwrap_or_default()
+    }
+
+    /// Players with a match to score in the current round
+    pub fn active_players(&self) -> Vec<String> {
+        self.current_round()
+            .map(|round| {
+                round.matches.iter()
+                    .filter(|league_match| !league_match.is_bye())
+                    .flat_map(|league_match| std::iter::once(league_match.home.clone()).chain(league_match.away.clone()))
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    /// Whether `a` and `b` have already been paired
+    fn have_met(&self, a: &str, b: &str) -> bool {
+        self.rounds.iter()
+            .flat_map(|round| round.matches.iter())
+            .any(|league_match| league_match.is_between(a, b))
+    }
+
+    fn has_had_bye(&self, player: &str) -> bool {
+        self.rounds.iter()
+            .flat_map(|round| round.matches.iter())
+            .any(|league_match| league_match.is_bye() && league_match.home == player)
+    }
+
+    /// Points per player, counting byes as wins
+    fn points(&self) -> FixedResult<BTreeMap<String, u32>> {
+        let tallies = Tally::collect(&self.players, self.rounds.iter())?;
+        Ok(self.players.iter().map(|player| {
+            let byes = self.bye_count(player);
+            (player.clone(), tallies[player].points + byes * WIN_POINTS)
+        }).collect())
+    }
+
+    fn bye_count(&self, player: &str) -> u32 {
+        self.rounds.iter()
+            .flat_map(|round| round.matches.iter())
+            .filter(|league_match| league_match.is_bye() && league_match.home == player)
+            .count() as u32
+    }
+
+    /// Pair players on equal or nearby points. Players are ranked by points, then registration
+    /// order; the lowest-ranked player without a bye sits out an odd round, and the rest are
+    /// paired top-down with the highest-ranked opponent they have not met, backtracking when a
+    /// choice leaves someone without a fresh opponent. If no rematch-free pairing is found
+    /// within the search budget, rematches are allowed.
+    fn pair_round(&self, round: u32, points: &BTreeMap<String, u32>) -> Vec<LeagueMatch> {
+        let mut ranked: Vec<&String> = self.players.iter().collect();
+        ranked.sort_by_key(|player| std::cmp::Reverse(points.get(*player).copied().unwrap_or(0)));
+
+        // Bye candidates, lowest ranked first; players who already sat out are a last resort
+        let bye_candidates: Vec<Option<usize>> = if ranked.len() % 2 == 1 {
+            let fresh = (0..ranked.len()).rev().filter(|index| !self.has_had_bye(ranked[*index]));
+            fresh.chain([ranked.len() - 1]).map(Some).collect()
+        } else {
+            vec![None]
+        };
+
+        let mut budget = PAIRING_SEARCH_BUDGET;
+        let mut fallback = None;
+        for bye in bye_candidates {
+            let field: Vec<&String> = ranked.iter()
+                .enumerate()
+                .filter(|(index, _)| Some(*index) != bye)
+                .map(|(_, player)| *player)
+                .collect();
+            let bye_match = bye.map(|index| LeagueMatch::new(round, ranked[index].clone(), None));
+
+            if let Some(pairs) = self.pair_without_rematches(&field, &mut budget) {
+                return Self::into_matches(round, pairs, bye_match);
+            }
+            if fallback.is_none() {
+                // Rank-order pairing as a last resort
+                let pairs = field.chunks(2).map(|pair| (pair[0], pair[1])).collect();
+                fallback = Some(Self::into_matches(round, pairs, bye_match));
+            }
+        }
+        fallback.unwrap_or_default()
+    }
+
+    /// Depth-first search for a rematch-free pairing of an even field
+    fn pair_without_rematches<'a>(&self, field: &[&'a String], budget: &mut u32) -> Option<Vec<(&'a String, &'a String)>> {
+        let Some((first, rest)) = field.split_first() else {
+            return Some(Vec::new());
+        };
+        for (index, opponent) in rest.iter().enumerate() {
+            if *budget == 0 {
+                return None;
+            }
+            *budget -= 1;
+            if self.have_met(first, opponent) {
+                continue;
+            }
+            let remaining: Vec<&String> = rest.iter()
+                .enumerate()
+                .filter(|(other, _)| *other != index)
+                .map(|(_, player)| *player)
+                .collect();
+            if let Some(mut pairs) = self.pair_without_rematches(&remaining, budget) {
+                pairs.insert(0, (*first, *opponent));
+                return Some(pairs);
+            }
+        }
+        None
+    }
+
+    fn into_matches(round: u32, pairs: Vec<(&String, &String)>, bye: Option<LeagueMatch>) -> Vec<LeagueMatch> {
+        pairs.into_iter()
+            .map(|(home, away)| LeagueMatch::new(round, home.clone(), Some(away.clone())))
+            .chain(bye)
+            .collect()
+    }
+
+    /// Score the current round against `end_prices` and pair the next round, which starts at
+    /// those prices. Returns the new round number, or `None` once the final round is scored.
+    pub fn advance_round(
+        &mut self,
+        end_prices: Vec<PriceSnapshot>,
+        portfolios: &BTreeMap<String, TournamentPortfolio>,
+        return_scale: i64,
+    ) -> FixedResult<Option<u32>> {
+        if self.finished {
+            return Ok(None);
+        }
+        let Some(round) = self.rounds.last_mut() else {
+            return Ok(None);
+        };
+
+        let start_prices = round.start_prices.clone().unwrap_or_default();
+        let price_data = PriceData::from_snapshots(&start_prices, &end_prices, return_scale)?;
+        for league_match in round.matches.iter_mut() {
+            league_match.decide(portfolios, &price_data)?;
+        }
+        round.end_prices = Some(end_prices.clone());
+        let next_round = round.round + 1;
+
+        if next_round > self.total_rounds {
+            self.finished = true;
+            return Ok(None);
+        }
+
+        let points = self.points()?;
+        let matches = self.pair_round(next_round, &points);
+        self.rounds.push(LeagueRound {
+            round: next_round,
+            matches,
+            start_prices: Some(end_prices),
+            end_prices: None,
+        });
+        Ok(Some(next_round))
+    }
+
+    /// Standings ordered by points, then Buchholz, then Sonneborn-Berger, then registration order
+    pub fn standings(&self) -> FixedResult<Vec<SwissStandingsEntry>> {
+        let points = self.points()?;
+        let mut tallies = Tally::collect(&self.players, self.rounds.iter())?;
+
+        let mut rows = Vec::with_capacity(self.players.len());
+        for (index, player) in self.players.iter().enumerate() {
+            let mut buchholz = 0;
+            let mut sonneborn_berger = Fixed::ZERO;
+            for league_match in self.rounds.iter().flat_map(|round| round.matches.iter()) {
+                let (Some(opponent), Some(earned)) = (league_match.opponent_of(player), league_match.points_for(player)) else {
+                    continue;
+                };
+                let opponent_points = points.get(opponent).copied().unwrap_or(0);
+                buchholz += opponent_points;
+                let share = match earned {
+                    WIN_POINTS => Fixed::from_ratio(opponent_points as i128, 1, Rounding::Down)?,
+                    0 => Fixed::ZERO,
+                    _ => Fixed::from_ratio(opponent_points as i128, 2, Rounding::Down)?,
+                };
+                sonneborn_berger = sonneborn_berger.checked_add(share)?;
+            }
+            rows.push((points[player], buchholz, sonneborn_berger, index, player));
+        }
+        rows.sort_by(|a, b| b.0.cmp(&a.0).then(b.1.cmp(&a.1)).then(b.2.cmp(&a.2)).then(a.3.cmp(&b.3)));
+
+        rows.into_iter()
+            .enumerate()
+            .map(|(rank, (points, buchholz, sonneborn_berger, _, player))| {
+                let tally = tallies.remove(player).unwrap_or_default();
+                let entry = tally.into_entry(rank as u32 + 1, player.clone())?;
+                Ok(SwissStandingsEntry {
+                    rank: entry.rank,
+                    player_account: entry.player_account,
+                    played: entry.played,
+                    wins: entry.wins,
+                    draws: entry.draws,
+                    losses: entry.losses,
+                    byes: self.bye_count(player),
+                    points,
+                    buchholz,
+                    sonneborn_berger,
+                    return_differential: entry.return_differential,
+                })
+            })
+            .collect()
+    }
+
+    /// Final placements in standings order
+    pub fn placements(&self) -> FixedResult<Vec<String>> {
+        Ok(self.standings()?.into_iter().map(|entry| entry.player_account).collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::BTreeSet;
+
+    fn players(count: usize) -> Vec<String> {
+        (0..count).map(|index| format!("p{}", index)).collect()
+    }
+
+    fn snapshot(crypto_id: &str, price_usd: u64) -> PriceSnapshot {
+        PriceSnapshot { crypto_id: crypto_id.to_string(), price_usd, timestamp: 0 }
+    }
+
+    #[test]
+    fn test_round_count() {
+        assert_eq!(default_swiss_rounds(2), 1);
+        assert_eq!(default_swiss_rounds(8), 3);
+        assert_eq!(default_swiss_rounds(9), 4);
+        assert_eq!(Swiss::new("t".to_string(), players(4), 0, vec![]).total_rounds, 2);
+        assert_eq!(Swiss::new("t".to_string(), players(4), 10, vec![]).total_rounds, 3);
+    }
+
+    #[test]
+    fn test_no_rematches_and_byes_rotate() {
+        let field = players(7);
+        // Each player holds a different coin so every match has a winner
+        let coins = ["A", "B", "C", "D", "E", "F", "G"];
+        let portfolios: BTreeMap<String, TournamentPortfolio> = field.iter().zip(coins)
+            .map(|(player, coin)| {
+                let portfolio = TournamentPortfolio {
+                    crypto_picks: vec![coin.to_string()],
+                    strategy_notes: None,
+                    captain: None,
+                    vice_captain: None,
+                    short_picks: vec![],
+                };
+                (player.clone(), portfolio)
+            })
+            .collect();
+        let prices = |round: u64| -> Vec<PriceSnapshot> {
+            coins.iter().enumerate().map(|(index, coin)| snapshot(coin, 1000 + round * (index as u64 + 1))).collect()
+        };
+
+        let mut swiss = Swiss::new("t".to_string(), field.clone(), 0, prices(0));
+        assert_eq!(swiss.total_rounds, 3);
+        assert_eq!(swiss.pairings(1).len(), 4);
+
+        let mut round = 0;
+        while swiss.advance_round(prices(round + 1), &portfolios, 1).unwrap().is_some() {
+            round += 1;
+            // Pairings for the next round are available before it is scored
+            let next = swiss.pairings(round as u32 + 1);
+            assert!(!next.is_empty() && next.iter().all(|league_match| league_match.result.is_none()));
+        }
+        assert!(swiss.finished);
+
+        let mut pairs = BTreeSet::new();
+        let mut byes = BTreeSet::new();
+        for league_match in swiss.rounds.iter().flat_map(|round| round.matches.iter()) {
+            match &league_match.away {
+                Some(away) => {
+                    let pair = if league_match.home < *away { (league_match.home.clone(), away.clone()) } else { (away.clone(), league_match.home.clone()) };
+                    assert!(pairs.insert(pair), "rematch in Swiss pairing");
+                }
+                None => assert!(byes.insert(league_match.home.clone()), "second bye for one player"),
+            }
+        }
+        assert_eq!(byes.len(), 3);
+
+        let standings = swiss.standings().unwrap();
+        assert!(standings.windows(2).all(|pair| pair[0].points >= pair[1].points));
+        // Later coins rise fastest, so the last player wins every match
+        assert_eq!(standings[0].player_account, "p6");
+        assert_eq!(standings[0].points, 3 * WIN_POINTS);
+    }
+
+    #[test]
+    fn test_buchholz_breaks_tie() {
+        fn play(swiss: &mut Swiss, winners: &[&str]) {
+            let round = swiss.rounds.last_mut().unwrap();
+            for league_match in round.matches.iter_mut() {
+                let home_wins = winners.contains(&league_match.home.as_str());
+                league_match.home_score = Some(Fixed::from_raw(if home_wins { 1 } else { 0 }));
+                league_match.away_score = Some(Fixed::from_raw(if home_wins { 0 } else { 1 }));
+                league_match.result = Some(if home_wins { crate::MatchResult::HomeWin } else { crate::MatchResult::AwayWin });
+            }
+        }
+
+        let mut swiss = Swiss::new("t".to_string(), players(4), 2, vec![]);
+        // Round 1: p0 beats p1, p3 beats p2
+        play(&mut swiss, &["p0", "p3"]);
+        let points = swiss.points().unwrap();
+        let matches = swiss.pair_round(2, &points);
+        swiss.rounds.push(LeagueRound { round: 2, matches, start_prices: None, end_prices: None });
+        assert!(swiss.pairings(2)[0].is_between("p0", "p3"));
+        // Round 2: p0 beats p3, p2 beats p1
+        play(&mut swiss, &["p0", "p2"]);
+
+        let standings = swiss.standings().unwrap();
+        let order: Vec<(&str, u32, u32)> = standings.iter()
+            .map(|entry| (entry.player_account.as_str(), entry.points, entry.buchholz))
+            .collect();
+        // p3 and p2 both have 3 points; p3 faced the stronger opponents
+        assert_eq!(order, vec![("p0", 6, 3), ("p3", 3, 9), ("p2", 3, 3), ("p1", 0, 9)]);
+        assert_eq!(standings[1].sonneborn_berger, Fixed::from_ratio(3, 1, Rounding::Down).unwrap());
+        assert_eq!(standings[2].sonneborn_berger, Fixed::ZERO);
+    }
+}
@@ -0,0 +1,255 @@
+/*!
+# Team Tournaments
+
+Players in a team tournament form teams of `Tournament::team_size` members. A captain
+creates the team and invites registered players, who join by accepting. When the tournament
+starts, players who are not on a full team are withdrawn and refunded, so every team competes
+at full strength. Every member submits their own portfolio; the team score combines the
+members' returns and team prizes are split evenly among the members.
+*/
+
+use std::cmp::Reverse;
+use std::collections::BTreeMap;
+
+use async_graphql::{Enum, SimpleObject};
+use serde::{Deserialize, Serialize};
+
+use crate::{Fixed, FixedResult, LeaderboardEntry, Rounding, PRIZE_SPLIT_PERCENT};
+
+/// How member returns combine into a team score
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, Enum)]
+pub enum TeamScoring {
+    /// Sum of member returns
+    #[default]
+    #[graphql(name = "TOTAL")]
+    Total,
+    /// Sum of member returns divided by the team size
+    #[graphql(name = "AVERAGE")]
+    Average,
+}
+
+/// A team within a tournament
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, SimpleObject)]
+pub struct Team {
+    pub id: String,
+    pub tournament_id: String,
+    pub name: String,
+    pub captain: String,
+    /// Members including the captain, in joining order
+    pub members: Vec<String>,
+    /// Players invited by the captain who have not yet accepted
+    pub invitations: Vec<String>,
+}
+
+/// A member's share of a team prize
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, SimpleObject)]
+pub struct MemberPayout {
+    pub player_account: String,
+    pub amount_usdc: u64,
+}
+
+/// Team tournament leaderboard entry
+#[derive(Debug, Clone, Serialize, Deserialize, SimpleObject)]
+pub struct TeamLeaderboardEntry {
+    pub rank: u32,
+    pub team_id: String,
+    pub team_name: String,
+    pub captain: String,
+    /// Combined member return in fixed point (e.g., 12.50% = 1250)
+    pub team_score: Fixed,
+    pub winning_amount: u64,
+    pub member_payouts: Vec<MemberPayout>,
+}
+
+impl Team {
+    /// Start a team with its captain as the only member
+    pub fn new(id: String, tournament_id: String, name: String, captain: String) -> Self {
+        Self {
+            id,
+            tournament_id,
+            name,
+            members: vec![captain.clone()],
+            captain,
+            invitations: Vec::new(),
+        }
+    }
+
+    /// Check whether the team has reached `team_size` members
+    pub fn is_full(&self, team_size: u32) -> bool {
+        self.members.len() as u32 >= team_size
+    }
+
+    /// Invite a player; returns false if they are already a member or invited
+    pub fn invite(&mut self, player_account: &str) -> bool {
+        if self.members.iter().chain(&self.invitations).any(|player| player == player_account) {
+            return false;
+        }
+        self.invitations.push(player_account.to_string());
+        true
+    }
+
+    /// Turn a pending invitation into membership; returns false without one or when full
+    pub fn accept(&mut self, player_account: &str, team_size: u32) -> bool {
+        let Some(position) = self.invitations.iter().position(|player| player == player_account) else {
+            return false;
+        };
+        if self.is_full(team_size) {
+            return false;
+        }
+        self.invitations.remove(position);
+        self.members.push(player_account.to_string());
+        true
+    }
+
+    /// Remove a member. A departing captain hands over to the longest-standing member.
+    /// Returns false once the team has no members left.
+    pub fn remove_member(&mut self, player_account: &str) -> bool {
+        self.members.retain(|member| member != player_account);
+        self.invitations.retain(|player| player != player_account);
+        match self.members.first() {
+            Some(next_captain) => {
+                if self.captain == player_account {
+                    self.captain = next_captain.clone();
+                }
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Combined score of the members' leaderboard returns. Members without a score count as zero.
+    pub fn score(&self, member_returns: &BTreeMap<&str, Fixed>, scoring: TeamScoring) -> FixedResult<Fixed> {
+        let mut total = Fixed::ZERO;
+        for member in &self.members {
+            if let Some(member_return) = member_returns.get(member.as_str()) {
+                total = total.checked_add(*member_return)?;
+            }
+        }
+
+        match scoring {
+            TeamScoring::Total => Ok(total),
+            TeamScoring::Average => {
+                total.checked_div(Fixed::from_int(self.members.len().max(1) as i64)?, Rounding::HalfEven)
+            }
+        }
+    }
+
+    /// Split a prize evenly among the members; the captain receives any remainder
+    pub fn split_prize(&self, amount_usdc: u64) -> Vec<MemberPayout> {
+        let member_count = self.members.len().max(1) as u64;
+        let share = amount_usdc / member_count;
+        let remainder = amount_usdc % member_count;
+        self.members
+            .iter()
+            .map(|member| MemberPayout {
+                player_account: member.clone(),
+                amount_usdc: if *member == self.captain { share + remainder } else { share },
+            })
+            .collect()
+    }
+}
+
+/// Rank teams by combining their members' entries from the individual `leaderboard`.
+/// Ties keep team creation order; the top three teams share the prize pool.
+pub fn team_leaderboard(
+    teams: &[Team],
+    leaderboard: &[LeaderboardEntry],
+    scoring: TeamScoring,
+    total_prize_pool: u64,
+) -> FixedResult<Vec<TeamLeaderboardEntry>> {
+    let member_returns: BTreeMap<&str, Fixed> = leaderboard
+        .iter()
+        .map(|entry| (entry.player_account.as_str(), entry.total_return))
+        .collect();
+
+    let mut scored = Vec::new();
+    for team in teams {
+        scored.push((team, team.score(&member_returns, scoring)?));
+    }
+    scored.sort_by_key(|(_, team_score)| Reverse(*team_score));
+
+    let mut entries = Vec::new();
+    for (index, (team, team_score)) in scored.into_iter().enumerate() {
+        let winning_amount = match PRIZE_SPLIT_PERCENT.get(index) {
+            Some(percent) => Fixed::from_percent(*percent).apply_to_amount(total_prize_pool, Rounding::Down)?,
+            None => 0,
+        };
+        entries.push(TeamLeaderboardEntry {
+            rank: index as u32 + 1,
+            team_id: team.id.clone(),
+            team_name: team.name.clone(),
+            captain: team.captain.clone(),
+            team_score,
+            winning_amount,
+            member_payouts: team.split_prize(winning_amount),
+        });
+    }
+    Ok(entries)
+}
+
+/// Replace individual winnings with each player's share of their team's prize
+pub fn apply_team_payouts(leaderboard: &mut [LeaderboardEntry], team_entries: &[TeamLeaderboardEntry]) {
+    for entry in leaderboard.iter_mut() {
+        entry.winning_amount = team_entries
+            .iter()
+            .flat_map(|team_entry| &team_entry.member_payouts)
+            .find(|payout| payout.player_account == entry.player_account)
+            .map(|payout| payout.amount_usdc)
+            .unwrap_or(0);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(player: &str, total_return: i64) -> LeaderboardEntry {
+        LeaderboardEntry {
+            rank: 0,
+            player_account: player.to_string(),
+            total_return: Fixed::from_raw(total_return),
+            winning_amount: 0,
+        }
+    }
+
+    #[test]
+    fn test_team_membership() {
+        let mut team = Team::new("t-team-1".to_string(), "t".to_string(), "Bulls".to_string(), "a".to_string());
+        assert!(team.invite("b"));
+        assert!(!team.invite("b"));
+        assert!(!team.invite("a"));
+        assert!(!team.accept("c", 2));
+        assert!(team.accept("b", 2));
+        assert!(team.is_full(2));
+
+        // The captain leaving hands the team to the next member
+        assert!(team.remove_member("a"));
+        assert_eq!(team.captain, "b");
+        assert!(!team.remove_member("b"));
+    }
+
+    #[test]
+    fn test_team_leaderboard_scores_and_splits() {
+        let mut bulls = Team::new("t-team-1".to_string(), "t".to_string(), "Bulls".to_string(), "a".to_string());
+        bulls.members.push("b".to_string());
+        let mut bears = Team::new("t-team-2".to_string(), "t".to_string(), "Bears".to_string(), "c".to_string());
+        bears.members.extend(["d".to_string(), "e".to_string()]);
+
+        let leaderboard = vec![entry("a", 3_000), entry("b", 1_000), entry("c", 2_000), entry("d", 1_000), entry("e", 1_500)];
+
+        // Bears win on total, Bulls on average
+        let total = team_leaderboard(&[bulls.clone(), bears.clone()], &leaderboard, TeamScoring::Total, 1_007).unwrap();
+        assert_eq!(total[0].team_id, "t-team-2");
+        assert_eq!(total[0].team_score, Fixed::from_raw(4_500));
+        assert_eq!(total[0].winning_amount, 604);
+        let payouts: Vec<u64> = total[0].member_payouts.iter().map(|payout| payout.amount_usdc).collect();
+        assert_eq!(payouts, vec![202, 201, 201]);
+
+        let average = team_leaderboard(&[bulls, bears], &leaderboard, TeamScoring::Average, 1_007).unwrap();
+        assert_eq!(average[0].team_id, "t-team-1");
+        assert_eq!(average[0].team_score, Fixed::from_raw(2_000));
+        assert_eq!(average[1].team_score, Fixed::from_raw(1_500));
+        let payouts: Vec<u64> = average[1].member_payouts.iter().map(|payout| payout.amount_usdc).collect();
+        assert_eq!(payouts, vec![102, 100, 100]);
+    }
+}
@@ -30,12 +30,19 @@ fi
 # Navigate back to project root
 cd ../../..
 
+# CoinDrafts Core application allowed to call the core-only operations, if already deployed
+CORE_APPLICATION_ID=null
+if [ -n "${COINDRAFTS_CORE_APP_ID:-}" ]; then
+    CORE_APPLICATION_ID="\"$COINDRAFTS_CORE_APP_ID\""
+fi
+
 # Deploy the application
 echo "📤 Publishing Traditional Leagues application..."
 linera publish-and-create \
     target/wasm32-unknown-unknown/release/traditional_leagues_contract.wasm \
     target/wasm32-unknown-unknown/release/traditional_leagues_service.wasm \
-    --json-argument "null"
+    --json-argument "null" \
+    --json-parameters "{\"core_application_id\": $CORE_APPLICATION_ID}"
 
 if [ $? -eq 0 ]; then
     echo "✅ Traditional Leagues application deployed successfully!"
@@ -41,6 +41,7 @@ if [ $? -eq 0 ]; then
     linera service --port 8080 &
     sleep 3
     echo "🌐 GraphQL endpoint: http://localhost:8080"
+    echo "🔗 Run the connectLeagues mutation to follow Traditional Leagues"
 else
     echo "❌ Deployment failed!"
     exit 1
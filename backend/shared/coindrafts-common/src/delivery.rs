@@ -0,0 +1,94 @@
+/*!
+# Message Delivery Log
+
+Bookkeeping for cross-application messages. Senders keep an outbox record per message
+until the receiver acknowledges it; receivers keep an inbox record per message ID so a
+replayed or retried message is never applied twice.
+*/
+
+use async_graphql::{Enum, SimpleObject};
+use serde::{Deserialize, Serialize};
+
+/// Delivery state of a cross-application message
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Enum)]
+pub enum DeliveryStatus {
+    /// Sent, not yet acknowledged (outbox) or not yet handled (inbox)
+    Pending,
+    /// Acknowledged by the receiver (outbox) or applied (inbox)
+    Delivered,
+    /// Rejected by the receiver; may be retried
+    Failed,
+}
+
+/// Outbox or inbox entry for one message
+#[derive(Debug, Clone, Serialize, Deserialize, SimpleObject)]
+pub struct MessageRecord {
+    /// Idempotency key carried by the message envelope
+    pub message_id: String,
+    /// Message variant, e.g. "TournamentCompleted"
+    pub kind: String,
+    pub status: DeliveryStatus,
+    /// Number of times the message was sent (outbox) or handled (inbox)
+    pub attempts: u32,
+    /// Why the last attempt failed
+    pub error: Option<String>,
+    pub updated_at: u64,
+}
+
+impl MessageRecord {
+    /// Record for a message on its first attempt
+    pub fn pending(message_id: String, kind: &str, now: u64) -> Self {
+        Self {
+            message_id,
+            kind: kind.to_string(),
+            status: DeliveryStatus::Pending,
+            attempts: 1,
+            error: None,
+            updated_at: now,
+        }
+    }
+
+    /// Apply the outcome of an attempt
+    pub fn settle(&mut self, outcome: Result<(), String>, now: u64) {
+        match outcome {
+            Ok(()) => {
+                self.status = DeliveryStatus::Delivered;
+                self.error = None;
+            }
+            Err(error) => {
+                self.status = DeliveryStatus::Failed;
+                self.error = Some(error);
+            }
+        }
+        self.updated_at = now;
+    }
+
+    /// Start another attempt after a failure
+    pub fn retry(&mut self, now: u64) {
+        self.status = DeliveryStatus::Pending;
+        self.attempts = self.attempts.saturating_add(1);
+        self.error = None;
+        self.updated_at = now;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_record_lifecycle() {
+        let mut record = MessageRecord::pending("chain-1".to_string(), "SyncPortfolio", 10);
+        assert_eq!(record.status, DeliveryStatus::Pending);
+
+        record.settle(Err("Tournament not found".to_string()), 20);
+        assert_eq!(record.status, DeliveryStatus::Failed);
+        assert_eq!(record.error.as_deref(), Some("Tournament not found"));
+
+        record.retry(30);
+        assert_eq!((record.status, record.attempts, record.error.clone()), (DeliveryStatus::Pending, 2, None));
+
+        record.settle(Ok(()), 40);
+        assert_eq!((record.status, record.updated_at), (DeliveryStatus::Delivered, 40));
+    }
+}
@@ -0,0 +1,116 @@
+/*!
+# Lifecycle Events
+
+Typed events CoinDrafts applications publish so other applications and off-chain
+indexers can subscribe instead of polling the GraphQL services. Every application
+publishes on its own instance of the named streams below; subscribers pick the
+application by ID.
+*/
+
+use serde::{Deserialize, Serialize};
+
+use crate::{MessageEnvelope, TraditionalLeaguesMessage};
+
+/// Stream of game lifecycle events: creation, joins, portfolio submissions, start and settlement.
+/// Traditional Leagues publishes its tournaments here, with the tournament ID as `game_id`.
+pub const GAME_EVENT_STREAM: &str = "coindrafts-games";
+
+/// Stream of achievements unlocked by players
+pub const ACHIEVEMENT_EVENT_STREAM: &str = "coindrafts-achievements";
+
+/// Stream of prediction market settlements
+pub const MARKET_EVENT_STREAM: &str = "coindrafts-markets";
+
+/// Lifecycle event published on [`GAME_EVENT_STREAM`], [`ACHIEVEMENT_EVENT_STREAM`]
+/// or [`MARKET_EVENT_STREAM`]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum GameEvent {
+    GameCreated {
+        game_id: String,
+        name: String,
+        entry_fee_usdc: u64,
+        max_players: u32,
+        created_at: u64,
+    },
+    PlayerJoined {
+        game_id: String,
+        player_account: String,
+        joined_at: u64,
+    },
+    /// Also published when a player replaces an earlier portfolio
+    PortfolioSubmitted {
+        game_id: String,
+        player_account: String,
+        submitted_at: u64,
+    },
+    GameStarted {
+        game_id: String,
+        started_at: u64,
+    },
+    /// `winners` lists player accounts best first
+    GameSettled {
+        game_id: String,
+        winners: Vec<String>,
+        settled_at: u64,
+    },
+    AchievementUnlocked {
+        player_account: String,
+        achievement_id: String,
+        game_id: Option<String>,
+        unlocked_at: u64,
+    },
+    /// `winners` lists the players whose predicted range held the final price
+    MarketSettled {
+        market_id: String,
+        final_price: u64,
+        winners: Vec<String>,
+        settled_at: u64,
+    },
+}
+
+impl GameEvent {
+    /// Stream the event is published on
+    pub fn stream_name(&self) -> &'static str {
+        match self {
+            Self::AchievementUnlocked { .. } => ACHIEVEMENT_EVENT_STREAM,
+            Self::MarketSettled { .. } => MARKET_EVENT_STREAM,
+            _ => GAME_EVENT_STREAM,
+        }
+    }
+}
+
+/// Value of every event CoinDrafts Core and Traditional Leagues emit: protocol messages on the
+/// notification stream, lifecycle events on the others.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum CoinDraftsEvent {
+    /// Leagues notification, on [`CORE_NOTIFICATION_STREAM`](crate::CORE_NOTIFICATION_STREAM)
+    Notification(MessageEnvelope<TraditionalLeaguesMessage>),
+    Lifecycle(GameEvent),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_event_streams() {
+        let achievement = GameEvent::AchievementUnlocked {
+            player_account: "player".to_string(),
+            achievement_id: "first_win".to_string(),
+            game_id: Some("game_1".to_string()),
+            unlocked_at: 0,
+        };
+        assert_eq!(achievement.stream_name(), ACHIEVEMENT_EVENT_STREAM);
+
+        let settled = GameEvent::MarketSettled {
+            market_id: "1".to_string(),
+            final_price: 100,
+            winners: Vec::new(),
+            settled_at: 0,
+        };
+        assert_eq!(settled.stream_name(), MARKET_EVENT_STREAM);
+
+        let started = GameEvent::GameStarted { game_id: "game_1".to_string(), started_at: 0 };
+        assert_eq!(started.stream_name(), GAME_EVENT_STREAM);
+    }
+}
@@ -0,0 +1,217 @@
+/*!
+# Fixed-Point Arithmetic
+
+Deterministic decimal arithmetic for scoring, multipliers, and payouts.
+Values are stored as integers scaled by 10,000 (basis points), so `1.0 = 10_000`
+and a 5.25% return is `525`. Every operation is checked and rounds explicitly;
+settlement never touches floating point, never wraps, and never panics.
+*/
+
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+/// Number of raw units in `1.0`
+pub const FIXED_SCALE: i64 = 10_000;
+
+/// Result type for fixed-point operations
+pub type FixedResult<T> = std::result::Result<T, ArithmeticError>;
+
+/// Fixed-point arithmetic failures
+#[derive(Error, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ArithmeticError {
+    #[error("Arithmetic overflow")]
+    Overflow,
+
+    #[error("Division by zero")]
+    DivisionByZero,
+
+    #[error("Negative value cannot be applied to an amount")]
+    NegativeAmount,
+}
+
+/// Rounding mode applied whenever a result does not fit the scale exactly
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Rounding {
+    /// Truncate toward zero (never overpays)
+    Down,
+    /// Round away from zero
+    Up,
+    /// Round to nearest, ties away from zero
+    HalfUp,
+    /// Round to nearest, ties to even (banker's rounding)
+    HalfEven,
+}
+
+/// Signed fixed-point decimal with four decimal places
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub struct Fixed(i64);
+
+async_graphql::scalar!(
+    Fixed,
+    "Fixed",
+    "Fixed-point decimal scaled by 10,000 (1.0 = 10000, 5.25% = 525)"
+);
+
+impl Fixed {
+    pub const ZERO: Fixed = Fixed(0);
+    pub const ONE: Fixed = Fixed(FIXED_SCALE);
+
+    /// Create from a raw value already scaled by 10,000
+    pub const fn from_raw(raw: i64) -> Self {
+        Fixed(raw)
+    }
+
+    /// Raw value scaled by 10,000
+    pub const fn raw(self) -> i64 {
+        self.0
+    }
+
+    /// Create from a whole number
+    pub fn from_int(value: i64) -> FixedResult<Self> {
+        value
+            .checked_mul(FIXED_SCALE)
+            .map(Fixed)
+            .ok_or(ArithmeticError::Overflow)
+    }
+
+    /// Create from a whole percentage (e.g., 60 -> 0.60)
+    pub fn from_percent(percent: u32) -> Self {
+        Fixed(percent as i64 * FIXED_SCALE / 100)
+    }
+
+    /// Create from `numerator / denominator`
+    pub fn from_ratio(numerator: i128, denominator: i128, rounding: Rounding) -> FixedResult<Self> {
+        let scaled = numerator
+            .checked_mul(FIXED_SCALE as i128)
+            .ok_or(ArithmeticError::Overflow)?;
+        Self::narrow(div_round(scaled, denominator, rounding)?)
+    }
+
+    /// Relative change from `start` to `end` (e.g., 100 -> 105 is 0.05)
+    pub fn percent_change(start: u64, end: u64, rounding: Rounding) -> FixedResult<Self> {
+        Self::from_ratio(end as i128 - start as i128, start as i128, rounding)
+    }
+
+    pub fn checked_add(self, rhs: Fixed) -> FixedResult<Self> {
+        self.0.checked_add(rhs.0).map(Fixed).ok_or(ArithmeticError::Overflow)
+    }
+
+    pub fn checked_sub(self, rhs: Fixed) -> FixedResult<Self> {
+        self.0.checked_sub(rhs.0).map(Fixed).ok_or(ArithmeticError::Overflow)
+    }
+
+    /// Multiply two fixed-point values
+    pub fn checked_mul(self, rhs: Fixed, rounding: Rounding) -> FixedResult<Self> {
+        let product = self.0 as i128 * rhs.0 as i128;
+        Self::narrow(div_round(product, FIXED_SCALE as i128, rounding)?)
+    }
+
+    /// Divide two fixed-point values
+    pub fn checked_div(self, rhs: Fixed, rounding: Rounding) -> FixedResult<Self> {
+        let scaled = self.0 as i128 * FIXED_SCALE as i128;
+        Self::narrow(div_round(scaled, rhs.0 as i128, rounding)?)
+    }
+
+    /// Multiply by a whole number
+    pub fn checked_mul_int(self, factor: i64) -> FixedResult<Self> {
+        self.0.checked_mul(factor).map(Fixed).ok_or(ArithmeticError::Overflow)
+    }
+
+    /// Apply this value as a multiplier to an integer amount (e.g., a micro-USDC payout)
+    pub fn apply_to_amount(self, amount: u64, rounding: Rounding) -> FixedResult<u64> {
+        if self.0 < 0 {
+            return Err(ArithmeticError::NegativeAmount);
+        }
+        let product = amount as i128 * self.0 as i128;
+        let result = div_round(product, FIXED_SCALE as i128, rounding)?;
+        u64::try_from(result).map_err(|_| ArithmeticError::Overflow)
+    }
+
+    pub fn is_negative(self) -> bool {
+        self.0 < 0
+    }
+
+    fn narrow(value: i128) -> FixedResult<Self> {
+        i64::try_from(value).map(Fixed).map_err(|_| ArithmeticError::Overflow)
+    }
+}
+
+/// Integer division with an explicit rounding mode
+fn div_round(numerator: i128, denominator: i128, rounding: Rounding) -> FixedResult<i128> {
+    if denominator == 0 {
+        return Err(ArithmeticError::DivisionByZero);
+    }
+    let quotient = numerator.checked_div(denominator).ok_or(ArithmeticError::Overflow)?;
+    let remainder = numerator % denominator;
+    if remainder == 0 {
+        return Ok(quotient);
+    }
+
+    // Step one unit away from zero, in the direction of the exact result
+    let away = if (numerator < 0) != (denominator < 0) { quotient - 1 } else { quotient + 1 };
+    let twice_remainder = remainder.unsigned_abs() * 2;
+    let divisor = denominator.unsigned_abs();
+
+    Ok(match rounding {
+        Rounding::Down => quotient,
+        Rounding::Up => away,
+        Rounding::HalfUp if twice_remainder >= divisor => away,
+        Rounding::HalfEven if twice_remainder > divisor => away,
+        Rounding::HalfEven if twice_remainder == divisor && quotient % 2 != 0 => away,
+        Rounding::HalfUp | Rounding::HalfEven => quotient,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_percent_change() {
+        let change = Fixed::percent_change(100_000_000, 105_250_000, Rounding::Down).unwrap();
+        assert_eq!(change.raw(), 525);
+        let change = Fixed::percent_change(100_000_000, 95_000_000, Rounding::Down).unwrap();
+        assert_eq!(change.raw(), -500);
+        assert_eq!(
+            Fixed::percent_change(0, 1, Rounding::Down),
+            Err(ArithmeticError::DivisionByZero)
+        );
+    }
+
+    #[test]
+    fn test_rounding_modes() {
+        // 1 / 3 = 0.33333...
+        assert_eq!(Fixed::from_ratio(1, 3, Rounding::Down).unwrap().raw(), 3333);
+        assert_eq!(Fixed::from_ratio(1, 3, Rounding::Up).unwrap().raw(), 3334);
+        assert_eq!(Fixed::from_ratio(-1, 3, Rounding::Down).unwrap().raw(), -3333);
+        assert_eq!(Fixed::from_ratio(-1, 3, Rounding::Up).unwrap().raw(), -3334);
+        // 0.00005 sits exactly between two representable values
+        assert_eq!(Fixed::from_ratio(1, 20_000, Rounding::HalfUp).unwrap().raw(), 1);
+        assert_eq!(Fixed::from_ratio(1, 20_000, Rounding::HalfEven).unwrap().raw(), 0);
+        assert_eq!(Fixed::from_ratio(3, 20_000, Rounding::HalfEven).unwrap().raw(), 2);
+    }
+
+    #[test]
+    fn test_checked_operations() {
+        let half = Fixed::from_percent(50);
+        assert_eq!(half.checked_mul(Fixed::from_int(20).unwrap(), Rounding::Down).unwrap(), Fixed::from_int(10).unwrap());
+        assert_eq!(Fixed::ONE.checked_div(half, Rounding::Down).unwrap(), Fixed::from_int(2).unwrap());
+        assert_eq!(Fixed::ONE.checked_div(Fixed::ZERO, Rounding::Down), Err(ArithmeticError::DivisionByZero));
+        assert_eq!(Fixed::from_raw(i64::MAX).checked_mul_int(2), Err(ArithmeticError::Overflow));
+        assert_eq!(Fixed::from_raw(i64::MAX).checked_add(Fixed::ONE), Err(ArithmeticError::Overflow));
+    }
+
+    #[test]
+    fn test_apply_to_amount() {
+        assert_eq!(Fixed::from_percent(60).apply_to_amount(1_000_001, Rounding::Down).unwrap(), 600_000);
+        assert_eq!(Fixed::from_percent(60).apply_to_amount(1_000_001, Rounding::Up).unwrap(), 600_001);
+        assert_eq!(
+            Fixed::from_raw(-1).apply_to_amount(100, Rounding::Down),
+            Err(ArithmeticError::NegativeAmount)
+        );
+        assert_eq!(
+            Fixed::from_int(1_000_000).unwrap().apply_to_amount(u64::MAX, Rounding::Down),
+            Err(ArithmeticError::Overflow)
+        );
+    }
+}
@@ -0,0 +1,31 @@
+/*!
+# CoinDrafts Common
+
+Types shared by every CoinDrafts application.
+Anything that crosses an application boundary (messages, price snapshots,
+player profiles, portfolios, tournament summaries) is defined here exactly once,
+so all applications agree on the wire format.
+*/
+
+pub mod delivery;
+pub mod events;
+pub mod fixed;
+pub mod messages;
+pub mod pagination;
+pub mod player;
+pub mod portfolio;
+pub mod price;
+pub mod subscription;
+pub mod tournament;
+
+// Re-export commonly used types
+pub use delivery::*;
+pub use events::*;
+pub use fixed::*;
+pub use messages::*;
+pub use pagination::*;
+pub use player::*;
+pub use portfolio::*;
+pub use price::*;
+pub use subscription::*;
+pub use tournament::*;
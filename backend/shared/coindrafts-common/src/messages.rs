@@ -0,0 +1,212 @@
+/*!
+# Cross-Application Messages
+
+The message protocol spoken between CoinDrafts Core and Traditional Leagues.
+Every message travels inside a [`MessageEnvelope`] carrying the protocol version and
+a message ID the receiver acknowledges and uses to drop duplicates. The envelope is
+decoded before its version is checked, so the check only turns away versions whose
+encoding still decodes; envelopes older than [`MIN_SUPPORTED_PROTOCOL_VERSION`] fail to
+decode instead.
+*/
+
+use serde::{Deserialize, Serialize};
+
+use crate::{PlayerProfile, Portfolio, TournamentInfo, TournamentStatus};
+
+/// Current version of the cross-application message protocol.
+/// Bump whenever a message variant or payload changes shape.
+pub const PROTOCOL_VERSION: u32 = 12;
+
+/// Oldest protocol version this build accepts: version 9 added the message ID to the envelope
+pub const MIN_SUPPORTED_PROTOCOL_VERSION: u32 = 9;
+
+/// Event stream on which Traditional Leagues publishes notifications for CoinDrafts Core.
+/// Applications only receive messages from their own instances and only read their own
+/// streams, so core follows this stream and reads each notification back through leagues.
+pub const CORE_NOTIFICATION_STREAM: &str = "coindrafts-core-notifications";
+
+/// Versioned wrapper around every cross-application message
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MessageEnvelope<M> {
+    /// Protocol version the sender was built with
+    pub version: u32,
+    /// Sender-unique idempotency key, kept when the message is retried
+    pub message_id: String,
+    /// The actual message
+    pub payload: M,
+}
+
+impl<M> MessageEnvelope<M> {
+    /// Wrap a message with the current protocol version
+    pub fn new(message_id: String, payload: M) -> Self {
+        Self {
+            version: PROTOCOL_VERSION,
+            message_id,
+            payload,
+        }
+    }
+
+    /// Check whether this build understands the envelope's protocol version
+    pub fn is_supported(&self) -> bool {
+        (MIN_SUPPORTED_PROTOCOL_VERSION..=PROTOCOL_VERSION).contains(&self.version)
+    }
+
+    /// Unwrap the payload if the protocol version is supported
+    pub fn into_payload(self) -> Result<M, u32> {
+        if self.is_supported() {
+            Ok(self.payload)
+        } else {
+            Err(self.version)
+        }
+    }
+}
+
+/// Messages sent FROM CoinDrafts Core TO Traditional Leagues
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum CoinDraftsMessage {
+    /// Request to create a tournament for a Traditional League game.
+    /// Leagues answers with `TraditionalLeaguesMessage::TournamentCreated` carrying its tournament ID.
+    CreateTournament {
+        game_id: String,
+        tournament_name: String,
+        entry_fee_usdc: u64,
+        max_participants: u32,
+        /// Tournament length in minutes, matching the game duration
+        duration_minutes: u64,
+    },
+    /// Register a player for a specific tournament
+    RegisterPlayerForTournament {
+        game_id: String,
+        tournament_id: String,
+        player_profile: PlayerProfile,
+    },
+    /// Sync portfolio submission to tournament application
+    SyncPortfolio {
+        game_id: String,
+        tournament_id: String,
+        portfolio: Portfolio,
+    },
+    /// Request tournament status update
+    GetTournamentStatus {
+        tournament_id: String,
+    },
+    /// Response to player verification request
+    VerifyPlayer {
+        game_id: String,
+        player_account: String,
+        tournament_id: String,
+        verified: bool,
+        /// Why the player failed verification
+        reason: Option<String>,
+    },
+    /// Delivery receipt for a leagues notification; `error` is set when core rejected it
+    Acknowledge {
+        message_id: String,
+        error: Option<String>,
+    },
+}
+
+impl CoinDraftsMessage {
+    /// Variant name, for delivery logs
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Self::CreateTournament { .. } => "CreateTournament",
+            Self::RegisterPlayerForTournament { .. } => "RegisterPlayerForTournament",
+            Self::SyncPortfolio { .. } => "SyncPortfolio",
+            Self::GetTournamentStatus { .. } => "GetTournamentStatus",
+            Self::VerifyPlayer { .. } => "VerifyPlayer",
+            Self::Acknowledge { .. } => "Acknowledge",
+        }
+    }
+}
+
+/// Messages sent FROM Traditional Leagues TO CoinDrafts Core
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum TraditionalLeaguesMessage {
+    /// Reply to `CoinDraftsMessage::CreateTournament` with the ID leagues generated for the game
+    TournamentCreated {
+        game_id: String,
+        tournament_id: String,
+        tournament_info: TournamentInfo,
+    },
+    /// Tournament completed notification
+    TournamentCompleted {
+        game_id: String,
+        tournament_id: String,
+        winners: Vec<String>,
+        total_prize_pool: u64,
+    },
+    /// Ask CoinDrafts Core to verify a pending registration: the player needs a profile
+    /// and a paid entry for the linked game
+    VerifyPlayer {
+        game_id: String,
+        player_account: String,
+        tournament_id: String,
+    },
+    /// Final outcome of a pending registration
+    PlayerVerified {
+        game_id: String,
+        tournament_id: String,
+        player_account: String,
+        verified: bool,
+        /// Why the registration was rejected
+        reason: Option<String>,
+    },
+    /// A synced portfolio could not be converted or was rejected by the tournament
+    PortfolioSyncFailed {
+        game_id: String,
+        tournament_id: String,
+        player_account: String,
+        reason: String,
+    },
+    /// Tournament status update
+    TournamentStatusUpdate {
+        tournament_id: String,
+        status: TournamentStatus,
+        current_round: u32,
+    },
+    /// A player withdrew from a tournament during registration and was refunded
+    PlayerUnregistered {
+        game_id: String,
+        tournament_id: String,
+        player_account: String,
+        refund_usdc: u64,
+    },
+    /// Tournament cancelled for lack of entrants; every entrant was refunded `refund_usdc`
+    TournamentCancelled {
+        game_id: String,
+        tournament_id: String,
+        reason: String,
+        refunded_players: Vec<String>,
+        refund_usdc: u64,
+    },
+    /// A season ended; `standings` lists player accounts best first
+    SeasonEnded {
+        season_id: String,
+        standings: Vec<String>,
+        bonus_pool_usdc: u64,
+    },
+    /// Delivery receipt for a core request; `error` is set when leagues rejected it
+    Acknowledge {
+        message_id: String,
+        error: Option<String>,
+    },
+}
+
+impl TraditionalLeaguesMessage {
+    /// Variant name, for delivery logs
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Self::TournamentCreated { .. } => "TournamentCreated",
+            Self::TournamentCompleted { .. } => "TournamentCompleted",
+            Self::VerifyPlayer { .. } => "VerifyPlayer",
+            Self::PlayerVerified { .. } => "PlayerVerified",
+            Self::PortfolioSyncFailed { .. } => "PortfolioSyncFailed",
+            Self::TournamentStatusUpdate { .. } => "TournamentStatusUpdate",
+            Self::PlayerUnregistered { .. } => "PlayerUnregistered",
+            Self::TournamentCancelled { .. } => "TournamentCancelled",
+            Self::SeasonEnded { .. } => "SeasonEnded",
+            Self::Acknowledge { .. } => "Acknowledge",
+        }
+    }
+}
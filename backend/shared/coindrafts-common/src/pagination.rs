@@ -0,0 +1,38 @@
+/*!
+# Pagination
+
+Shared page-size limits for service queries over keyed entries. Entries are stored under
+1-based slot numbers, so a page is a contiguous range of slots.
+*/
+
+use std::ops::RangeInclusive;
+
+/// Number of entries returned when a query does not ask for a page size
+pub const DEFAULT_PAGE_SIZE: u32 = 100;
+
+/// Largest page a service query will return
+pub const MAX_PAGE_SIZE: u32 = 1_000;
+
+/// Slot numbers (1-based) covering the requested page of `total` entries.
+/// The range is empty when `offset` is past the end.
+pub fn page_slots(offset: Option<u32>, limit: Option<u32>, total: u32) -> RangeInclusive<u32> {
+    let offset = offset.unwrap_or(0);
+    let limit = limit.unwrap_or(DEFAULT_PAGE_SIZE).min(MAX_PAGE_SIZE);
+    let last = offset.saturating_add(limit).min(total);
+    offset.saturating_add(1)..=last
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_page_slots() {
+        assert_eq!(page_slots(None, None, 250), 1..=100);
+        assert_eq!(page_slots(Some(200), None, 250), 201..=250);
+        assert_eq!(page_slots(Some(10), Some(5), 250), 11..=15);
+        assert_eq!(page_slots(None, Some(5_000), 2_500), 1..=1_000);
+        assert!(page_slots(Some(300), None, 250).is_empty());
+        assert!(page_slots(None, None, 0).is_empty());
+    }
+}
@@ -0,0 +1,144 @@
+/*!
+# Portfolio Management
+
+Portfolio types and validation shared between CoinDrafts applications.
+*/
+
+use async_graphql::{SimpleObject, InputObject, Enum};
+use serde::{Deserialize, Serialize};
+
+use crate::{Fixed, FixedResult};
+
+/// Largest share of a portfolio, in percent, that may be held short
+pub const MAX_SHORT_PERCENT: u32 = 50;
+
+/// Most holdings a single portfolio may contain
+pub const MAX_HOLDINGS: usize = 10;
+
+/// Cryptocurrency holding in a portfolio
+#[derive(Debug, Clone, Serialize, Deserialize, SimpleObject, InputObject)]
+#[graphql(input_name = "CryptoHoldingInput")]
+pub struct CryptoHolding {
+    /// Cryptocurrency symbol (e.g., "BTC", "ETH")
+    pub symbol: String,
+    /// Allocation percentage (1-100)
+    pub allocation_percent: u8,
+    /// Short position: the holding scores the inverse of its price return
+    pub short: bool,
+}
+
+impl CryptoHolding {
+    /// Return contributed by this holding for a given price return
+    pub fn position_return(&self, price_return: Fixed) -> FixedResult<Fixed> {
+        if self.short {
+            price_return.checked_mul_int(-1)
+        } else {
+            Ok(price_return)
+        }
+    }
+}
+
+/// Portfolio submission
+#[derive(Debug, Clone, Serialize, Deserialize, SimpleObject, InputObject)]
+#[graphql(input_name = "PortfolioInput")]
+pub struct Portfolio {
+    /// Game ID this portfolio belongs to
+    pub game_id: String,
+    /// Player account
+    pub player_account: String,
+    /// List of cryptocurrency holdings
+    pub holdings: Vec<CryptoHolding>,
+    /// Submission timestamp
+    pub submitted_at: u64,
+    /// Portfolio status
+    pub status: PortfolioStatus,
+}
+
+/// Portfolio validation status
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Enum)]
+pub enum PortfolioStatus {
+    Valid,
+    Invalid,
+    Pending,
+}
+
+impl Portfolio {
+    /// Create new portfolio
+    pub fn new(game_id: String, player_account: String, holdings: Vec<CryptoHolding>, timestamp: u64) -> Self {
+        Self {
+            game_id,
+            player_account,
+            holdings,
+            submitted_at: timestamp,
+            status: PortfolioStatus::Pending,
+        }
+    }
+
+    /// Share of the portfolio allocated to short holdings, in percent
+    pub fn short_percent(&self) -> u32 {
+        self.holdings.iter().filter(|h| h.short).map(|h| h.allocation_percent as u32).sum()
+    }
+
+    /// Validate portfolio
+    pub fn validate(&self) -> Result<(), Vec<String>> {
+        let mut errors = Vec::new();
+
+        // Check total allocation
+        let total_allocation: u32 = self.holdings.iter().map(|h| h.allocation_percent as u32).sum();
+        if total_allocation != 100 {
+            errors.push(format!("Total allocation must equal 100%, got {}%", total_allocation));
+        }
+
+        // Check holdings count
+        if self.holdings.is_empty() {
+            errors.push("Portfolio must contain at least one holding".to_string());
+        }
+
+        if self.holdings.len() > MAX_HOLDINGS {
+            errors.push(format!("Portfolio cannot contain more than {} holdings", MAX_HOLDINGS));
+        }
+
+        if self.short_percent() > MAX_SHORT_PERCENT {
+            errors.push(format!("At most {}% of a portfolio can be short, got {}%", MAX_SHORT_PERCENT, self.short_percent()));
+        }
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn holding(symbol: &str, allocation_percent: u8, short: bool) -> CryptoHolding {
+        CryptoHolding { symbol: symbol.to_string(), allocation_percent, short }
+    }
+
+    #[test]
+    fn test_short_holdings() {
+        let short = holding("bitcoin", 40, true);
+        assert_eq!(short.position_return(Fixed::from_raw(-500)).unwrap(), Fixed::from_raw(500));
+        assert_eq!(holding("bitcoin", 40, false).position_return(Fixed::from_raw(-500)).unwrap(), Fixed::from_raw(-500));
+
+        let capped = Portfolio::new(
+            "game-1".to_string(),
+            "player".to_string(),
+            vec![short.clone(), holding("ethereum", 60, false)],
+            0,
+        );
+        assert_eq!(capped.validate(), Ok(()));
+
+        let too_short = Portfolio::new(
+            "game-1".to_string(),
+            "player".to_string(),
+            vec![short, holding("ethereum", 60, true)],
+            0,
+        );
+        assert_eq!(too_short.short_percent(), 100);
+        assert!(too_short.validate().is_err());
+    }
+}
@@ -0,0 +1,18 @@
+/*!
+# Price Data
+
+Price snapshot types shared by every CoinDrafts application.
+*/
+
+use async_graphql::{InputObject, SimpleObject};
+use serde::{Deserialize, Serialize};
+
+/// Price snapshot at a specific timestamp
+#[derive(Debug, Clone, Serialize, Deserialize, SimpleObject, InputObject)]
+#[graphql(input_name = "PriceSnapshotInput")]
+pub struct PriceSnapshot {
+    pub crypto_id: String,
+    /// Price in micro-units (price * 1_000_000 for 6 decimal precision)
+    pub price_usd: u64,
+    pub timestamp: u64,
+}
@@ -0,0 +1,113 @@
+/*!
+# GraphQL Subscriptions
+
+Linera services answer one request at a time and cannot hold a connection open, so a
+subscription answers with every update its stream yields for the current block, collected
+into one response. Applications count their changes in state, and each subscription takes
+the revision the client already has and yields one update per change recorded since; when
+nothing changed the response data is null. Clients re-run their subscriptions with the
+latest revision whenever the node's `notifications` subscription reports a new block on
+the application's chain.
+*/
+
+use async_graphql::futures_util::StreamExt;
+use async_graphql::parser::types::{DocumentOperations, OperationType};
+use async_graphql::{Name, ObjectType, Request, Response, Schema, SubscriptionType, Value};
+
+/// Execute a request. Queries and mutations run as usual; a subscription answers with all
+/// of its updates, each field holding the list of values it yielded.
+pub async fn execute<Query, Mutation, Subscription>(
+    schema: &Schema<Query, Mutation, Subscription>,
+    mut request: Request,
+) -> Response
+where
+    Query: ObjectType + 'static,
+    Mutation: ObjectType + 'static,
+    Subscription: SubscriptionType + 'static,
+{
+    if !is_subscription(&mut request) {
+        return schema.execute(request).await;
+    }
+    let updates = schema.execute_stream(request).collect::<Vec<_>>().await;
+    merge_updates(updates)
+}
+
+/// Whether the operation the request selects is a subscription
+fn is_subscription(request: &mut Request) -> bool {
+    let operation_name = request.operation_name.clone();
+    let Ok(document) = request.parsed_query() else {
+        return false;
+    };
+    let operation = match (&document.operations, operation_name) {
+        (DocumentOperations::Single(operation), _) => Some(operation),
+        (DocumentOperations::Multiple(operations), Some(name)) => operations.get(name.as_str()),
+        (DocumentOperations::Multiple(operations), None) if operations.len() == 1 => operations.values().next(),
+        (DocumentOperations::Multiple(_), None) => None,
+    };
+    operation.is_some_and(|operation| operation.node.ty == OperationType::Subscription)
+}
+
+/// Fold the responses of a subscription stream into one, listing each field's values in
+/// the order they were yielded. Data is null when the stream yielded nothing.
+fn merge_updates(updates: Vec<Response>) -> Response {
+    let mut fields: Vec<(Name, Vec<Value>)> = Vec::new();
+    let mut errors = Vec::new();
+    for update in updates {
+        errors.extend(update.errors);
+        let Value::Object(data) = update.data else {
+            continue;
+        };
+        for (name, value) in data {
+            match fields.iter_mut().find(|(field, _)| *field == name) {
+                Some((_, values)) => values.push(value),
+                None => fields.push((name, vec![value])),
+            }
+        }
+    }
+
+    let data = if fields.is_empty() {
+        Value::Null
+    } else {
+        Value::Object(fields.into_iter().map(|(name, values)| (name, Value::List(values))).collect())
+    };
+    let mut response = Response::new(data);
+    response.errors = errors;
+    response
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use async_graphql::ServerError;
+
+    fn update(field: &str, value: i32) -> Response {
+        Response::new(Value::Object([(Name::new(field), Value::from(value))].into_iter().collect()))
+    }
+
+    #[test]
+    fn test_merge_updates() {
+        let merged = merge_updates(vec![
+            update("gameStatusChanged", 1),
+            Response::from_errors(vec![ServerError::new("stale revision", None)]),
+            update("gameStatusChanged", 2),
+        ]);
+        let expected: Value = Value::Object(
+            [(Name::new("gameStatusChanged"), Value::List(vec![Value::from(1), Value::from(2)]))].into_iter().collect(),
+        );
+        assert_eq!(merged.data, expected);
+        assert_eq!(merged.errors.len(), 1);
+
+        assert_eq!(merge_updates(Vec::new()).data, Value::Null);
+    }
+
+    #[test]
+    fn test_is_subscription() {
+        assert!(is_subscription(&mut Request::new("subscription { gameStatusChanged(gameId: \"g\") }")));
+        assert!(!is_subscription(&mut Request::new("{ games { id } }")));
+
+        let document = "query Games { games { id } } subscription Status { gameStatusChanged(gameId: \"g\") }";
+        assert!(is_subscription(&mut Request::new(document).operation_name("Status")));
+        assert!(!is_subscription(&mut Request::new(document).operation_name("Games")));
+        assert!(!is_subscription(&mut Request::new(document)));
+    }
+}
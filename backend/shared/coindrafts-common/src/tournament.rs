@@ -0,0 +1,59 @@
+/*!
+# Tournament Types
+
+Tournament metadata exchanged between CoinDrafts Core and Traditional Leagues.
+*/
+
+use async_graphql::{Enum, SimpleObject};
+use serde::{Deserialize, Serialize};
+
+/// Tournament types supported
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Enum)]
+pub enum TournamentType {
+    /// Single elimination bracket
+    SingleElimination,
+    /// Double elimination bracket
+    DoubleElimination,
+    /// Round robin format
+    RoundRobin,
+    /// Swiss system tournament
+    Swiss,
+    /// All participants scored together once over the tournament window
+    FreeForAll,
+}
+
+impl TournamentType {
+    /// Check whether this format is played in rounds advanced one at a time
+    pub fn is_round_based(self) -> bool {
+        matches!(
+            self,
+            TournamentType::SingleElimination
+                | TournamentType::DoubleElimination
+                | TournamentType::RoundRobin
+                | TournamentType::Swiss
+        )
+    }
+}
+
+/// Tournament status
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Enum)]
+pub enum TournamentStatus {
+    Registration,
+    InProgress,
+    Completed,
+    Cancelled,
+}
+
+/// Summary of a tournament as seen by other applications
+#[derive(Debug, Clone, Serialize, Deserialize, SimpleObject)]
+pub struct TournamentInfo {
+    pub tournament_id: String,
+    pub name: String,
+    pub tournament_type: TournamentType,
+    pub status: TournamentStatus,
+    pub entry_fee_usdc: u64,
+    pub max_participants: u32,
+    pub current_participants: u32,
+    /// Configured tournament length in minutes
+    pub duration_minutes: u64,
+}
@@ -126,7 +126,7 @@ export const CREATE_TOURNAMENT = gql`
 		$entryFeeUsdc: String!
 		$maxParticipants: Int!
 		$tournamentType: TournamentType!
-		$category: String!
+		$category: CryptoCategory!
 	) {
 		createTournament(
 			name: $name
@@ -376,14 +376,14 @@ const CREATE_TOURNAMENT = gql`
 		$entryFeeUsdc: String!
 		$maxParticipants: Int!
 		$tournamentType: TournamentType!
-		$category: String!
+		$category: CryptoCategory!
 	) {
 		createTournament(
 			name: $name
 			entryFeeUsdc: $entryFeeUsdc
 			maxParticipants: $maxParticipants
 			tournamentType: $tournamentType
-			category: $category
+			options: { category: $category }
 		)
 	}
 `;
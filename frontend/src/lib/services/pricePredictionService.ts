@@ -30,7 +30,7 @@ export interface Prediction {
 	confidence: number;
 	aiAssisted: boolean;
 	submittedAt: number;
-	multiplier?: number;
+	multiplier?: number; // Fixed point, scaled by 10000 (e.g., 10.0x = 100000)
 	reward?: number;
 }
 
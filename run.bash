@@ -30,9 +30,9 @@ echo "Core App ID: $COINDRAFTS_CORE_APP_ID"
 echo "Syncing Core app..."
 linera sync
 
-# Deploy Traditional Leagues
+# Deploy Traditional Leagues, taking verifications and receipts from CoinDrafts Core only
 echo "Deploying Traditional Leagues..."
-LEAGUES_OUTPUT=$(linera publish-and-create target/wasm32-unknown-unknown/release/traditional_leagues_{contract,service}.wasm --json-argument "null" 2>&1)
+LEAGUES_OUTPUT=$(linera publish-and-create target/wasm32-unknown-unknown/release/traditional_leagues_{contract,service}.wasm --json-argument "null" --json-parameters "{\"core_application_id\": \"$COINDRAFTS_CORE_APP_ID\"}" 2>&1)
 TRADITIONAL_LEAGUES_APP_ID=$(echo "$LEAGUES_OUTPUT" | grep -o '[a-f0-9]\{64\}' | tail -1)
 echo "Leagues App ID: $TRADITIONAL_LEAGUES_APP_ID"
 echo "Syncing Leagues app..."
@@ -68,6 +68,13 @@ linera service --port 8081 > /tmp/graphql-service.log 2>&1 &
 echo "Waiting for GraphQL service to start..."
 sleep 5
 
+# Connect CoinDrafts Core to Traditional Leagues on this chain
+echo "Connecting CoinDrafts Core to Traditional Leagues..."
+CONNECT_RESULT=$(curl -s -X POST "http://localhost:8081/chains/$DEFAULT_CHAIN_ID/applications/$COINDRAFTS_CORE_APP_ID" \
+  -H "Content-Type: application/json" \
+  -d "{\"query\":\"mutation { connectLeagues(applicationId: \\\"$TRADITIONAL_LEAGUES_APP_ID\\\", chainId: \\\"$DEFAULT_CHAIN_ID\\\") }\"}")
+echo "Connect result: $CONNECT_RESULT"
+
 echo ""
 echo "Testing tournament functionality..."
 echo ""
@@ -201,14 +201,14 @@ async function createTournament(endpoint, tournament) {
           $entryFeeUsdc: String!
           $maxParticipants: Int!
           $tournamentType: TournamentType!
-          $category: String
+          $category: CryptoCategory
         ) {
           createTournament(
             name: $name
             entryFeeUsdc: $entryFeeUsdc
             maxParticipants: $maxParticipants
             tournamentType: $tournamentType
-            category: $category
+            options: { category: $category }
           )
         }
       `,
@@ -407,14 +407,14 @@ async function test_TradLeagues_CreateTournament() {
           $entryFeeUsdc: String!
           $maxParticipants: Int!
           $tournamentType: TournamentType!
-          $category: String
+          $category: CryptoCategory
         ) {
           createTournament(
             name: $name
             entryFeeUsdc: $entryFeeUsdc
             maxParticipants: $maxParticipants
             tournamentType: $tournamentType
-            category: $category
+            options: { category: $category }
           )
         }
       `,